@@ -17,4 +17,76 @@ impl Dialect for GenericSqlDialect {
             || ch == '#'
             || ch == '_'
     }
+
+    fn supports_dollar_quoted_strings(&self) -> bool {
+        true
+    }
+
+    fn supports_numbered_placeholders(&self) -> bool {
+        true
+    }
+
+    fn supports_named_placeholders(&self) -> bool {
+        true
+    }
+
+    fn supports_nested_comments(&self) -> bool {
+        true
+    }
+
+    fn supports_partition_selection(&self) -> bool {
+        true
+    }
+
+    fn supports_show_tables_columns_variables(&self) -> bool {
+        true
+    }
+
+    fn supports_straight_join(&self) -> bool {
+        true
+    }
+
+    fn supports_group_by_with_rollup(&self) -> bool {
+        true
+    }
+
+    fn supports_select_modifiers(&self) -> bool {
+        true
+    }
+
+    fn supports_insert_modifiers(&self) -> bool {
+        true
+    }
+
+    fn supports_limit_comma(&self) -> bool {
+        true
+    }
+
+    fn supports_on_conflict(&self) -> bool {
+        true
+    }
+
+    fn supports_returning(&self) -> bool {
+        true
+    }
+
+    fn supports_on_duplicate_key_update(&self) -> bool {
+        true
+    }
+
+    fn supports_ilike(&self) -> bool {
+        true
+    }
+
+    fn supports_regex_match(&self) -> bool {
+        true
+    }
+
+    fn supports_bitwise_operators(&self) -> bool {
+        true
+    }
+
+    fn supports_caret_exponent(&self) -> bool {
+        true
+    }
 }
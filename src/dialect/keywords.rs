@@ -2,7 +2,8 @@
 /// 1) a list of constants for every keyword that
 /// can appear in SQLWord::keyword:
 ///    pub const KEYWORD = "KEYWORD"
-/// 2) an `ALL_KEYWORDS` array with every keyword in it
+/// 2) an `ALL_KEYWORDS` array with every keyword in it, sorted so that it
+///     can be searched with `is_keyword`.
 ///     This is not a list of *reserved* keywords: some of these can be
 ///     parsed as identifiers if the parser decides so. This means that
 ///     new keywords can be added here without affecting the parse result.
@@ -25,6 +26,7 @@ macro_rules! kw_def {
 
 /// Expands to a list of `kw_def!()` invocations for each keyword
 /// and defines an ALL_KEYWORDS array of the defined constants.
+/// The keywords must be listed in sorted order (of their string values).
 macro_rules! define_keywords {
     ($(
         $ident:ident $(= $string_keyword:expr)?
@@ -40,7 +42,7 @@ macro_rules! define_keywords {
 define_keywords!(
     ABS,
     ADD,
-    ASC,
+    ADMIN,
     ALL,
     ALLOCATE,
     ALTER,
@@ -51,6 +53,7 @@ define_keywords!(
     ARRAY_AGG,
     ARRAY_MAX_CARDINALITY,
     AS,
+    ASC,
     ASENSITIVE,
     ASYMMETRIC,
     AT,
@@ -60,14 +63,18 @@ define_keywords!(
     BEGIN,
     BEGIN_FRAME,
     BEGIN_PARTITION,
+    BERNOULLI,
     BETWEEN,
     BIGINT,
     BINARY,
     BLOB,
+    BOOL,
     BOOLEAN,
     BOTH,
+    BREADTH,
     BY,
     BYTEA,
+    CACHE,
     CALL,
     CALLED,
     CARDINALITY,
@@ -78,9 +85,9 @@ define_keywords!(
     CEIL,
     CEILING,
     CHAR,
-    CHAR_LENGTH,
     CHARACTER,
     CHARACTER_LENGTH,
+    CHAR_LENGTH,
     CHECK,
     CLOB,
     CLOSE,
@@ -88,8 +95,10 @@ define_keywords!(
     COLLATE,
     COLLECT,
     COLUMN,
+    COLUMNS,
     COMMIT,
     CONDITION,
+    CONFLICT,
     CONNECT,
     CONSTRAINT,
     CONTAINS,
@@ -126,24 +135,31 @@ define_keywords!(
     DECIMAL,
     DECLARE,
     DEFAULT,
+    DELAYED,
     DELETE,
     DENSE_RANK,
+    DEPTH,
     DEREF,
     DESC,
     DESCRIBE,
     DETERMINISTIC,
     DISCONNECT,
     DISTINCT,
+    DO,
     DOUBLE,
     DROP,
+    DUPLICATE,
     DYNAMIC,
     EACH,
     ELEMENT,
     ELSE,
+    EMPTY,
     END,
+    END_EXEC = "END-EXEC",
     END_FRAME,
     END_PARTITION,
     EQUALS,
+    ERROR,
     ESCAPE,
     EVERY,
     EXCEPT,
@@ -156,6 +172,7 @@ define_keywords!(
     FALSE,
     FETCH,
     FILTER,
+    FIRST,
     FIRST_VALUE,
     FLOAT,
     FLOOR,
@@ -176,12 +193,19 @@ define_keywords!(
     GROUPS,
     HAVING,
     HEADER,
+    HIGH_PRIORITY,
     HOLD,
     HOUR,
     IDENTITY,
     IF,
+    IGNORE,
+    ILIKE,
+    IMMUTABLE,
     IN,
+    INCREMENT,
+    INDEX,
     INDICATOR,
+    INHERIT,
     INNER,
     INOUT,
     INSENSITIVE,
@@ -194,10 +218,17 @@ define_keywords!(
     INTO,
     IS,
     JOIN,
+    JSON,
+    JSONB,
+    JSON_EXISTS,
+    JSON_QUERY,
+    JSON_TABLE,
+    JSON_VALUE,
     KEY,
     LAG,
     LANGUAGE,
     LARGE,
+    LAST,
     LAST_VALUE,
     LATERAL,
     LEAD,
@@ -211,15 +242,19 @@ define_keywords!(
     LOCALTIME,
     LOCALTIMESTAMP,
     LOCATION,
+    LOCKED,
     LOWER,
+    LOW_PRIORITY,
     MATCH,
     MATERIALIZED,
     MAX,
+    MAXVALUE,
     MEMBER,
     MERGE,
     METHOD,
     MIN,
     MINUTE,
+    MINVALUE,
     MOD,
     MODIFIES,
     MODULE,
@@ -230,24 +265,34 @@ define_keywords!(
     NCHAR,
     NCLOB,
     NEW,
+    NEXT,
+    NFC,
+    NFD,
+    NFKC,
+    NFKD,
     NO,
     NONE,
     NORMALIZE,
+    NORMALIZED,
     NOT,
+    NOTHING,
+    NOWAIT,
     NTH_VALUE,
     NTILE,
     NULL,
     NULLIF,
+    NULLS,
     NUMERIC,
     OBJECT,
-    OCTET_LENGTH,
     OCCURRENCES_REGEX,
+    OCTET_LENGTH,
     OF,
     OFFSET,
     OLD,
     ON,
     ONLY,
     OPEN,
+    OPTION,
     OR,
     ORDER,
     OUT,
@@ -256,12 +301,13 @@ define_keywords!(
     OVERLAPS,
     OVERLAY,
     PARAMETER,
-    PARTITION,
     PARQUET,
+    PARTITION,
+    PATH,
     PERCENT,
-    PERCENT_RANK,
     PERCENTILE_CONT,
     PERCENTILE_DISC,
+    PERCENT_RANK,
     PERIOD,
     PORTION,
     POSITION,
@@ -272,6 +318,7 @@ define_keywords!(
     PRECISION,
     PREPARE,
     PRIMARY,
+    PRIVILEGES,
     PROCEDURE,
     RANGE,
     RANK,
@@ -292,27 +339,37 @@ define_keywords!(
     REGR_SXY,
     REGR_SYY,
     RELEASE,
+    RENAME,
+    REPEATABLE,
+    REPLACE,
     RESTRICT,
     RESULT,
     RETURN,
+    RETURNING,
     RETURNS,
     REVOKE,
     RIGHT,
     ROLLBACK,
     ROLLUP,
     ROW,
-    ROW_NUMBER,
     ROWS,
+    ROW_NUMBER,
     SAVEPOINT,
+    SCHEMA,
     SCOPE,
     SCROLL,
     SEARCH,
     SECOND,
     SELECT,
     SENSITIVE,
+    SEQUENCE,
     SESSION_USER,
     SET,
+    SETS,
+    SHARE,
+    SHOW,
     SIMILAR,
+    SKIP,
     SMALLINT,
     SOME,
     SPECIFIC,
@@ -321,13 +378,20 @@ define_keywords!(
     SQLEXCEPTION,
     SQLSTATE,
     SQLWARNING,
+    SQL_BIG_RESULT,
+    SQL_BUFFER_RESULT,
+    SQL_CALC_FOUND_ROWS,
+    SQL_NO_CACHE,
+    SQL_SMALL_RESULT,
     SQRT,
+    STABLE,
     START,
     STATIC,
     STDDEV_POP,
     STDDEV_SAMP,
     STDIN,
     STORED,
+    STRAIGHT_JOIN,
     SUBMULTISET,
     SUBSTRING,
     SUBSTRING_REGEX,
@@ -338,9 +402,13 @@ define_keywords!(
     SYSTEM_TIME,
     SYSTEM_USER,
     TABLE,
+    TABLES,
     TABLESAMPLE,
+    TEMP,
+    TEMPORARY,
     TEXT,
     THEN,
+    TIES,
     TIME,
     TIMESTAMP,
     TIMEZONE_HOUR,
@@ -352,10 +420,10 @@ define_keywords!(
     TRANSLATION,
     TREAT,
     TRIGGER,
-    TRUNCATE,
     TRIM,
     TRIM_ARRAY,
     TRUE,
+    TRUNCATE,
     UESCAPE,
     UNBOUNDED,
     UNION,
@@ -364,19 +432,23 @@ define_keywords!(
     UNNEST,
     UPDATE,
     UPPER,
+    USAGE,
+    USE,
     USER,
     USING,
     UUID,
     VALUE,
     VALUES,
     VALUE_OF,
-    VAR_POP,
-    VAR_SAMP,
     VARBINARY,
     VARCHAR,
+    VARIABLES,
     VARYING,
+    VAR_POP,
+    VAR_SAMP,
     VERSIONING,
     VIEW,
+    VOLATILE,
     WHEN,
     WHENEVER,
     WHERE,
@@ -386,24 +458,51 @@ define_keywords!(
     WITHIN,
     WITHOUT,
     YEAR,
-    ZONE,
-    END_EXEC = "END-EXEC"
+    ZONE
 );
 
+/// Whether `word`, which must already be uppercase, is one of the
+/// `ALL_KEYWORDS`
+pub fn is_keyword(word: &str) -> bool {
+    ALL_KEYWORDS.binary_search(&word).is_ok()
+}
+
+/// Find the entry of `ALL_KEYWORDS` matching `word` case-insensitively,
+/// without allocating an uppercased copy of `word` unless it is non-ASCII
+pub fn lookup_keyword(word: &str) -> Option<&'static str> {
+    if !word.is_ascii() {
+        // Some non-ASCII characters uppercase to ASCII, e.g. `ı` to `I`
+        let word = word.to_uppercase();
+        return ALL_KEYWORDS
+            .binary_search(&word.as_str())
+            .ok()
+            .map(|index| ALL_KEYWORDS[index]);
+    }
+    ALL_KEYWORDS
+        .binary_search_by(|keyword| {
+            keyword
+                .bytes()
+                .cmp(word.bytes().map(|b| b.to_ascii_uppercase()))
+        })
+        .ok()
+        .map(|index| ALL_KEYWORDS[index])
+}
+
 /// These keywords can't be used as a table alias, so that `FROM table_name alias`
 /// can be parsed unambiguously without looking ahead.
+#[rustfmt::skip] // keep the grouped layout despite the long `TABLESAMPLE`
 pub const RESERVED_FOR_TABLE_ALIAS: &[&str] = &[
     // Reserved as both a table and a column alias:
-    WITH, SELECT, WHERE, GROUP, ORDER, UNION, EXCEPT, INTERSECT,
+    WITH, SELECT, WHERE, GROUP, ORDER, UNION, EXCEPT, INTERSECT, FOR, WINDOW,
     // Reserved only as a table alias in the `FROM`/`JOIN` clauses:
-    ON, JOIN, INNER, CROSS, FULL, LEFT, RIGHT, NATURAL, USING, LIMIT,
+    ON, JOIN, INNER, CROSS, FULL, LEFT, RIGHT, NATURAL, USING, LIMIT, OFFSET, FETCH, TABLESAMPLE,
 ];
 
 /// Can't be used as a column alias, so that `SELECT <expr> alias`
 /// can be parsed unambiguously without looking ahead.
 pub const RESERVED_FOR_COLUMN_ALIAS: &[&str] = &[
     // Reserved as both a table and a column alias:
-    WITH, SELECT, WHERE, GROUP, ORDER, UNION, EXCEPT, INTERSECT,
+    WITH, SELECT, WHERE, GROUP, ORDER, UNION, EXCEPT, INTERSECT, FOR, WINDOW,
     // Reserved only as a column alias in the `SELECT` clause:
     FROM,
 ];
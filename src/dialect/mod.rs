@@ -2,13 +2,17 @@ mod ansi_sql;
 mod generic_sql;
 pub mod keywords;
 mod mssql;
+mod mysql;
 mod postgresql;
 
 use std::fmt::Debug;
 
+use crate::sqlast::SQLType;
+
 pub use self::ansi_sql::AnsiSqlDialect;
 pub use self::generic_sql::GenericSqlDialect;
 pub use self::mssql::MsSqlDialect;
+pub use self::mysql::MySqlDialect;
 pub use self::postgresql::PostgreSqlDialect;
 
 pub trait Dialect: Debug {
@@ -24,4 +28,103 @@ pub trait Dialect: Debug {
     fn is_identifier_start(&self, ch: char) -> bool;
     /// Determine if a character is a valid unquoted identifier character
     fn is_identifier_part(&self, ch: char) -> bool;
+    /// Determine if `$$string$$` and `$tag$string$tag$` literals (as
+    /// supported by PostgreSQL) should be recognized by the tokenizer
+    fn supports_dollar_quoted_strings(&self) -> bool {
+        false
+    }
+    /// Determine if `$1`, `$2`, ... are tokenized as positional bind
+    /// parameter placeholders (as in PostgreSQL). The `?` placeholder is
+    /// recognized in all dialects.
+    fn supports_numbered_placeholders(&self) -> bool {
+        false
+    }
+    /// Determine if `:name` is tokenized as a named bind parameter placeholder
+    fn supports_named_placeholders(&self) -> bool {
+        false
+    }
+    /// Determine if `/* ... */` comments can be nested, so that
+    /// `/* a /* b */ c */` is a single comment (as in PostgreSQL)
+    fn supports_nested_comments(&self) -> bool {
+        false
+    }
+    /// Determine if explicit partition selection, i.e. `PARTITION (p0, p1)`
+    /// after a table name in `FROM` or `INSERT INTO` (as in MySQL), is allowed
+    fn supports_partition_selection(&self) -> bool {
+        false
+    }
+    /// Determine if the MySQL `SHOW TABLES`, `SHOW COLUMNS` and
+    /// `SHOW VARIABLES` statements are recognized. Otherwise, `SHOW <name>`
+    /// is the only form of `SHOW`.
+    fn supports_show_tables_columns_variables(&self) -> bool {
+        false
+    }
+    /// Determine if the MySQL `STRAIGHT_JOIN` join operator is recognized
+    fn supports_straight_join(&self) -> bool {
+        false
+    }
+    /// Determine if `GROUP BY ... WITH ROLLUP` (as in MySQL) is allowed
+    fn supports_group_by_with_rollup(&self) -> bool {
+        false
+    }
+    /// Determine if MySQL modifiers like `HIGH_PRIORITY` or
+    /// `SQL_CALC_FOUND_ROWS` are allowed after `SELECT [ALL | DISTINCT]`
+    fn supports_select_modifiers(&self) -> bool {
+        false
+    }
+    /// Determine if MySQL modifiers like `LOW_PRIORITY` or `IGNORE` are
+    /// allowed between `INSERT` and `INTO`
+    fn supports_insert_modifiers(&self) -> bool {
+        false
+    }
+    /// Determine if `INSERT`, `UPDATE` and `DELETE` can end with a
+    /// `RETURNING <select list>` clause, as in PostgreSQL
+    fn supports_returning(&self) -> bool {
+        false
+    }
+    /// Determine if `INSERT ... ON CONFLICT` (as in PostgreSQL) is allowed
+    fn supports_on_conflict(&self) -> bool {
+        false
+    }
+    /// Determine if `INSERT ... ON DUPLICATE KEY UPDATE` (as in MySQL) is allowed
+    fn supports_on_duplicate_key_update(&self) -> bool {
+        false
+    }
+    /// Determine if the `LIMIT <offset>, <count>` form (as in MySQL) is allowed
+    fn supports_limit_comma(&self) -> bool {
+        false
+    }
+    /// Determine if the case-insensitive `ILIKE` and `NOT ILIKE` operators
+    /// (as in PostgreSQL) are recognized
+    fn supports_ilike(&self) -> bool {
+        false
+    }
+    /// Determine if `SIMILAR TO` and the POSIX regular expression match
+    /// operators `~`, `~*`, `!~`, and `!~*` (as in PostgreSQL) are recognized
+    fn supports_regex_match(&self) -> bool {
+        false
+    }
+    /// Determine if the bitwise operators `&`, `|`, `<<`, and `>>` (as in
+    /// PostgreSQL and MySQL) are recognized
+    fn supports_bitwise_operators(&self) -> bool {
+        false
+    }
+    /// Determine if `#` is the bitwise exclusive or operator (as in
+    /// PostgreSQL). This must stay disabled in dialects where `#` can start
+    /// an identifier, such as the generic and MSSQL ones.
+    fn supports_sharp_xor(&self) -> bool {
+        false
+    }
+    /// Determine if `^` is the exponentiation operator (as in PostgreSQL),
+    /// rather than the bitwise exclusive or of MSSQL and MySQL
+    fn supports_caret_exponent(&self) -> bool {
+        false
+    }
+    /// Resolve a dialect-specific alias of a data type, such as `int4` in
+    /// PostgreSQL, to the canonical `SQLType`. `name` is the unquoted word
+    /// used as the type name, in its original case. Types that are not
+    /// resolved here are parsed as `SQLType::Custom`.
+    fn resolve_type_alias(&self, _name: &str) -> Option<SQLType> {
+        None
+    }
 }
@@ -0,0 +1,70 @@
+use crate::dialect::Dialect;
+use crate::sqlast::SQLType;
+
+#[derive(Debug)]
+pub struct MySqlDialect {}
+
+impl Dialect for MySqlDialect {
+    fn is_delimited_identifier_start(&self, ch: char) -> bool {
+        // Double quotes delimit identifiers only in the ANSI_QUOTES SQL mode,
+        // which is not the default.
+        ch == '`'
+    }
+
+    fn is_identifier_start(&self, ch: char) -> bool {
+        // See https://dev.mysql.com/doc/refman/8.0/en/identifiers.html.
+        // We don't yet support identifiers beginning with numbers, as that
+        // makes it hard to distinguish numeric literals.
+        // `@` starts user-defined (`@var`) and system (`@@var`) variables.
+        ch.is_ascii_alphabetic() || ch == '_' || ch == '$' || ch == '@'
+    }
+
+    fn is_identifier_part(&self, ch: char) -> bool {
+        self.is_identifier_start(ch) || ch.is_ascii_digit()
+    }
+
+    fn supports_partition_selection(&self) -> bool {
+        true
+    }
+
+    fn supports_show_tables_columns_variables(&self) -> bool {
+        true
+    }
+
+    fn supports_straight_join(&self) -> bool {
+        true
+    }
+
+    fn supports_group_by_with_rollup(&self) -> bool {
+        true
+    }
+
+    fn supports_select_modifiers(&self) -> bool {
+        true
+    }
+
+    fn supports_insert_modifiers(&self) -> bool {
+        true
+    }
+
+    fn supports_limit_comma(&self) -> bool {
+        true
+    }
+
+    fn supports_on_duplicate_key_update(&self) -> bool {
+        true
+    }
+
+    fn supports_bitwise_operators(&self) -> bool {
+        true
+    }
+
+    fn resolve_type_alias(&self, name: &str) -> Option<SQLType> {
+        match name.to_ascii_lowercase().as_str() {
+            "tinytext" => Some(SQLType::TinyText),
+            "mediumtext" => Some(SQLType::MediumText),
+            "longtext" => Some(SQLType::LongText),
+            _ => None,
+        }
+    }
+}
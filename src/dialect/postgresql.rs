@@ -1,4 +1,5 @@
 use crate::dialect::Dialect;
+use crate::sqlast::SQLType;
 
 #[derive(Debug)]
 pub struct PostgreSqlDialect {}
@@ -18,4 +19,63 @@ impl Dialect for PostgreSqlDialect {
             || ch == '$'
             || ch == '_'
     }
+
+    fn supports_dollar_quoted_strings(&self) -> bool {
+        true
+    }
+
+    fn supports_numbered_placeholders(&self) -> bool {
+        true
+    }
+
+    fn supports_nested_comments(&self) -> bool {
+        true
+    }
+
+    fn supports_ilike(&self) -> bool {
+        true
+    }
+
+    fn supports_on_conflict(&self) -> bool {
+        true
+    }
+
+    fn supports_returning(&self) -> bool {
+        true
+    }
+
+    fn supports_regex_match(&self) -> bool {
+        true
+    }
+
+    fn supports_bitwise_operators(&self) -> bool {
+        true
+    }
+
+    fn supports_sharp_xor(&self) -> bool {
+        true
+    }
+
+    fn supports_caret_exponent(&self) -> bool {
+        true
+    }
+
+    fn resolve_type_alias(&self, name: &str) -> Option<SQLType> {
+        // See https://www.postgresql.org/docs/11/datatype.html#DATATYPE-TABLE
+        match name.to_ascii_lowercase().as_str() {
+            "int2" => Some(SQLType::SmallInt),
+            "int4" => Some(SQLType::Int),
+            "int8" => Some(SQLType::BigInt),
+            "float4" => Some(SQLType::Real),
+            "float8" => Some(SQLType::Double),
+            "oid" => Some(SQLType::Oid),
+            "regproc" => Some(SQLType::Regproc),
+            "regtype" => Some(SQLType::Regtype),
+            "regnamespace" => Some(SQLType::Regnamespace),
+            "smallserial" | "serial2" => Some(SQLType::SmallSerial),
+            "serial" | "serial4" => Some(SQLType::Serial),
+            "bigserial" | "serial8" => Some(SQLType::BigSerial),
+            _ => None,
+        }
+    }
 }
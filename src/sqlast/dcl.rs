@@ -0,0 +1,92 @@
+use super::{display_comma_separated, SQLIdent, SQLObjectName};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The privileges granted by a `GRANT` statement
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Privileges<I = SQLIdent> {
+    /// `ALL [ PRIVILEGES ]`
+    All,
+    /// A list of specific privileges, e.g. `SELECT, INSERT`
+    Privileges(Vec<Privilege<I>>),
+}
+
+impl<I: fmt::Display> fmt::Display for Privileges<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Privileges::All => f.write_str("ALL PRIVILEGES"),
+            Privileges::Privileges(privileges) => {
+                write!(f, "{}", display_comma_separated(privileges))
+            }
+        }
+    }
+}
+
+/// A specific privilege that can be granted on a database object.
+/// `SELECT`, `INSERT`, `UPDATE` and `REFERENCES` can be restricted to
+/// a list of columns, e.g. `SELECT (a, b)`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Privilege<I = SQLIdent> {
+    Select { columns: Option<Vec<I>> },
+    Insert { columns: Option<Vec<I>> },
+    Update { columns: Option<Vec<I>> },
+    Delete,
+    Truncate,
+    References { columns: Option<Vec<I>> },
+    Trigger,
+    Usage,
+    Execute,
+    Create,
+    Connect,
+    Temporary,
+}
+
+impl<I: fmt::Display> fmt::Display for Privilege<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (keyword, columns) = match self {
+            Privilege::Select { columns } => ("SELECT", columns),
+            Privilege::Insert { columns } => ("INSERT", columns),
+            Privilege::Update { columns } => ("UPDATE", columns),
+            Privilege::Delete => ("DELETE", &None),
+            Privilege::Truncate => ("TRUNCATE", &None),
+            Privilege::References { columns } => ("REFERENCES", columns),
+            Privilege::Trigger => ("TRIGGER", &None),
+            Privilege::Usage => ("USAGE", &None),
+            Privilege::Execute => ("EXECUTE", &None),
+            Privilege::Create => ("CREATE", &None),
+            Privilege::Connect => ("CONNECT", &None),
+            Privilege::Temporary => ("TEMPORARY", &None),
+        };
+        f.write_str(keyword)?;
+        if let Some(columns) = columns {
+            write!(f, " ({})", display_comma_separated(columns))?;
+        }
+        Ok(())
+    }
+}
+
+/// The objects on which privileges are granted
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GrantObjects<I = SQLIdent> {
+    /// `[ TABLE ] <table_name>, ...`
+    Tables(Vec<SQLObjectName<I>>),
+    /// `ALL TABLES IN SCHEMA <schema_name>, ...` (PostgreSQL)
+    AllTablesInSchema { schemas: Vec<SQLObjectName<I>> },
+}
+
+impl<I: fmt::Display> fmt::Display for GrantObjects<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GrantObjects::Tables(tables) => write!(f, "{}", display_comma_separated(tables)),
+            GrantObjects::AllTablesInSchema { schemas } => write!(
+                f,
+                "ALL TABLES IN SCHEMA {}",
+                display_comma_separated(schemas)
+            ),
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use super::{display_comma_separated, ASTNode, SQLIdent, SQLObjectName};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AlterOperation<I = SQLIdent> {
+    /// `ADD <table_constraint>`
+    AddConstraint(TableConstraint<I>),
+    RemoveConstraint {
+        name: I,
+    },
+    /// `RENAME TO <table_name>`
+    RenameTable {
+        table_name: SQLObjectName<I>,
+    },
+    /// `RENAME [ COLUMN ] <old_column_name> TO <new_column_name>`
+    RenameColumn {
+        old_column_name: I,
+        new_column_name: I,
+    },
+    /// `RENAME CONSTRAINT <old_name> TO <new_name>`
+    RenameConstraint {
+        old_name: I,
+        new_name: I,
+    },
+}
+
+impl<I: fmt::Display> fmt::Display for AlterOperation<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlterOperation::AddConstraint(constraint) => write!(f, "ADD {}", constraint),
+            AlterOperation::RemoveConstraint { name } => write!(f, "REMOVE CONSTRAINT {}", name),
+            AlterOperation::RenameTable { table_name } => write!(f, "RENAME TO {}", table_name),
+            AlterOperation::RenameColumn {
+                old_column_name,
+                new_column_name,
+            } => write!(
+                f,
+                "RENAME COLUMN {} TO {}",
+                old_column_name, new_column_name
+            ),
+            AlterOperation::RenameConstraint { old_name, new_name } => {
+                write!(f, "RENAME CONSTRAINT {} TO {}", old_name, new_name)
+            }
+        }
+    }
+}
+
+/// A table-level constraint, specified in a `CREATE TABLE` alongside the
+/// column definitions: `[ CONSTRAINT <name> ] <constraint>`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TableConstraint<I = SQLIdent> {
+    /// `[ CONSTRAINT <name> ] { PRIMARY KEY | UNIQUE } (<columns>)`
+    Unique {
+        name: Option<I>,
+        columns: Vec<I>,
+        /// Whether this is a `PRIMARY KEY` or just a `UNIQUE` constraint
+        is_primary: bool,
+    },
+    /// `[ CONSTRAINT <name> ] FOREIGN KEY (<columns>)
+    /// REFERENCES <foreign_table> (<referred_columns>)`
+    ForeignKey {
+        name: Option<I>,
+        columns: Vec<I>,
+        foreign_table: SQLObjectName<I>,
+        referred_columns: Vec<I>,
+    },
+    /// `[ CONSTRAINT <name> ] CHECK (<expr>) [ NO INHERIT ]`, where
+    /// `NO INHERIT` is Postgres-specific
+    Check {
+        name: Option<I>,
+        expr: Box<ASTNode<I>>,
+        no_inherit: bool,
+    },
+}
+
+impl<I: fmt::Display> fmt::Display for TableConstraint<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn constraint_name<I: fmt::Display>(
+            f: &mut fmt::Formatter,
+            name: &Option<I>,
+        ) -> fmt::Result {
+            if let Some(name) = name {
+                write!(f, "CONSTRAINT {} ", name)?;
+            }
+            Ok(())
+        }
+        match self {
+            TableConstraint::Unique {
+                name,
+                columns,
+                is_primary,
+            } => {
+                constraint_name(f, name)?;
+                write!(
+                    f,
+                    "{} ({})",
+                    if *is_primary { "PRIMARY KEY" } else { "UNIQUE" },
+                    display_comma_separated(columns)
+                )
+            }
+            TableConstraint::ForeignKey {
+                name,
+                columns,
+                foreign_table,
+                referred_columns,
+            } => {
+                constraint_name(f, name)?;
+                write!(
+                    f,
+                    "FOREIGN KEY ({}) REFERENCES {}({})",
+                    display_comma_separated(columns),
+                    foreign_table,
+                    display_comma_separated(referred_columns)
+                )
+            }
+            TableConstraint::Check {
+                name,
+                expr,
+                no_inherit,
+            } => {
+                constraint_name(f, name)?;
+                write!(f, "CHECK ({})", expr)?;
+                if *no_inherit {
+                    f.write_str(" NO INHERIT")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
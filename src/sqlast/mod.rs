@@ -14,28 +14,75 @@
 
 //! SQL Abstract Syntax Tree (AST) types
 
+mod dcl;
+mod ddl;
+mod parameters;
 mod query;
+mod rename;
 mod sql_operator;
 mod sqltype;
-mod table_key;
 mod value;
+mod visitor;
 
+pub use self::dcl::{GrantObjects, Privilege, Privileges};
+pub use self::ddl::{AlterOperation, TableConstraint};
 pub use self::query::{
-    Cte, Join, JoinConstraint, JoinOperator, SQLOrderByExpr, SQLQuery, SQLSelect, SQLSelectItem,
-    SQLSetExpr, SQLSetOperator, TableFactor,
+    Cte, CteCycle, CteSearch, Fetch, Join, JoinConstraint, JoinOperator, JsonTableColumn,
+    LockClause, LockType, NonBlock, Offset, SQLOrderByExpr, SQLQuery, SQLSelect, SQLSelectItem,
+    SQLSelectModifier, SQLSetExpr, SQLSetOperator, SQLValues, TableAlias, TableFactor, TableSample,
+    TableSampleMethod, TableSampleUnit,
 };
-pub use self::sqltype::SQLType;
-pub use self::table_key::{AlterOperation, Key, TableKey};
+pub use self::sqltype::{DateTimeField, SQLType};
+use self::value::escape_single_quote_string;
 pub use self::value::Value;
+pub use self::visitor::visit::{self, Visitor};
+pub use self::visitor::visit_mut::{self, VisitorMut};
 
 pub use self::sql_operator::SQLOperator;
 
-/// Like `vec.join(", ")`, but for any types implementing ToString.
-fn comma_separated_string<T: ToString>(vec: &[T]) -> String {
-    vec.iter()
-        .map(T::to_string)
-        .collect::<Vec<String>>()
-        .join(", ")
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::fmt;
+
+struct DisplaySeparated<'a, T>
+where
+    T: fmt::Display,
+{
+    slice: &'a [T],
+    sep: &'static str,
+}
+
+impl<'a, T> fmt::Display for DisplaySeparated<'a, T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut delim = "";
+        for t in self.slice {
+            write!(f, "{}", delim)?;
+            delim = self.sep;
+            write!(f, "{}", t)?;
+        }
+        Ok(())
+    }
+}
+
+/// Like `slice.join(sep)`, but for any types implementing `Display`, and
+/// without allocating an intermediate `String`.
+fn display_separated<'a, T>(slice: &'a [T], sep: &'static str) -> DisplaySeparated<'a, T>
+where
+    T: fmt::Display,
+{
+    DisplaySeparated { slice, sep }
+}
+
+/// Like `slice.join(", ")`, but for any types implementing `Display`.
+fn display_comma_separated<T>(slice: &[T]) -> DisplaySeparated<'_, T>
+where
+    T: fmt::Display,
+{
+    DisplaySeparated { slice, sep: ", " }
 }
 
 /// Identifier name, in the originally quoted form (e.g. `"id"`)
@@ -47,71 +94,114 @@ pub type SQLIdent = String;
 /// (e.g. boolean vs string), so the caller must handle expressions of
 /// inappropriate type, like `WHERE 1` or `SELECT 1=1`, as necessary.
 #[derive(Debug, Clone, PartialEq)]
-pub enum ASTNode {
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ASTNode<I = SQLIdent> {
     /// Identifier e.g. table name or column name
-    SQLIdentifier(SQLIdent),
+    SQLIdentifier(I),
+    /// A variable, such as a T-SQL local `@foo` or global `@@version`,
+    /// including the leading `@`s
+    SQLVariable(I),
     /// Unqualified wildcard (`*`). SQL allows this in limited contexts (such as right
     /// after `SELECT` or as part of an aggregate function, e.g. `COUNT(*)`, but we
     /// currently accept it in contexts where it doesn't make sense, such as `* + *`
     SQLWildcard,
     /// Qualified wildcard, e.g. `alias.*` or `schema.table.*`.
     /// (Same caveats apply to SQLQualifiedWildcard as to SQLWildcard.)
-    SQLQualifiedWildcard(Vec<SQLIdent>),
+    SQLQualifiedWildcard(Vec<I>),
     /// Multi-part identifier, e.g. `table_alias.column` or `schema.table.col`
-    SQLCompoundIdentifier(Vec<SQLIdent>),
+    SQLCompoundIdentifier(Vec<I>),
     /// `IS NULL` expression
-    SQLIsNull(Box<ASTNode>),
+    SQLIsNull(Box<ASTNode<I>>),
     /// `IS NOT NULL` expression
-    SQLIsNotNull(Box<ASTNode>),
+    SQLIsNotNull(Box<ASTNode<I>>),
+    /// `<expr> IS [ NOT ] [ <form> ] NORMALIZED`
+    SQLIsNormalized {
+        expr: Box<ASTNode<I>>,
+        form: Option<SQLNormalForm>,
+        negated: bool,
+    },
     /// `[ NOT ] IN (val1, val2, ...)`
     SQLInList {
-        expr: Box<ASTNode>,
-        list: Vec<ASTNode>,
+        expr: Box<ASTNode<I>>,
+        list: Vec<ASTNode<I>>,
         negated: bool,
     },
     /// `[ NOT ] IN (SELECT ...)`
     SQLInSubquery {
-        expr: Box<ASTNode>,
-        subquery: Box<SQLQuery>,
+        expr: Box<ASTNode<I>>,
+        subquery: Box<SQLQuery<I>>,
         negated: bool,
     },
     /// `<expr> [ NOT ] BETWEEN <low> AND <high>`
     SQLBetween {
-        expr: Box<ASTNode>,
+        expr: Box<ASTNode<I>>,
         negated: bool,
-        low: Box<ASTNode>,
-        high: Box<ASTNode>,
+        low: Box<ASTNode<I>>,
+        high: Box<ASTNode<I>>,
+    },
+    /// `<expr> <op> <pattern> ESCAPE '<escape_char>'`, where `op` is one of
+    /// `[ NOT ] LIKE`, `[ NOT ] ILIKE` or `[ NOT ] SIMILAR TO`. A pattern
+    /// match without `ESCAPE` is represented as an `SQLBinaryExpr`.
+    SQLLike {
+        expr: Box<ASTNode<I>>,
+        op: SQLOperator,
+        pattern: Box<ASTNode<I>>,
+        escape_char: char,
     },
     /// Binary expression e.g. `1 + 1` or `foo > bar`
     SQLBinaryExpr {
-        left: Box<ASTNode>,
+        left: Box<ASTNode<I>>,
         op: SQLOperator,
-        right: Box<ASTNode>,
+        right: Box<ASTNode<I>>,
     },
     /// CAST an expression to a different data type e.g. `CAST(foo AS VARCHAR(123))`
     SQLCast {
-        expr: Box<ASTNode>,
+        expr: Box<ASTNode<I>>,
         data_type: SQLType,
     },
+    /// One of the SQL/JSON query functions, e.g.
+    /// `JSON_VALUE(<json_expr>, '<json_path>' RETURNING int DEFAULT 0 ON ERROR)`
+    SQLJsonFunction {
+        function: SQLJsonFunctionName,
+        json_expr: Box<ASTNode<I>>,
+        json_path: String,
+        returning: Option<SQLType>,
+        on_empty: Option<SQLJsonBehavior<I>>,
+        on_error: Option<SQLJsonBehavior<I>>,
+    },
+    /// `<key> VALUE <value>`, an argument of the SQL/JSON `JSON_OBJECT` and
+    /// `JSON_OBJECTAGG` constructors
+    SQLJsonKeyValue {
+        key: Box<ASTNode<I>>,
+        value: Box<ASTNode<I>>,
+    },
+    /// `name => expr`, a named argument of a function call
+    SQLNamedArgument { name: I, arg: Box<ASTNode<I>> },
     /// `expr COLLATE collation`
     SQLCollate {
-        expr: Box<ASTNode>,
-        collation: SQLObjectName,
+        expr: Box<ASTNode<I>>,
+        collation: SQLObjectName<I>,
     },
     /// Nested expression e.g. `(foo > bar)` or `(1)`
-    SQLNested(Box<ASTNode>),
+    SQLNested(Box<ASTNode<I>>),
     /// Unary expression
     SQLUnary {
         operator: SQLOperator,
-        expr: Box<ASTNode>,
+        expr: Box<ASTNode<I>>,
     },
     /// SQLValue
     SQLValue(Value),
     /// Scalar function call e.g. `LEFT(foo, 5)`
     SQLFunction {
-        name: SQLObjectName,
-        args: Vec<ASTNode>,
-        over: Option<SQLWindowSpec>,
+        name: SQLObjectName<I>,
+        args: Vec<ASTNode<I>>,
+        /// `ORDER BY` inside the argument list of aggregates,
+        /// e.g. `string_agg(name, ',' ORDER BY name)`
+        order_by: Vec<SQLOrderByExpr<I>>,
+        /// `WITHIN GROUP (ORDER BY ...)` of ordered-set aggregates,
+        /// e.g. `percentile_cont(0.5) WITHIN GROUP (ORDER BY x)`
+        within_group: Vec<SQLOrderByExpr<I>>,
+        over: Option<Box<SQLWindowType<I>>>,
         // aggregate functions may specify eg `COUNT(DISTINCT x)`
         distinct: bool,
     },
@@ -120,94 +210,158 @@ pub enum ASTNode {
     /// `< 0` nor `1, 2, 3` as allowed in a <simple when clause> per
     /// https://jakewheat.github.io/sql-overview/sql-2011-foundation-grammar.html#simple-when-clause
     SQLCase {
-        operand: Option<Box<ASTNode>>,
-        conditions: Vec<ASTNode>,
-        results: Vec<ASTNode>,
-        else_result: Option<Box<ASTNode>>,
+        operand: Option<Box<ASTNode<I>>>,
+        conditions: Vec<ASTNode<I>>,
+        results: Vec<ASTNode<I>>,
+        else_result: Option<Box<ASTNode<I>>>,
     },
     /// A parenthesized subquery `(SELECT ...)`, used in expression like
     /// `SELECT (subquery) AS x` or `WHERE (subquery) = x`
-    SQLSubquery(Box<SQLQuery>),
+    SQLSubquery(Box<SQLQuery<I>>),
+    /// `GROUPING SETS ((a), (a, b), ())`, only allowed in `GROUP BY`
+    SQLGroupingSets(Vec<Vec<ASTNode<I>>>),
+    /// `CUBE(a, (b, c))`, only allowed in `GROUP BY`
+    SQLCube(Vec<Vec<ASTNode<I>>>),
+    /// `ROLLUP(a, (b, c))`, only allowed in `GROUP BY`
+    SQLRollup(Vec<Vec<ASTNode<I>>>),
 }
 
-impl ToString for ASTNode {
-    fn to_string(&self) -> String {
+impl<I: fmt::Display> fmt::Display for ASTNode<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            ASTNode::SQLIdentifier(s) => s.to_string(),
-            ASTNode::SQLWildcard => "*".to_string(),
-            ASTNode::SQLQualifiedWildcard(q) => q.join(".") + ".*",
-            ASTNode::SQLCompoundIdentifier(s) => s.join("."),
-            ASTNode::SQLIsNull(ast) => format!("{} IS NULL", ast.as_ref().to_string()),
-            ASTNode::SQLIsNotNull(ast) => format!("{} IS NOT NULL", ast.as_ref().to_string()),
+            ASTNode::SQLIdentifier(s) => write!(f, "{}", s),
+            ASTNode::SQLVariable(name) => write!(f, "{}", name),
+            ASTNode::SQLWildcard => f.write_str("*"),
+            ASTNode::SQLQualifiedWildcard(q) => write!(f, "{}.*", display_separated(q, ".")),
+            ASTNode::SQLCompoundIdentifier(s) => write!(f, "{}", display_separated(s, ".")),
+            ASTNode::SQLIsNull(ast) => write!(f, "{} IS NULL", ast),
+            ASTNode::SQLIsNotNull(ast) => write!(f, "{} IS NOT NULL", ast),
+            ASTNode::SQLIsNormalized {
+                expr,
+                form,
+                negated,
+            } => {
+                write!(f, "{} IS ", expr)?;
+                if *negated {
+                    f.write_str("NOT ")?;
+                }
+                if let Some(form) = form {
+                    write!(f, "{} ", form)?;
+                }
+                f.write_str("NORMALIZED")
+            }
             ASTNode::SQLInList {
                 expr,
                 list,
                 negated,
-            } => format!(
+            } => write!(
+                f,
                 "{} {}IN ({})",
-                expr.as_ref().to_string(),
+                expr,
                 if *negated { "NOT " } else { "" },
-                comma_separated_string(list)
+                display_comma_separated(list)
             ),
             ASTNode::SQLInSubquery {
                 expr,
                 subquery,
                 negated,
-            } => format!(
+            } => write!(
+                f,
                 "{} {}IN ({})",
-                expr.as_ref().to_string(),
+                expr,
                 if *negated { "NOT " } else { "" },
-                subquery.to_string()
+                subquery
             ),
             ASTNode::SQLBetween {
                 expr,
                 negated,
                 low,
                 high,
-            } => format!(
+            } => write!(
+                f,
                 "{} {}BETWEEN {} AND {}",
-                expr.to_string(),
+                expr,
                 if *negated { "NOT " } else { "" },
-                low.to_string(),
-                high.to_string()
-            ),
-            ASTNode::SQLBinaryExpr { left, op, right } => format!(
-                "{} {} {}",
-                left.as_ref().to_string(),
-                op.to_string(),
-                right.as_ref().to_string()
-            ),
-            ASTNode::SQLCast { expr, data_type } => format!(
-                "CAST({} AS {})",
-                expr.as_ref().to_string(),
-                data_type.to_string()
+                low,
+                high
             ),
-            ASTNode::SQLCollate { expr, collation } => format!(
-                "{} COLLATE {}",
-                expr.as_ref().to_string(),
-                collation.to_string()
+            ASTNode::SQLLike {
+                expr,
+                op,
+                pattern,
+                escape_char,
+            } => write!(
+                f,
+                "{} {} {} ESCAPE {}",
+                expr,
+                op,
+                pattern,
+                Value::SingleQuotedString(escape_char.to_string())
             ),
-            ASTNode::SQLNested(ast) => format!("({})", ast.as_ref().to_string()),
-            ASTNode::SQLUnary { operator, expr } => {
-                format!("{} {}", operator.to_string(), expr.as_ref().to_string())
+            ASTNode::SQLBinaryExpr { left, op, right } => write!(f, "{} {} {}", left, op, right),
+            ASTNode::SQLCast { expr, data_type } => write!(f, "CAST({} AS {})", expr, data_type),
+            ASTNode::SQLJsonFunction {
+                function,
+                json_expr,
+                json_path,
+                returning,
+                on_empty,
+                on_error,
+            } => {
+                write!(
+                    f,
+                    "{}({}, '{}'",
+                    function,
+                    json_expr,
+                    escape_single_quote_string(json_path)
+                )?;
+                if let Some(returning) = returning {
+                    write!(f, " RETURNING {}", returning)?;
+                }
+                if let Some(on_empty) = on_empty {
+                    write!(f, " {} ON EMPTY", on_empty)?;
+                }
+                if let Some(on_error) = on_error {
+                    write!(f, " {} ON ERROR", on_error)?;
+                }
+                f.write_str(")")
             }
-            ASTNode::SQLValue(v) => v.to_string(),
+            ASTNode::SQLJsonKeyValue { key, value } => write!(f, "{} VALUE {}", key, value),
+            ASTNode::SQLNamedArgument { name, arg } => write!(f, "{} => {}", name, arg),
+            ASTNode::SQLCollate { expr, collation } => write!(f, "{} COLLATE {}", expr, collation),
+            ASTNode::SQLNested(ast) => write!(f, "({})", ast),
+            ASTNode::SQLUnary { operator, expr } => write!(f, "{} {}", operator, expr),
+            ASTNode::SQLValue(v) => write!(f, "{}", v),
             ASTNode::SQLFunction {
                 name,
                 args,
+                order_by,
+                within_group,
                 over,
                 distinct,
             } => {
-                let mut s = format!(
-                    "{}({}{})",
-                    name.to_string(),
+                write!(
+                    f,
+                    "{}({}{}",
+                    name,
                     if *distinct { "DISTINCT " } else { "" },
-                    comma_separated_string(args)
-                );
+                    display_comma_separated(args)
+                )?;
+                if !order_by.is_empty() {
+                    write!(f, " ORDER BY {}", display_comma_separated(order_by))?;
+                }
+                f.write_str(")")?;
+                if !within_group.is_empty() {
+                    write!(
+                        f,
+                        " WITHIN GROUP (ORDER BY {})",
+                        display_comma_separated(within_group)
+                    )?;
+                }
                 if let Some(o) = over {
-                    s += &format!(" OVER ({})", o.to_string())
+                    write!(f, " OVER {}", o)?;
                 }
-                s
+                Ok(())
             }
             ASTNode::SQLCase {
                 operand,
@@ -215,72 +369,198 @@ impl ToString for ASTNode {
                 results,
                 else_result,
             } => {
-                let mut s = "CASE".to_string();
+                f.write_str("CASE")?;
                 if let Some(operand) = operand {
-                    s += &format!(" {}", operand.to_string());
-                }
-                s += &conditions
-                    .iter()
-                    .zip(results)
-                    .map(|(c, r)| format!(" WHEN {} THEN {}", c.to_string(), r.to_string()))
-                    .collect::<Vec<String>>()
-                    .join("");
+                    write!(f, " {}", operand)?;
+                }
+                for (c, r) in conditions.iter().zip(results) {
+                    write!(f, " WHEN {} THEN {}", c, r)?;
+                }
                 if let Some(else_result) = else_result {
-                    s += &format!(" ELSE {}", else_result.to_string())
+                    write!(f, " ELSE {}", else_result)?;
+                }
+                f.write_str(" END")
+            }
+            ASTNode::SQLSubquery(s) => write!(f, "({})", s),
+            ASTNode::SQLGroupingSets(sets) => {
+                f.write_str("GROUPING SETS (")?;
+                let mut delim = "";
+                for set in sets {
+                    write!(f, "{}({})", delim, display_comma_separated(set))?;
+                    delim = ", ";
                 }
-                s + " END"
+                f.write_str(")")
             }
-            ASTNode::SQLSubquery(s) => format!("({})", s.to_string()),
+            ASTNode::SQLCube(sets) => write!(f, "CUBE({})", display_grouping_sets(sets)),
+            ASTNode::SQLRollup(sets) => write!(f, "ROLLUP({})", display_grouping_sets(sets)),
+        }
+    }
+}
+
+/// Displays the elements of `CUBE` or `ROLLUP`, which are parenthesized
+/// unless they consist of a single expression.
+fn display_grouping_sets<I: fmt::Display>(sets: &[Vec<ASTNode<I>>]) -> impl fmt::Display + '_ {
+    struct GroupingSets<'a, I>(&'a [Vec<ASTNode<I>>]);
+    impl<'a, I: fmt::Display> fmt::Display for GroupingSets<'a, I> {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            let mut delim = "";
+            for set in self.0 {
+                f.write_str(delim)?;
+                delim = ", ";
+                if set.len() == 1 {
+                    write!(f, "{}", set[0])?;
+                } else {
+                    write!(f, "({})", display_comma_separated(set))?;
+                }
+            }
+            Ok(())
+        }
+    }
+    GroupingSets(sets)
+}
+
+/// A Unicode normalization form, as used by `IS NORMALIZED`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SQLNormalForm {
+    NFC,
+    NFD,
+    NFKC,
+    NFKD,
+}
+
+impl fmt::Display for SQLNormalForm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            SQLNormalForm::NFC => "NFC",
+            SQLNormalForm::NFD => "NFD",
+            SQLNormalForm::NFKC => "NFKC",
+            SQLNormalForm::NFKD => "NFKD",
+        })
+    }
+}
+
+/// The SQL/JSON query functions, represented by `ASTNode::SQLJsonFunction`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SQLJsonFunctionName {
+    JsonValue,
+    JsonQuery,
+    JsonExists,
+}
+
+impl fmt::Display for SQLJsonFunctionName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            SQLJsonFunctionName::JsonValue => "JSON_VALUE",
+            SQLJsonFunctionName::JsonQuery => "JSON_QUERY",
+            SQLJsonFunctionName::JsonExists => "JSON_EXISTS",
+        })
+    }
+}
+
+/// What an SQL/JSON query function returns `ON EMPTY` or `ON ERROR`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SQLJsonBehavior<I = SQLIdent> {
+    Error,
+    Null,
+    True,
+    False,
+    Unknown,
+    EmptyArray,
+    EmptyObject,
+    Default(Box<ASTNode<I>>),
+}
+
+impl<I: fmt::Display> fmt::Display for SQLJsonBehavior<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SQLJsonBehavior::Error => f.write_str("ERROR"),
+            SQLJsonBehavior::Null => f.write_str("NULL"),
+            SQLJsonBehavior::True => f.write_str("TRUE"),
+            SQLJsonBehavior::False => f.write_str("FALSE"),
+            SQLJsonBehavior::Unknown => f.write_str("UNKNOWN"),
+            SQLJsonBehavior::EmptyArray => f.write_str("EMPTY ARRAY"),
+            SQLJsonBehavior::EmptyObject => f.write_str("EMPTY OBJECT"),
+            SQLJsonBehavior::Default(expr) => write!(f, "DEFAULT {}", expr),
+        }
+    }
+}
+
+/// The window following `OVER`: either an inline specification, or the
+/// name of a window defined in the `WINDOW` clause
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SQLWindowType<I = SQLIdent> {
+    /// `OVER (PARTITION BY .. ORDER BY .. etc.)`
+    WindowSpec(SQLWindowSpec<I>),
+    /// `OVER window_name`
+    NamedWindow(I),
+}
+
+impl<I: fmt::Display> fmt::Display for SQLWindowType<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SQLWindowType::WindowSpec(spec) => write!(f, "({})", spec),
+            SQLWindowType::NamedWindow(name) => write!(f, "{}", name),
         }
     }
 }
 
 /// A window specification (i.e. `OVER (PARTITION BY .. ORDER BY .. etc.)`)
 #[derive(Debug, Clone, PartialEq)]
-pub struct SQLWindowSpec {
-    pub partition_by: Vec<ASTNode>,
-    pub order_by: Vec<SQLOrderByExpr>,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SQLWindowSpec<I = SQLIdent> {
+    /// The existing window this specification is based on, e.g. `w1` in
+    /// `WINDOW w2 AS (w1 ORDER BY a)`
+    pub window_name: Option<I>,
+    pub partition_by: Vec<ASTNode<I>>,
+    pub order_by: Vec<SQLOrderByExpr<I>>,
     pub window_frame: Option<SQLWindowFrame>,
 }
 
-impl ToString for SQLWindowSpec {
-    fn to_string(&self) -> String {
-        let mut clauses = vec![];
+impl<I: fmt::Display> fmt::Display for SQLWindowSpec<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut delim = "";
+        if let Some(window_name) = &self.window_name {
+            delim = " ";
+            write!(f, "{}", window_name)?;
+        }
         if !self.partition_by.is_empty() {
-            clauses.push(format!(
+            f.write_str(delim)?;
+            delim = " ";
+            write!(
+                f,
                 "PARTITION BY {}",
-                comma_separated_string(&self.partition_by)
-            ))
-        };
+                display_comma_separated(&self.partition_by)
+            )?;
+        }
         if !self.order_by.is_empty() {
-            clauses.push(format!(
-                "ORDER BY {}",
-                comma_separated_string(&self.order_by)
-            ))
-        };
+            f.write_str(delim)?;
+            delim = " ";
+            write!(f, "ORDER BY {}", display_comma_separated(&self.order_by))?;
+        }
         if let Some(window_frame) = &self.window_frame {
+            f.write_str(delim)?;
             if let Some(end_bound) = &window_frame.end_bound {
-                clauses.push(format!(
+                write!(
+                    f,
                     "{} BETWEEN {} AND {}",
-                    window_frame.units.to_string(),
-                    window_frame.start_bound.to_string(),
-                    end_bound.to_string()
-                ));
+                    window_frame.units, window_frame.start_bound, end_bound
+                )?;
             } else {
-                clauses.push(format!(
-                    "{} {}",
-                    window_frame.units.to_string(),
-                    window_frame.start_bound.to_string()
-                ));
+                write!(f, "{} {}", window_frame.units, window_frame.start_bound)?;
             }
         }
-        clauses.join(" ")
+        Ok(())
     }
 }
 
 /// Specifies the data processed by a window function, e.g.
 /// `RANGE UNBOUNDED PRECEDING` or `ROWS BETWEEN 5 PRECEDING AND CURRENT ROW`.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SQLWindowFrame {
     pub units: SQLWindowFrameUnits,
     pub start_bound: SQLWindowFrameBound,
@@ -290,19 +570,20 @@ pub struct SQLWindowFrame {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SQLWindowFrameUnits {
     Rows,
     Range,
     Groups,
 }
 
-impl ToString for SQLWindowFrameUnits {
-    fn to_string(&self) -> String {
-        match self {
-            SQLWindowFrameUnits::Rows => "ROWS".to_string(),
-            SQLWindowFrameUnits::Range => "RANGE".to_string(),
-            SQLWindowFrameUnits::Groups => "GROUPS".to_string(),
-        }
+impl fmt::Display for SQLWindowFrameUnits {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            SQLWindowFrameUnits::Rows => "ROWS",
+            SQLWindowFrameUnits::Range => "RANGE",
+            SQLWindowFrameUnits::Groups => "GROUPS",
+        })
     }
 }
 
@@ -323,6 +604,7 @@ impl FromStr for SQLWindowFrameUnits {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SQLWindowFrameBound {
     /// "CURRENT ROW"
     CurrentRow,
@@ -333,265 +615,790 @@ pub enum SQLWindowFrameBound {
     Following(Option<u64>),
 }
 
-impl ToString for SQLWindowFrameBound {
-    fn to_string(&self) -> String {
+impl fmt::Display for SQLWindowFrameBound {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            SQLWindowFrameBound::CurrentRow => "CURRENT ROW".to_string(),
-            SQLWindowFrameBound::Preceding(None) => "UNBOUNDED PRECEDING".to_string(),
-            SQLWindowFrameBound::Following(None) => "UNBOUNDED FOLLOWING".to_string(),
-            SQLWindowFrameBound::Preceding(Some(n)) => format!("{} PRECEDING", n),
-            SQLWindowFrameBound::Following(Some(n)) => format!("{} FOLLOWING", n),
+            SQLWindowFrameBound::CurrentRow => f.write_str("CURRENT ROW"),
+            SQLWindowFrameBound::Preceding(None) => f.write_str("UNBOUNDED PRECEDING"),
+            SQLWindowFrameBound::Following(None) => f.write_str("UNBOUNDED FOLLOWING"),
+            SQLWindowFrameBound::Preceding(Some(n)) => write!(f, "{} PRECEDING", n),
+            SQLWindowFrameBound::Following(Some(n)) => write!(f, "{} FOLLOWING", n),
         }
     }
 }
 
 /// A top-level statement (SELECT, INSERT, CREATE, etc.)
 #[derive(Debug, Clone, PartialEq)]
-pub enum SQLStatement {
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SQLStatement<I = SQLIdent> {
     /// SELECT
-    SQLQuery(Box<SQLQuery>),
+    SQLQuery(Box<SQLQuery<I>>),
     /// INSERT
     SQLInsert {
+        /// MySQL-specific `LOW_PRIORITY | DELAYED | HIGH_PRIORITY`
+        priority: Option<SQLInsertPriority>,
+        /// MySQL-specific `IGNORE`
+        ignore: bool,
         /// TABLE
-        table_name: SQLObjectName,
+        table_name: SQLObjectName<I>,
+        /// MySQL-specific `PARTITION (p0, p1)` selection
+        partitions: Vec<I>,
         /// COLUMNS
-        columns: Vec<SQLIdent>,
-        /// VALUES (vector of rows to insert)
-        values: Vec<Vec<ASTNode>>,
+        columns: Vec<I>,
+        /// A SQL query that specifies what to insert, e.g. `VALUES (...)`
+        source: Box<SQLQuery<I>>,
+        /// What to do when a row to insert conflicts with an existing one
+        on_conflict: Option<SQLOnConflict<I>>,
+        /// Postgres-specific `RETURNING <select list>`
+        returning: Option<Vec<SQLSelectItem<I>>>,
     },
     SQLCopy {
         /// TABLE
-        table_name: SQLObjectName,
+        table_name: SQLObjectName<I>,
         /// COLUMNS
-        columns: Vec<SQLIdent>,
+        columns: Vec<I>,
         /// VALUES a vector of values to be copied
         values: Vec<Option<String>>,
     },
     /// UPDATE
     SQLUpdate {
         /// TABLE
-        table_name: SQLObjectName,
+        table_name: SQLObjectName<I>,
         /// Column assignments
-        assignments: Vec<SQLAssignment>,
+        assignments: Vec<SQLAssignment<I>>,
         /// WHERE
-        selection: Option<ASTNode>,
+        selection: Option<ASTNode<I>>,
+        /// Postgres-specific `RETURNING <select list>`
+        returning: Option<Vec<SQLSelectItem<I>>>,
     },
     /// DELETE
     SQLDelete {
         /// FROM
-        table_name: SQLObjectName,
+        table_name: SQLObjectName<I>,
         /// WHERE
-        selection: Option<ASTNode>,
+        selection: Option<ASTNode<I>>,
+        /// Postgres-specific `RETURNING <select list>`
+        returning: Option<Vec<SQLSelectItem<I>>>,
     },
     /// CREATE VIEW
     SQLCreateView {
         /// View name
-        name: SQLObjectName,
-        query: Box<SQLQuery>,
+        name: SQLObjectName<I>,
+        /// Optional list of names for the view's columns
+        columns: Vec<I>,
+        query: Box<SQLQuery<I>>,
         materialized: bool,
+        /// `WITH (option = value, ...)`
+        with_options: Vec<SQLOption<I>>,
     },
     /// CREATE TABLE
     SQLCreateTable {
         /// Table name
-        name: SQLObjectName,
+        name: SQLObjectName<I>,
         /// Optional schema
-        columns: Vec<SQLColumnDef>,
+        columns: Vec<SQLColumnDef<I>>,
+        /// Table-level constraints, e.g. `PRIMARY KEY (a, b)`
+        constraints: Vec<TableConstraint<I>>,
         external: bool,
         file_format: Option<FileFormat>,
         location: Option<String>,
+        if_not_exists: bool,
+    },
+    /// CREATE INDEX
+    SQLCreateIndex {
+        unique: bool,
+        if_not_exists: bool,
+        name: SQLObjectName<I>,
+        table_name: SQLObjectName<I>,
+        /// The index access method, e.g. `gin` in `USING gin`
+        method: Option<I>,
+        columns: Vec<I>,
+    },
+    /// CREATE SCHEMA
+    SQLCreateSchema {
+        schema_name: SQLObjectName<I>,
+        if_not_exists: bool,
+    },
+    /// CREATE FUNCTION
+    SQLCreateFunction {
+        or_replace: bool,
+        name: SQLObjectName<I>,
+        args: Vec<SQLFunctionArgDef<I>>,
+        return_type: SQLType,
+        /// The function body (`AS '...'`), as a string literal. Its contents
+        /// are preserved verbatim and are not parsed.
+        body: Value,
+        /// `LANGUAGE <lang>`
+        language: Option<I>,
+        /// `IMMUTABLE | STABLE | VOLATILE`
+        behavior: Option<SQLFunctionBehavior>,
+    },
+    /// CREATE SEQUENCE
+    SQLCreateSequence {
+        name: SQLObjectName<I>,
+        temporary: bool,
+        if_not_exists: bool,
+        /// Options in canonical order, see `SQLSequenceOption`
+        options: Vec<SQLSequenceOption>,
+    },
+    /// ALTER SEQUENCE
+    SQLAlterSequence {
+        name: SQLObjectName<I>,
+        options: Vec<SQLSequenceOption>,
     },
     /// ALTER TABLE
     SQLAlterTable {
         /// Table name
-        name: SQLObjectName,
-        operation: AlterOperation,
+        name: SQLObjectName<I>,
+        operation: AlterOperation<I>,
     },
     /// DROP TABLE
     SQLDrop {
         object_type: SQLObjectType,
         if_exists: bool,
-        names: Vec<SQLObjectName>,
+        names: Vec<SQLObjectName<I>>,
         cascade: bool,
     },
+    /// USE <db_name>
+    SQLUse { db_name: SQLObjectName<I> },
+    /// GRANT privileges ON objects TO grantees [ WITH GRANT OPTION ]
+    SQLGrant {
+        privileges: Privileges<I>,
+        objects: GrantObjects<I>,
+        grantees: Vec<I>,
+        with_grant_option: bool,
+    },
+    /// GRANT roles TO grantees [ WITH ADMIN OPTION ], granting membership
+    /// in the roles rather than privileges on objects
+    SQLGrantRole {
+        roles: Vec<I>,
+        grantees: Vec<I>,
+        with_admin_option: bool,
+    },
+    /// SHOW <variable>
+    SQLShowVariable { variable: I },
+    /// SHOW TABLES [ { FROM | IN } db_name ] [ LIKE 'pattern' | WHERE expr ]
+    SQLShowTables {
+        db_name: Option<SQLObjectName<I>>,
+        filter: Option<SQLShowStatementFilter<I>>,
+    },
+    /// SHOW COLUMNS { FROM | IN } table_name [ LIKE 'pattern' | WHERE expr ]
+    SQLShowColumns {
+        table_name: SQLObjectName<I>,
+        filter: Option<SQLShowStatementFilter<I>>,
+    },
+    /// SHOW VARIABLES [ LIKE 'pattern' | WHERE expr ]
+    SQLShowVariables {
+        filter: Option<SQLShowStatementFilter<I>>,
+    },
 }
 
-impl ToString for SQLStatement {
-    fn to_string(&self) -> String {
+impl<I: fmt::Display> fmt::Display for SQLStatement<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            SQLStatement::SQLQuery(s) => s.to_string(),
+            SQLStatement::SQLQuery(s) => write!(f, "{}", s),
             SQLStatement::SQLInsert {
+                priority,
+                ignore,
                 table_name,
+                partitions,
                 columns,
-                values,
+                source,
+                on_conflict,
+                returning,
             } => {
-                let mut s = format!("INSERT INTO {}", table_name.to_string());
+                f.write_str("INSERT")?;
+                if let Some(priority) = priority {
+                    write!(f, " {}", priority)?;
+                }
+                if *ignore {
+                    f.write_str(" IGNORE")?;
+                }
+                write!(f, " INTO {}", table_name)?;
+                if !partitions.is_empty() {
+                    write!(f, " PARTITION ({})", display_comma_separated(partitions))?;
+                }
                 if !columns.is_empty() {
-                    s += &format!(" ({})", columns.join(", "));
+                    write!(f, " ({})", display_comma_separated(columns))?;
                 }
-                if !values.is_empty() {
-                    s += &format!(
-                        " VALUES({})",
-                        values
-                            .iter()
-                            .map(|row| comma_separated_string(row))
-                            .collect::<Vec<String>>()
-                            .join(", ")
-                    );
+                write!(f, " {}", source)?;
+                if let Some(on_conflict) = on_conflict {
+                    write!(f, " {}", on_conflict)?;
                 }
-                s
+                if let Some(returning) = returning {
+                    write!(f, " RETURNING {}", display_comma_separated(returning))?;
+                }
+                Ok(())
             }
             SQLStatement::SQLCopy {
                 table_name,
                 columns,
                 values,
             } => {
-                let mut s = format!("COPY {}", table_name.to_string());
+                write!(f, "COPY {}", table_name)?;
                 if !columns.is_empty() {
-                    s += &format!(" ({})", comma_separated_string(columns));
+                    write!(f, " ({})", display_comma_separated(columns))?;
                 }
-                s += " FROM stdin; ";
+                f.write_str(" FROM stdin; ")?;
                 if !values.is_empty() {
-                    s += &format!(
-                        "\n{}",
-                        values
-                            .iter()
-                            .map(|v| v.clone().unwrap_or_else(|| "\\N".to_string()))
-                            .collect::<Vec<String>>()
-                            .join("\t")
-                    );
-                }
-                s += "\n\\.";
-                s
+                    f.write_str("\n")?;
+                    let mut delim = "";
+                    for v in values {
+                        f.write_str(delim)?;
+                        delim = "\t";
+                        f.write_str(v.as_ref().map(String::as_str).unwrap_or("\\N"))?;
+                    }
+                }
+                f.write_str("\n\\.")
             }
             SQLStatement::SQLUpdate {
                 table_name,
                 assignments,
                 selection,
+                returning,
             } => {
-                let mut s = format!("UPDATE {}", table_name.to_string());
+                write!(f, "UPDATE {}", table_name)?;
                 if !assignments.is_empty() {
-                    s += &comma_separated_string(assignments);
+                    write!(f, " SET {}", display_comma_separated(assignments))?;
                 }
                 if let Some(selection) = selection {
-                    s += &format!(" WHERE {}", selection.to_string());
+                    write!(f, " WHERE {}", selection)?;
                 }
-                s
+                if let Some(returning) = returning {
+                    write!(f, " RETURNING {}", display_comma_separated(returning))?;
+                }
+                Ok(())
             }
             SQLStatement::SQLDelete {
                 table_name,
                 selection,
+                returning,
             } => {
-                let mut s = format!("DELETE FROM {}", table_name.to_string());
+                write!(f, "DELETE FROM {}", table_name)?;
                 if let Some(selection) = selection {
-                    s += &format!(" WHERE {}", selection.to_string());
+                    write!(f, " WHERE {}", selection)?;
                 }
-                s
+                if let Some(returning) = returning {
+                    write!(f, " RETURNING {}", display_comma_separated(returning))?;
+                }
+                Ok(())
             }
             SQLStatement::SQLCreateView {
                 name,
+                columns,
                 query,
                 materialized,
+                with_options,
             } => {
-                let modifier = if *materialized { " MATERIALIZED" } else { "" };
-                format!(
-                    "CREATE{} VIEW {} AS {}",
-                    modifier,
-                    name.to_string(),
-                    query.to_string()
-                )
+                write!(
+                    f,
+                    "CREATE{} VIEW {}",
+                    if *materialized { " MATERIALIZED" } else { "" },
+                    name
+                )?;
+                if !columns.is_empty() {
+                    write!(f, " ({})", display_comma_separated(columns))?;
+                }
+                if !with_options.is_empty() {
+                    write!(f, " WITH ({})", display_comma_separated(with_options))?;
+                }
+                write!(f, " AS {}", query)
             }
             SQLStatement::SQLCreateTable {
                 name,
                 columns,
+                constraints,
                 external,
                 file_format,
                 location,
-            } if *external => format!(
-                "CREATE EXTERNAL TABLE {} ({}) STORED AS {} LOCATION '{}'",
-                name.to_string(),
-                comma_separated_string(columns),
-                file_format.as_ref().unwrap().to_string(),
-                location.as_ref().unwrap()
-            ),
-            SQLStatement::SQLCreateTable { name, columns, .. } => format!(
-                "CREATE TABLE {} ({})",
-                name.to_string(),
-                comma_separated_string(columns)
+                if_not_exists,
+            } => {
+                write!(
+                    f,
+                    "CREATE {}TABLE {}{} ({}",
+                    if *external { "EXTERNAL " } else { "" },
+                    if *if_not_exists { "IF NOT EXISTS " } else { "" },
+                    name,
+                    display_comma_separated(columns)
+                )?;
+                if !columns.is_empty() && !constraints.is_empty() {
+                    f.write_str(", ")?;
+                }
+                write!(f, "{})", display_comma_separated(constraints))?;
+                if *external {
+                    write!(
+                        f,
+                        " STORED AS {} LOCATION '{}'",
+                        file_format.as_ref().unwrap(),
+                        location.as_ref().unwrap()
+                    )?;
+                }
+                Ok(())
+            }
+            SQLStatement::SQLCreateIndex {
+                unique,
+                if_not_exists,
+                name,
+                table_name,
+                method,
+                columns,
+            } => {
+                write!(
+                    f,
+                    "CREATE {}INDEX {}{} ON {}",
+                    if *unique { "UNIQUE " } else { "" },
+                    if *if_not_exists { "IF NOT EXISTS " } else { "" },
+                    name,
+                    table_name
+                )?;
+                if let Some(method) = method {
+                    write!(f, " USING {}", method)?;
+                }
+                write!(f, " ({})", display_comma_separated(columns))
+            }
+            SQLStatement::SQLCreateSchema {
+                schema_name,
+                if_not_exists,
+            } => write!(
+                f,
+                "CREATE SCHEMA {}{}",
+                if *if_not_exists { "IF NOT EXISTS " } else { "" },
+                schema_name
             ),
+            SQLStatement::SQLCreateFunction {
+                or_replace,
+                name,
+                args,
+                return_type,
+                body,
+                language,
+                behavior,
+            } => {
+                write!(
+                    f,
+                    "CREATE {}FUNCTION {}({}) RETURNS {} AS {}",
+                    if *or_replace { "OR REPLACE " } else { "" },
+                    name,
+                    display_comma_separated(args),
+                    return_type,
+                    body
+                )?;
+                if let Some(language) = language {
+                    write!(f, " LANGUAGE {}", language)?;
+                }
+                if let Some(behavior) = behavior {
+                    write!(f, " {}", behavior)?;
+                }
+                Ok(())
+            }
+            SQLStatement::SQLCreateSequence {
+                name,
+                temporary,
+                if_not_exists,
+                options,
+            } => {
+                write!(
+                    f,
+                    "CREATE {}SEQUENCE {}{}",
+                    if *temporary { "TEMPORARY " } else { "" },
+                    if *if_not_exists { "IF NOT EXISTS " } else { "" },
+                    name
+                )?;
+                for option in options {
+                    write!(f, " {}", option)?;
+                }
+                Ok(())
+            }
+            SQLStatement::SQLAlterSequence { name, options } => {
+                write!(f, "ALTER SEQUENCE {}", name)?;
+                for option in options {
+                    write!(f, " {}", option)?;
+                }
+                Ok(())
+            }
             SQLStatement::SQLAlterTable { name, operation } => {
-                format!("ALTER TABLE {} {}", name.to_string(), operation.to_string())
+                write!(f, "ALTER TABLE {} {}", name, operation)
             }
             SQLStatement::SQLDrop {
                 object_type,
                 if_exists,
                 names,
                 cascade,
-            } => format!(
+            } => write!(
+                f,
                 "DROP {}{} {}{}",
-                object_type.to_string(),
+                object_type,
                 if *if_exists { " IF EXISTS" } else { "" },
-                comma_separated_string(&names),
+                display_comma_separated(names),
                 if *cascade { " CASCADE" } else { "" },
             ),
+            SQLStatement::SQLUse { db_name } => write!(f, "USE {}", db_name),
+            SQLStatement::SQLGrant {
+                privileges,
+                objects,
+                grantees,
+                with_grant_option,
+            } => write!(
+                f,
+                "GRANT {} ON {} TO {}{}",
+                privileges,
+                objects,
+                display_comma_separated(grantees),
+                if *with_grant_option {
+                    " WITH GRANT OPTION"
+                } else {
+                    ""
+                },
+            ),
+            SQLStatement::SQLGrantRole {
+                roles,
+                grantees,
+                with_admin_option,
+            } => write!(
+                f,
+                "GRANT {} TO {}{}",
+                display_comma_separated(roles),
+                display_comma_separated(grantees),
+                if *with_admin_option {
+                    " WITH ADMIN OPTION"
+                } else {
+                    ""
+                },
+            ),
+            SQLStatement::SQLShowVariable { variable } => write!(f, "SHOW {}", variable),
+            SQLStatement::SQLShowTables { db_name, filter } => {
+                f.write_str("SHOW TABLES")?;
+                if let Some(db_name) = db_name {
+                    write!(f, " FROM {}", db_name)?;
+                }
+                if let Some(filter) = filter {
+                    write!(f, " {}", filter)?;
+                }
+                Ok(())
+            }
+            SQLStatement::SQLShowColumns { table_name, filter } => {
+                write!(f, "SHOW COLUMNS FROM {}", table_name)?;
+                if let Some(filter) = filter {
+                    write!(f, " {}", filter)?;
+                }
+                Ok(())
+            }
+            SQLStatement::SQLShowVariables { filter } => {
+                f.write_str("SHOW VARIABLES")?;
+                if let Some(filter) = filter {
+                    write!(f, " {}", filter)?;
+                }
+                Ok(())
+            }
         }
     }
 }
 
 /// A name of a table, view, custom type, etc., possibly multi-part, i.e. db.schema.obj
 #[derive(Debug, Clone, PartialEq)]
-pub struct SQLObjectName(pub Vec<SQLIdent>);
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SQLObjectName<I = SQLIdent>(pub Vec<I>);
 
-impl ToString for SQLObjectName {
-    fn to_string(&self) -> String {
-        self.0.join(".")
+impl<I: fmt::Display> fmt::Display for SQLObjectName<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", display_separated(&self.0, "."))
+    }
+}
+
+/// The MySQL-specific priority of an `INSERT` statement
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SQLInsertPriority {
+    LowPriority,
+    Delayed,
+    HighPriority,
+}
+
+impl fmt::Display for SQLInsertPriority {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            SQLInsertPriority::LowPriority => "LOW_PRIORITY",
+            SQLInsertPriority::Delayed => "DELAYED",
+            SQLInsertPriority::HighPriority => "HIGH_PRIORITY",
+        })
+    }
+}
+
+/// SQL assignment `foo = expr` as used in SQLUpdate and SQLOnConflict
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SQLAssignment<I = SQLIdent> {
+    pub id: I,
+    pub value: ASTNode<I>,
+}
+
+impl<I: fmt::Display> fmt::Display for SQLAssignment<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} = {}", self.id, self.value)
+    }
+}
+
+/// The clause of an `INSERT` saying what to do with rows that would violate
+/// a unique constraint
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SQLOnConflict<I = SQLIdent> {
+    /// `ON CONFLICT [ (<conflict_target>) ] DO NOTHING`
+    DoNothing { conflict_target: Vec<I> },
+    /// `ON CONFLICT [ (<conflict_target>) ] DO UPDATE SET <assignments>
+    /// [ WHERE <selection> ]`
+    DoUpdate {
+        conflict_target: Vec<I>,
+        assignments: Vec<SQLAssignment<I>>,
+        selection: Option<Box<ASTNode<I>>>,
+    },
+    /// MySQL-specific `ON DUPLICATE KEY UPDATE <assignments>`
+    DuplicateKeyUpdate(Vec<SQLAssignment<I>>),
+}
+
+impl<I: fmt::Display> fmt::Display for SQLOnConflict<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn conflict_target<I: fmt::Display>(f: &mut fmt::Formatter, columns: &[I]) -> fmt::Result {
+            f.write_str("ON CONFLICT")?;
+            if !columns.is_empty() {
+                write!(f, " ({})", display_comma_separated(columns))?;
+            }
+            Ok(())
+        }
+        match self {
+            SQLOnConflict::DoNothing {
+                conflict_target: columns,
+            } => {
+                conflict_target(f, columns)?;
+                f.write_str(" DO NOTHING")
+            }
+            SQLOnConflict::DoUpdate {
+                conflict_target: columns,
+                assignments,
+                selection,
+            } => {
+                conflict_target(f, columns)?;
+                write!(f, " DO UPDATE SET {}", display_comma_separated(assignments))?;
+                if let Some(selection) = selection {
+                    write!(f, " WHERE {}", selection)?;
+                }
+                Ok(())
+            }
+            SQLOnConflict::DuplicateKeyUpdate(assignments) => write!(
+                f,
+                "ON DUPLICATE KEY UPDATE {}",
+                display_comma_separated(assignments)
+            ),
+        }
     }
 }
 
-/// SQL assignment `foo = expr` as used in SQLUpdate
+/// The optional filter at the end of `SHOW TABLES`, `SHOW COLUMNS`, etc.
 #[derive(Debug, Clone, PartialEq)]
-pub struct SQLAssignment {
-    id: SQLIdent,
-    value: ASTNode,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SQLShowStatementFilter<I = SQLIdent> {
+    /// `LIKE 'pattern'`
+    Like(String),
+    /// `WHERE expr`
+    Where(ASTNode<I>),
 }
 
-impl ToString for SQLAssignment {
-    fn to_string(&self) -> String {
-        format!("SET {} = {}", self.id, self.value.to_string())
+impl<I: fmt::Display> fmt::Display for SQLShowStatementFilter<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SQLShowStatementFilter::Like(pattern) => {
+                write!(f, "LIKE {}", Value::SingleQuotedString(pattern.clone()))
+            }
+            SQLShowStatementFilter::Where(expr) => write!(f, "WHERE {}", expr),
+        }
     }
 }
 
 /// SQL column definition
 #[derive(Debug, Clone, PartialEq)]
-pub struct SQLColumnDef {
-    pub name: SQLIdent,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SQLColumnDef<I = SQLIdent> {
+    pub name: I,
     pub data_type: SQLType,
-    pub is_primary: bool,
-    pub is_unique: bool,
-    pub default: Option<ASTNode>,
-    pub allow_null: bool,
+    /// Column-level constraints and options, in the order they were declared
+    pub options: Vec<ColumnOptionDef<I>>,
 }
 
-impl ToString for SQLColumnDef {
-    fn to_string(&self) -> String {
-        let mut s = format!("{} {}", self.name, self.data_type.to_string());
-        if self.is_primary {
-            s += " PRIMARY KEY";
+impl<I: fmt::Display> fmt::Display for SQLColumnDef<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.name, self.data_type)?;
+        for option in &self.options {
+            write!(f, " {}", option)?;
         }
-        if self.is_unique {
-            s += " UNIQUE";
+        Ok(())
+    }
+}
+
+/// An optionally named `ColumnOption`: `[ CONSTRAINT <name> ] <column-option>`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ColumnOptionDef<I = SQLIdent> {
+    pub name: Option<I>,
+    pub option: ColumnOption<I>,
+}
+
+impl<I: fmt::Display> fmt::Display for ColumnOptionDef<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(ref name) = self.name {
+            write!(f, "CONSTRAINT {} ", name)?;
         }
-        if let Some(ref default) = self.default {
-            s += &format!(" DEFAULT {}", default.to_string());
+        write!(f, "{}", self.option)
+    }
+}
+
+/// A constraint or option of a single column in `CREATE TABLE`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ColumnOption<I = SQLIdent> {
+    /// `NULL`
+    Null,
+    /// `NOT NULL`
+    NotNull,
+    /// `DEFAULT <restricted-expr>`
+    Default(ASTNode<I>),
+    /// `PRIMARY KEY` or `UNIQUE`
+    Unique { is_primary: bool },
+    /// `REFERENCES <foreign_table> [ (<referred_columns>) ]`
+    ForeignKey {
+        foreign_table: SQLObjectName<I>,
+        referred_columns: Vec<I>,
+    },
+    /// `CHECK (<expr>)`
+    Check(ASTNode<I>),
+}
+
+impl<I: fmt::Display> fmt::Display for ColumnOption<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ColumnOption::Null => f.write_str("NULL"),
+            ColumnOption::NotNull => f.write_str("NOT NULL"),
+            ColumnOption::Default(expr) => write!(f, "DEFAULT {}", expr),
+            ColumnOption::Unique { is_primary } => {
+                f.write_str(if *is_primary { "PRIMARY KEY" } else { "UNIQUE" })
+            }
+            ColumnOption::ForeignKey {
+                foreign_table,
+                referred_columns,
+            } => {
+                write!(f, "REFERENCES {}", foreign_table)?;
+                if !referred_columns.is_empty() {
+                    write!(f, "({})", display_comma_separated(referred_columns))?;
+                }
+                Ok(())
+            }
+            ColumnOption::Check(expr) => write!(f, "CHECK ({})", expr),
         }
-        if !self.allow_null {
-            s += " NOT NULL";
+    }
+}
+
+/// A `name = value` option, as used in `CREATE VIEW ... WITH (...)`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SQLOption<I = SQLIdent> {
+    pub name: I,
+    pub value: Value,
+}
+
+impl<I: fmt::Display> fmt::Display for SQLOption<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} = {}", self.name, self.value)
+    }
+}
+
+/// A parameter in the `CREATE FUNCTION` parameter list: `[ name ] data_type`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SQLFunctionArgDef<I = SQLIdent> {
+    pub name: Option<I>,
+    pub data_type: SQLType,
+}
+
+impl<I: fmt::Display> fmt::Display for SQLFunctionArgDef<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "{} {}", name, self.data_type),
+            None => write!(f, "{}", self.data_type),
+        }
+    }
+}
+
+/// The volatility category of a function, as specified in `CREATE FUNCTION`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SQLFunctionBehavior {
+    Immutable,
+    Stable,
+    Volatile,
+}
+
+impl fmt::Display for SQLFunctionBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            SQLFunctionBehavior::Immutable => "IMMUTABLE",
+            SQLFunctionBehavior::Stable => "STABLE",
+            SQLFunctionBehavior::Volatile => "VOLATILE",
+        })
+    }
+}
+
+/// An option of `CREATE SEQUENCE` or `ALTER SEQUENCE`. The options may
+/// appear in any order in the source, but the parser stores them in the
+/// order in which the variants are declared here.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SQLSequenceOption {
+    /// `INCREMENT [ BY ] <n>`
+    IncrementBy(i64),
+    /// `MINVALUE <n>` or `NO MINVALUE`
+    MinValue(Option<i64>),
+    /// `MAXVALUE <n>` or `NO MAXVALUE`
+    MaxValue(Option<i64>),
+    /// `START [ WITH ] <n>`
+    StartWith(i64),
+    /// `CACHE <n>`
+    Cache(i64),
+    /// `CYCLE` or `NO CYCLE`
+    Cycle(bool),
+}
+
+impl SQLSequenceOption {
+    /// The position of this option in the canonical order
+    pub fn canonical_position(&self) -> usize {
+        match self {
+            SQLSequenceOption::IncrementBy(_) => 0,
+            SQLSequenceOption::MinValue(_) => 1,
+            SQLSequenceOption::MaxValue(_) => 2,
+            SQLSequenceOption::StartWith(_) => 3,
+            SQLSequenceOption::Cache(_) => 4,
+            SQLSequenceOption::Cycle(_) => 5,
+        }
+    }
+}
+
+impl fmt::Display for SQLSequenceOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SQLSequenceOption::IncrementBy(n) => write!(f, "INCREMENT BY {}", n),
+            SQLSequenceOption::MinValue(Some(n)) => write!(f, "MINVALUE {}", n),
+            SQLSequenceOption::MinValue(None) => f.write_str("NO MINVALUE"),
+            SQLSequenceOption::MaxValue(Some(n)) => write!(f, "MAXVALUE {}", n),
+            SQLSequenceOption::MaxValue(None) => f.write_str("NO MAXVALUE"),
+            SQLSequenceOption::StartWith(n) => write!(f, "START WITH {}", n),
+            SQLSequenceOption::Cache(n) => write!(f, "CACHE {}", n),
+            SQLSequenceOption::Cycle(true) => f.write_str("CYCLE"),
+            SQLSequenceOption::Cycle(false) => f.write_str("NO CYCLE"),
         }
-        s
     }
 }
 
 /// External table's available file format
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum FileFormat {
     TEXTFILE,
     SEQUENCEFILE,
@@ -602,18 +1409,18 @@ pub enum FileFormat {
     JSONFILE,
 }
 
-impl ToString for FileFormat {
-    fn to_string(&self) -> String {
+impl fmt::Display for FileFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use self::FileFormat::*;
-        match self {
-            TEXTFILE => "TEXTFILE".to_string(),
-            SEQUENCEFILE => "SEQUENCEFILE".to_string(),
-            ORC => "ORC".to_string(),
-            PARQUET => "PARQUET".to_string(),
-            AVRO => "AVRO".to_string(),
-            RCFILE => "RCFILE".to_string(),
-            JSONFILE => "TEXTFILE".to_string(),
-        }
+        f.write_str(match self {
+            TEXTFILE => "TEXTFILE",
+            SEQUENCEFILE => "SEQUENCEFILE",
+            ORC => "ORC",
+            PARQUET => "PARQUET",
+            AVRO => "AVRO",
+            RCFILE => "RCFILE",
+            JSONFILE => "JSONFILE",
+        })
     }
 }
 
@@ -641,16 +1448,23 @@ impl FromStr for FileFormat {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SQLObjectType {
     Table,
     View,
+    Sequence,
+    Schema,
+    Index,
 }
 
-impl SQLObjectType {
-    fn to_string(&self) -> String {
-        match self {
-            SQLObjectType::Table => "TABLE".into(),
-            SQLObjectType::View => "VIEW".into(),
-        }
+impl fmt::Display for SQLObjectType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            SQLObjectType::Table => "TABLE",
+            SQLObjectType::View => "VIEW",
+            SQLObjectType::Sequence => "SEQUENCE",
+            SQLObjectType::Schema => "SCHEMA",
+            SQLObjectType::Index => "INDEX",
+        })
     }
 }
@@ -0,0 +1,25 @@
+use super::*;
+
+impl SQLStatement {
+    /// Return the text of every bind parameter placeholder (`?`, `$1` or
+    /// `:name`) in this statement, in the order they appear in the source.
+    /// A placeholder that is used more than once is reported each time.
+    pub fn parameters(&self) -> Vec<String> {
+        let mut collector = ParameterCollector::default();
+        collector.visit_statement(self);
+        collector.parameters
+    }
+}
+
+#[derive(Default)]
+struct ParameterCollector {
+    parameters: Vec<String>,
+}
+
+impl Visitor for ParameterCollector {
+    fn visit_value(&mut self, value: &Value) {
+        if let Value::Placeholder(s) = value {
+            self.parameters.push(s.clone());
+        }
+    }
+}
@@ -1,60 +1,191 @@
+use super::value::escape_single_quote_string;
 use super::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// The most complete variant of a `SELECT` query expression, optionally
 /// including `WITH`, `UNION` / other set operations, and `ORDER BY`.
 #[derive(Debug, Clone, PartialEq)]
-pub struct SQLQuery {
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SQLQuery<I = SQLIdent> {
+    /// WITH RECURSIVE
+    pub recursive: bool,
     /// WITH (common table expressions, or CTEs)
-    pub ctes: Vec<Cte>,
+    pub ctes: Vec<Cte<I>>,
     /// SELECT or UNION / EXCEPT / INTECEPT
-    pub body: SQLSetExpr,
+    pub body: SQLSetExpr<I>,
     /// ORDER BY
-    pub order_by: Vec<SQLOrderByExpr>,
+    pub order_by: Vec<SQLOrderByExpr<I>>,
     /// LIMIT
-    pub limit: Option<ASTNode>,
+    pub limit: Option<ASTNode<I>>,
+    /// OFFSET <N> [ { ROW | ROWS } ]
+    pub offset: Option<Offset<I>>,
+    /// FETCH { FIRST | NEXT } <N> [ PERCENT ] { ROW | ROWS } { ONLY | WITH TIES }
+    pub fetch: Option<Fetch<I>>,
+    /// `FOR { UPDATE | SHARE | ... }` row-locking clauses
+    pub locks: Vec<LockClause<I>>,
 }
 
-impl ToString for SQLQuery {
-    fn to_string(&self) -> String {
-        let mut s = String::new();
+impl<I: fmt::Display> fmt::Display for SQLQuery<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if !self.ctes.is_empty() {
-            s += &format!("WITH {} ", comma_separated_string(&self.ctes))
+            write!(
+                f,
+                "WITH {}{} ",
+                if self.recursive { "RECURSIVE " } else { "" },
+                display_comma_separated(&self.ctes)
+            )?;
         }
-        s += &self.body.to_string();
+        write!(f, "{}", self.body)?;
         if !self.order_by.is_empty() {
-            s += &format!(" ORDER BY {}", comma_separated_string(&self.order_by));
+            write!(f, " ORDER BY {}", display_comma_separated(&self.order_by))?;
         }
         if let Some(ref limit) = self.limit {
-            s += &format!(" LIMIT {}", limit.to_string());
+            write!(f, " LIMIT {}", limit)?;
+        }
+        if let Some(ref offset) = self.offset {
+            write!(f, " {}", offset)?;
+        }
+        if let Some(ref fetch) = self.fetch {
+            write!(f, " {}", fetch)?;
+        }
+        for lock in &self.locks {
+            write!(f, " {}", lock)?;
+        }
+        Ok(())
+    }
+}
+
+/// The `OFFSET` clause of a query. `rows` is whether the standard `ROW` or
+/// `ROWS` keyword was specified, which MySQL and PostgreSQL's `LIMIT <N>
+/// OFFSET <M>` omit.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Offset<I = SQLIdent> {
+    pub value: ASTNode<I>,
+    pub rows: bool,
+}
+
+impl<I: fmt::Display> fmt::Display for Offset<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "OFFSET {}", self.value)?;
+        if self.rows {
+            write!(f, " ROWS")?;
+        }
+        Ok(())
+    }
+}
+
+/// The `FETCH` clause of a query. `quantity` is `None` for
+/// `FETCH FIRST ROWS ONLY`, which fetches a single row.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Fetch<I = SQLIdent> {
+    pub with_ties: bool,
+    pub percent: bool,
+    pub quantity: Option<ASTNode<I>>,
+}
+
+impl<I: fmt::Display> fmt::Display for Fetch<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let extension = if self.with_ties { "WITH TIES" } else { "ONLY" };
+        if let Some(ref quantity) = self.quantity {
+            let percent = if self.percent { " PERCENT" } else { "" };
+            write!(f, "FETCH FIRST {}{} ROWS {}", quantity, percent, extension)
+        } else {
+            write!(f, "FETCH FIRST ROWS {}", extension)
         }
-        s
+    }
+}
+
+/// A row-locking clause of a query:
+/// `FOR lock_type [ OF table [, ...] ] [ NOWAIT | SKIP LOCKED ]`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LockClause<I = SQLIdent> {
+    pub lock_type: LockType,
+    pub of: Vec<SQLObjectName<I>>,
+    pub nonblock: Option<NonBlock>,
+}
+
+impl<I: fmt::Display> fmt::Display for LockClause<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FOR {}", self.lock_type)?;
+        if !self.of.is_empty() {
+            write!(f, " OF {}", display_comma_separated(&self.of))?;
+        }
+        if let Some(ref nonblock) = self.nonblock {
+            write!(f, " {}", nonblock)?;
+        }
+        Ok(())
+    }
+}
+
+/// The strength of a row-locking clause
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LockType {
+    Update,
+    NoKeyUpdate,
+    Share,
+    KeyShare,
+}
+
+impl fmt::Display for LockType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            LockType::Update => "UPDATE",
+            LockType::NoKeyUpdate => "NO KEY UPDATE",
+            LockType::Share => "SHARE",
+            LockType::KeyShare => "KEY SHARE",
+        })
+    }
+}
+
+/// What to do when a row to be locked is already locked by another transaction
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NonBlock {
+    Nowait,
+    SkipLocked,
+}
+
+impl fmt::Display for NonBlock {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            NonBlock::Nowait => "NOWAIT",
+            NonBlock::SkipLocked => "SKIP LOCKED",
+        })
     }
 }
 
 /// A node in a tree, representing a "query body" expression, roughly:
 /// `SELECT ... [ {UNION|EXCEPT|INTERSECT} SELECT ...]`
 #[derive(Debug, Clone, PartialEq)]
-pub enum SQLSetExpr {
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SQLSetExpr<I = SQLIdent> {
     /// Restricted SELECT .. FROM .. HAVING (no ORDER BY or set operations)
-    Select(Box<SQLSelect>),
+    Select(Box<SQLSelect<I>>),
     /// Parenthesized SELECT subquery, which may include more set operations
     /// in its body and an optional ORDER BY / LIMIT.
-    Query(Box<SQLQuery>),
+    Query(Box<SQLQuery<I>>),
     /// UNION/EXCEPT/INTERSECT of two queries
     SetOperation {
         op: SQLSetOperator,
         all: bool,
-        left: Box<SQLSetExpr>,
-        right: Box<SQLSetExpr>,
+        left: Box<SQLSetExpr<I>>,
+        right: Box<SQLSetExpr<I>>,
     },
-    // TODO: ANSI SQL supports `TABLE` and `VALUES` here.
+    Values(SQLValues<I>),
+    /// `TABLE name`, a shorthand for `SELECT * FROM name`
+    Table(SQLObjectName<I>),
 }
 
-impl ToString for SQLSetExpr {
-    fn to_string(&self) -> String {
+impl<I: fmt::Display> fmt::Display for SQLSetExpr<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            SQLSetExpr::Select(s) => s.to_string(),
-            SQLSetExpr::Query(q) => format!("({})", q.to_string()),
+            SQLSetExpr::Select(s) => write!(f, "{}", s),
+            SQLSetExpr::Query(q) => write!(f, "({})", q),
             SQLSetExpr::SetOperation {
                 left,
                 right,
@@ -62,32 +193,46 @@ impl ToString for SQLSetExpr {
                 all,
             } => {
                 let all_str = if *all { " ALL" } else { "" };
-                format!(
-                    "{} {}{} {}",
-                    left.to_string(),
-                    op.to_string(),
-                    all_str,
-                    right.to_string()
-                )
+                write!(f, "{} {}{} {}", left, op, all_str, right)
             }
+            SQLSetExpr::Values(v) => write!(f, "{}", v),
+            SQLSetExpr::Table(name) => write!(f, "TABLE {}", name),
+        }
+    }
+}
+
+/// A `VALUES (expr, ...), (expr, ...)` list of row constructors
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SQLValues<I = SQLIdent>(pub Vec<Vec<ASTNode<I>>>);
+
+impl<I: fmt::Display> fmt::Display for SQLValues<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("VALUES ")?;
+        let mut delim = "";
+        for row in &self.0 {
+            write!(f, "{}({})", delim, display_comma_separated(row))?;
+            delim = ", ";
         }
+        Ok(())
     }
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SQLSetOperator {
     Union,
     Except,
     Intersect,
 }
 
-impl ToString for SQLSetOperator {
-    fn to_string(&self) -> String {
-        match self {
-            SQLSetOperator::Union => "UNION".to_string(),
-            SQLSetOperator::Except => "EXCEPT".to_string(),
-            SQLSetOperator::Intersect => "INTERSECT".to_string(),
-        }
+impl fmt::Display for SQLSetOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            SQLSetOperator::Union => "UNION",
+            SQLSetOperator::Except => "EXCEPT",
+            SQLSetOperator::Intersect => "INTERSECT",
+        })
     }
 }
 
@@ -95,45 +240,107 @@ impl ToString for SQLSetOperator {
 /// appear either as the only body item of an `SQLQuery`, or as an operand
 /// to a set operation like `UNION`.
 #[derive(Debug, Clone, PartialEq)]
-pub struct SQLSelect {
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SQLSelect<I = SQLIdent> {
     pub distinct: bool,
+    /// MySQL-specific modifiers, in canonical order, see `SQLSelectModifier`
+    pub modifiers: Vec<SQLSelectModifier>,
     /// projection expressions
-    pub projection: Vec<SQLSelectItem>,
+    pub projection: Vec<SQLSelectItem<I>>,
     /// FROM
-    pub relation: Option<TableFactor>,
+    pub relation: Option<TableFactor<I>>,
     /// JOIN
-    pub joins: Vec<Join>,
+    pub joins: Vec<Join<I>>,
     /// WHERE
-    pub selection: Option<ASTNode>,
+    pub selection: Option<ASTNode<I>>,
     /// GROUP BY
-    pub group_by: Vec<ASTNode>,
+    pub group_by: Vec<ASTNode<I>>,
+    /// `GROUP BY ... WITH ROLLUP` (MySQL)
+    pub with_rollup: bool,
     /// HAVING
-    pub having: Option<ASTNode>,
+    pub having: Option<ASTNode<I>>,
+    /// WINDOW
+    pub window: Vec<(I, SQLWindowSpec<I>)>,
 }
 
-impl ToString for SQLSelect {
-    fn to_string(&self) -> String {
-        let mut s = format!(
-            "SELECT{} {}",
-            if self.distinct { " DISTINCT" } else { "" },
-            comma_separated_string(&self.projection)
-        );
+impl<I: fmt::Display> fmt::Display for SQLSelect<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SELECT{}", if self.distinct { " DISTINCT" } else { "" })?;
+        for modifier in &self.modifiers {
+            write!(f, " {}", modifier)?;
+        }
+        write!(f, " {}", display_comma_separated(&self.projection))?;
         if let Some(ref relation) = self.relation {
-            s += &format!(" FROM {}", relation.to_string());
+            write!(f, " FROM {}", relation)?;
         }
         for join in &self.joins {
-            s += &join.to_string();
+            write!(f, "{}", join)?;
         }
         if let Some(ref selection) = self.selection {
-            s += &format!(" WHERE {}", selection.to_string());
+            write!(f, " WHERE {}", selection)?;
         }
         if !self.group_by.is_empty() {
-            s += &format!(" GROUP BY {}", comma_separated_string(&self.group_by));
+            write!(f, " GROUP BY {}", display_comma_separated(&self.group_by))?;
+            if self.with_rollup {
+                f.write_str(" WITH ROLLUP")?;
+            }
         }
         if let Some(ref having) = self.having {
-            s += &format!(" HAVING {}", having.to_string());
+            write!(f, " HAVING {}", having)?;
+        }
+        if !self.window.is_empty() {
+            f.write_str(" WINDOW ")?;
+            let mut delim = "";
+            for (name, spec) in &self.window {
+                write!(f, "{}{} AS ({})", delim, name, spec)?;
+                delim = ", ";
+            }
         }
-        s
+        Ok(())
+    }
+}
+
+/// A MySQL-specific modifier following `SELECT [ALL | DISTINCT]`, e.g.
+/// `SQL_CALC_FOUND_ROWS`. The variants are listed in the canonical order,
+/// in which MySQL requires them to be specified.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SQLSelectModifier {
+    HighPriority,
+    StraightJoin,
+    SmallResult,
+    BigResult,
+    BufferResult,
+    NoCache,
+    CalcFoundRows,
+}
+
+impl SQLSelectModifier {
+    /// The position of this modifier in the canonical order
+    pub fn canonical_position(&self) -> usize {
+        match self {
+            SQLSelectModifier::HighPriority => 0,
+            SQLSelectModifier::StraightJoin => 1,
+            SQLSelectModifier::SmallResult => 2,
+            SQLSelectModifier::BigResult => 3,
+            SQLSelectModifier::BufferResult => 4,
+            SQLSelectModifier::NoCache => 5,
+            SQLSelectModifier::CalcFoundRows => 6,
+        }
+    }
+}
+
+impl fmt::Display for SQLSelectModifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            SQLSelectModifier::HighPriority => "HIGH_PRIORITY",
+            SQLSelectModifier::StraightJoin => "STRAIGHT_JOIN",
+            SQLSelectModifier::SmallResult => "SQL_SMALL_RESULT",
+            SQLSelectModifier::BigResult => "SQL_BIG_RESULT",
+            SQLSelectModifier::BufferResult => "SQL_BUFFER_RESULT",
+            SQLSelectModifier::NoCache => "SQL_NO_CACHE",
+            SQLSelectModifier::CalcFoundRows => "SQL_CALC_FOUND_ROWS",
+        })
     }
 }
 
@@ -142,181 +349,453 @@ impl ToString for SQLSelect {
 /// of the columns returned by the query. The parser does not validate that the
 /// number of columns in the query matches the number of columns in the query.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Cte {
-    pub alias: SQLIdent,
-    pub query: SQLQuery,
-    pub renamed_columns: Vec<SQLIdent>,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Cte<I = SQLIdent> {
+    pub alias: I,
+    pub query: SQLQuery<I>,
+    pub renamed_columns: Vec<I>,
+    /// `SEARCH { BREADTH | DEPTH } FIRST BY ... SET ...` (recursive CTEs only)
+    pub search: Option<CteSearch<I>>,
+    /// `CYCLE ... SET ... USING ...` (recursive CTEs only)
+    pub cycle: Option<CteCycle<I>>,
 }
 
-impl ToString for Cte {
-    fn to_string(&self) -> String {
-        let mut s = self.alias.clone();
+impl<I: fmt::Display> fmt::Display for Cte<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.alias)?;
         if !self.renamed_columns.is_empty() {
-            s += &format!(" ({})", comma_separated_string(&self.renamed_columns));
+            write!(f, " ({})", display_comma_separated(&self.renamed_columns))?;
+        }
+        write!(f, " AS ({})", self.query)?;
+        if let Some(ref search) = self.search {
+            write!(f, " {}", search)?;
+        }
+        if let Some(ref cycle) = self.cycle {
+            write!(f, " {}", cycle)?;
+        }
+        Ok(())
+    }
+}
+
+/// The `SEARCH` clause of a recursive CTE, which adds a column (`set`) that
+/// can be used to sort the results in breadth-first or depth-first order:
+/// `SEARCH { BREADTH | DEPTH } FIRST BY col1, col2 SET ordercol`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CteSearch<I = SQLIdent> {
+    pub depth_first: bool,
+    pub columns: Vec<I>,
+    pub set: I,
+}
+
+impl<I: fmt::Display> fmt::Display for CteSearch<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "SEARCH {} FIRST BY {} SET {}",
+            if self.depth_first { "DEPTH" } else { "BREADTH" },
+            display_comma_separated(&self.columns),
+            self.set
+        )
+    }
+}
+
+/// The `CYCLE` clause of a recursive CTE, used to detect cycles:
+/// `CYCLE col1, col2 SET is_cycle [ TO value DEFAULT default ] USING path`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CteCycle<I = SQLIdent> {
+    pub columns: Vec<I>,
+    pub set: I,
+    /// The optional `TO <value> DEFAULT <default>` cycle mark values
+    pub mark: Option<(Value, Value)>,
+    pub using: I,
+}
+
+impl<I: fmt::Display> fmt::Display for CteCycle<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "CYCLE {} SET {}",
+            display_comma_separated(&self.columns),
+            self.set
+        )?;
+        if let Some((ref value, ref default)) = self.mark {
+            write!(f, " TO {} DEFAULT {}", value, default)?;
         }
-        s + &format!(" AS ({})", self.query.to_string())
+        write!(f, " USING {}", self.using)
     }
 }
 
 /// One item of the comma-separated list following `SELECT`
 #[derive(Debug, Clone, PartialEq)]
-pub enum SQLSelectItem {
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SQLSelectItem<I = SQLIdent> {
     /// Any expression, not followed by `[ AS ] alias`
-    UnnamedExpression(ASTNode),
+    UnnamedExpression(ASTNode<I>),
     /// An expression, followed by `[ AS ] alias`
-    ExpressionWithAlias { expr: ASTNode, alias: SQLIdent },
+    ExpressionWithAlias { expr: ASTNode<I>, alias: I },
     /// `alias.*` or even `schema.table.*`
-    QualifiedWildcard(SQLObjectName),
+    QualifiedWildcard(SQLObjectName<I>),
     /// An unqualified `*`
     Wildcard,
 }
 
-impl ToString for SQLSelectItem {
-    fn to_string(&self) -> String {
+impl<I: fmt::Display> fmt::Display for SQLSelectItem<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match &self {
-            SQLSelectItem::UnnamedExpression(expr) => expr.to_string(),
+            SQLSelectItem::UnnamedExpression(expr) => write!(f, "{}", expr),
             SQLSelectItem::ExpressionWithAlias { expr, alias } => {
-                format!("{} AS {}", expr.to_string(), alias)
+                write!(f, "{} AS {}", expr, alias)
             }
-            SQLSelectItem::QualifiedWildcard(prefix) => format!("{}.*", prefix.to_string()),
-            SQLSelectItem::Wildcard => "*".to_string(),
+            SQLSelectItem::QualifiedWildcard(prefix) => write!(f, "{}.*", prefix),
+            SQLSelectItem::Wildcard => f.write_str("*"),
         }
     }
 }
 
 /// A table name or a parenthesized subquery with an optional alias
 #[derive(Debug, Clone, PartialEq)]
-pub enum TableFactor {
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TableFactor<I = SQLIdent> {
     Table {
-        name: SQLObjectName,
-        alias: Option<SQLIdent>,
+        /// `LATERAL`, only allowed before a table-valued function call
+        lateral: bool,
+        name: SQLObjectName<I>,
+        /// MySQL-specific `PARTITION (p0, p1)` selection
+        partitions: Vec<I>,
+        alias: Option<TableAlias<I>>,
+        /// `TABLESAMPLE ...` clause, returning a random sample of the rows
+        sample: Option<Box<TableSample<I>>>,
         /// Arguments of a table-valued function, as supported by Postgres
         /// and MSSQL. Note that deprecated MSSQL `FROM foo (NOLOCK)` syntax
-        /// will also be parsed as `args`.
-        args: Vec<ASTNode>,
+        /// will also be parsed as `args`. `None` if the table is not
+        /// followed by a (possibly empty) argument list.
+        args: Option<Vec<ASTNode<I>>>,
         /// MSSQL-specific `WITH (...)` hints such as NOLOCK.
-        with_hints: Vec<ASTNode>,
+        with_hints: Vec<ASTNode<I>>,
     },
     Derived {
-        subquery: Box<SQLQuery>,
-        alias: Option<SQLIdent>,
+        lateral: bool,
+        subquery: Box<SQLQuery<I>>,
+        alias: Option<TableAlias<I>>,
+    },
+    /// The SQL/JSON `JSON_TABLE(<json_expr>, '<json_path>' COLUMNS (...))`
+    /// table function, producing a row for each item matched by `json_path`
+    JsonTable {
+        json_expr: ASTNode<I>,
+        json_path: String,
+        columns: Vec<JsonTableColumn<I>>,
+        alias: Option<TableAlias<I>>,
+    },
+    /// `UNNEST(<array_expr>) [WITH OFFSET] [AS alias]`, producing a row for
+    /// each element of the array (and its position with BigQuery's `WITH OFFSET`)
+    Unnest {
+        array_expr: ASTNode<I>,
+        alias: Option<TableAlias<I>>,
+        with_offset: bool,
     },
 }
 
-impl ToString for TableFactor {
-    fn to_string(&self) -> String {
+impl<I: fmt::Display> fmt::Display for TableFactor<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             TableFactor::Table {
+                lateral,
                 name,
+                partitions,
                 alias,
+                sample,
                 args,
                 with_hints,
             } => {
-                let mut s = name.to_string();
-                if !args.is_empty() {
-                    s += &format!("({})", comma_separated_string(args))
-                };
+                if *lateral {
+                    f.write_str("LATERAL ")?;
+                }
+                write!(f, "{}", name)?;
+                if let Some(args) = args {
+                    write!(f, "({})", display_comma_separated(args))?;
+                }
+                if !partitions.is_empty() {
+                    write!(f, " PARTITION ({})", display_comma_separated(partitions))?;
+                }
                 if let Some(alias) = alias {
-                    s += &format!(" AS {}", alias);
+                    write!(f, " AS {}", alias)?;
+                }
+                if let Some(sample) = sample {
+                    write!(f, " {}", sample)?;
                 }
                 if !with_hints.is_empty() {
-                    s += &format!(" WITH ({})", comma_separated_string(with_hints));
+                    write!(f, " WITH ({})", display_comma_separated(with_hints))?;
                 }
-                s
+                Ok(())
             }
-            TableFactor::Derived { subquery, alias } => {
-                let mut s = format!("({})", subquery.to_string());
+            TableFactor::Derived {
+                lateral,
+                subquery,
+                alias,
+            } => {
+                if *lateral {
+                    f.write_str("LATERAL ")?;
+                }
+                write!(f, "({})", subquery)?;
+                if let Some(alias) = alias {
+                    write!(f, " AS {}", alias)?;
+                }
+                Ok(())
+            }
+            TableFactor::JsonTable {
+                json_expr,
+                json_path,
+                columns,
+                alias,
+            } => {
+                write!(
+                    f,
+                    "JSON_TABLE({}, '{}' COLUMNS ({}))",
+                    json_expr,
+                    escape_single_quote_string(json_path),
+                    display_comma_separated(columns)
+                )?;
+                if let Some(alias) = alias {
+                    write!(f, " AS {}", alias)?;
+                }
+                Ok(())
+            }
+            TableFactor::Unnest {
+                array_expr,
+                alias,
+                with_offset,
+            } => {
+                write!(f, "UNNEST({})", array_expr)?;
+                if *with_offset {
+                    f.write_str(" WITH OFFSET")?;
+                }
                 if let Some(alias) = alias {
-                    s += &format!(" AS {}", alias);
+                    write!(f, " AS {}", alias)?;
                 }
-                s
+                Ok(())
             }
         }
     }
 }
 
+/// A column of a `JSON_TABLE`: `<name> <data_type> PATH '<path>'`
 #[derive(Debug, Clone, PartialEq)]
-pub struct Join {
-    pub relation: TableFactor,
-    pub join_operator: JoinOperator,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct JsonTableColumn<I = SQLIdent> {
+    pub name: I,
+    pub data_type: SQLType,
+    pub path: String,
+}
+
+impl<I: fmt::Display> fmt::Display for JsonTableColumn<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} PATH '{}'",
+            self.name,
+            self.data_type,
+            escape_single_quote_string(&self.path)
+        )
+    }
 }
 
-impl ToString for Join {
-    fn to_string(&self) -> String {
-        fn prefix(constraint: &JoinConstraint) -> String {
+/// A table alias, which may also rename the columns of the table:
+/// `name [ (col1, col2, ...) ]`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TableAlias<I = SQLIdent> {
+    pub name: I,
+    pub columns: Vec<I>,
+}
+
+impl<I: fmt::Display> fmt::Display for TableAlias<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if !self.columns.is_empty() {
+            write!(f, " ({})", display_comma_separated(&self.columns))?;
+        }
+        Ok(())
+    }
+}
+
+/// `TABLESAMPLE <method> (<quantity> [PERCENT | ROWS]) [REPEATABLE (<seed>)]`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TableSample<I = SQLIdent> {
+    pub method: TableSampleMethod,
+    /// The percentage of rows to return, or their number with MSSQL's `ROWS`
+    pub quantity: ASTNode<I>,
+    /// MSSQL-specific unit of the quantity
+    pub unit: Option<TableSampleUnit>,
+    /// The seed of the random number generator
+    pub repeatable: Option<ASTNode<I>>,
+}
+
+impl<I: fmt::Display> fmt::Display for TableSample<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TABLESAMPLE {} ({}", self.method, self.quantity)?;
+        if let Some(unit) = &self.unit {
+            write!(f, " {}", unit)?;
+        }
+        f.write_str(")")?;
+        if let Some(seed) = &self.repeatable {
+            write!(f, " REPEATABLE ({})", seed)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TableSampleMethod {
+    /// Each row is selected with the given probability
+    Bernoulli,
+    /// Each block of rows is selected with the given probability
+    System,
+}
+
+impl fmt::Display for TableSampleMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            TableSampleMethod::Bernoulli => "BERNOULLI",
+            TableSampleMethod::System => "SYSTEM",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TableSampleUnit {
+    Percent,
+    Rows,
+}
+
+impl fmt::Display for TableSampleUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            TableSampleUnit::Percent => "PERCENT",
+            TableSampleUnit::Rows => "ROWS",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Join<I = SQLIdent> {
+    pub relation: TableFactor<I>,
+    pub join_operator: JoinOperator<I>,
+}
+
+impl<I: fmt::Display> fmt::Display for Join<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn prefix<I>(constraint: &JoinConstraint<I>) -> &'static str {
             match constraint {
-                JoinConstraint::Natural => "NATURAL ".to_string(),
-                _ => "".to_string(),
+                JoinConstraint::Natural => "NATURAL ",
+                _ => "",
             }
         }
-        fn suffix(constraint: &JoinConstraint) -> String {
-            match constraint {
-                JoinConstraint::On(expr) => format!("ON {}", expr.to_string()),
-                JoinConstraint::Using(attrs) => format!("USING({})", attrs.join(", ")),
-                _ => "".to_string(),
+        fn suffix<I: fmt::Display>(constraint: &JoinConstraint<I>) -> impl fmt::Display + '_ {
+            struct Suffix<'a, I>(&'a JoinConstraint<I>);
+            impl<'a, I: fmt::Display> fmt::Display for Suffix<'a, I> {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    match self.0 {
+                        JoinConstraint::On(expr) => write!(f, " ON {}", expr),
+                        JoinConstraint::Using(attrs) => {
+                            write!(f, " USING({})", display_comma_separated(attrs))
+                        }
+                        JoinConstraint::Natural | JoinConstraint::None => Ok(()),
+                    }
+                }
             }
+            Suffix(constraint)
         }
         match &self.join_operator {
-            JoinOperator::Inner(constraint) => format!(
-                " {}JOIN {} {}",
+            JoinOperator::Inner(constraint) => write!(
+                f,
+                " {}JOIN {}{}",
                 prefix(constraint),
-                self.relation.to_string(),
+                self.relation,
                 suffix(constraint)
             ),
-            JoinOperator::Cross => format!(" CROSS JOIN {}", self.relation.to_string()),
-            JoinOperator::Implicit => format!(", {}", self.relation.to_string()),
-            JoinOperator::LeftOuter(constraint) => format!(
-                " {}LEFT JOIN {} {}",
+            JoinOperator::Cross => write!(f, " CROSS JOIN {}", self.relation),
+            JoinOperator::Implicit => write!(f, ", {}", self.relation),
+            JoinOperator::LeftOuter(constraint) => write!(
+                f,
+                " {}LEFT JOIN {}{}",
                 prefix(constraint),
-                self.relation.to_string(),
+                self.relation,
                 suffix(constraint)
             ),
-            JoinOperator::RightOuter(constraint) => format!(
-                " {}RIGHT JOIN {} {}",
+            JoinOperator::RightOuter(constraint) => write!(
+                f,
+                " {}RIGHT JOIN {}{}",
                 prefix(constraint),
-                self.relation.to_string(),
+                self.relation,
                 suffix(constraint)
             ),
-            JoinOperator::FullOuter(constraint) => format!(
-                " {}FULL JOIN {} {}",
+            JoinOperator::FullOuter(constraint) => write!(
+                f,
+                " {}FULL JOIN {}{}",
                 prefix(constraint),
-                self.relation.to_string(),
+                self.relation,
                 suffix(constraint)
             ),
+            JoinOperator::StraightJoin(constraint) => {
+                write!(f, " STRAIGHT_JOIN {}{}", self.relation, suffix(constraint))
+            }
         }
     }
 }
 
 #[derive(Debug, Clone, PartialEq)]
-pub enum JoinOperator {
-    Inner(JoinConstraint),
-    LeftOuter(JoinConstraint),
-    RightOuter(JoinConstraint),
-    FullOuter(JoinConstraint),
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JoinOperator<I = SQLIdent> {
+    Inner(JoinConstraint<I>),
+    LeftOuter(JoinConstraint<I>),
+    RightOuter(JoinConstraint<I>),
+    FullOuter(JoinConstraint<I>),
     Implicit,
     Cross,
+    /// MySQL `STRAIGHT_JOIN`: an inner join, which reads the left table first
+    StraightJoin(JoinConstraint<I>),
 }
 
 #[derive(Debug, Clone, PartialEq)]
-pub enum JoinConstraint {
-    On(ASTNode),
-    Using(Vec<SQLIdent>),
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JoinConstraint<I = SQLIdent> {
+    On(ASTNode<I>),
+    Using(Vec<I>),
     Natural,
+    /// No constraint, as allowed by MySQL's `STRAIGHT_JOIN`
+    None,
 }
 
 /// SQL ORDER BY expression
 #[derive(Debug, Clone, PartialEq)]
-pub struct SQLOrderByExpr {
-    pub expr: ASTNode,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SQLOrderByExpr<I = SQLIdent> {
+    pub expr: ASTNode<I>,
+    /// Optional `ASC` or `DESC`
     pub asc: Option<bool>,
+    /// Optional `NULLS FIRST` or `NULLS LAST`
+    pub nulls_first: Option<bool>,
 }
 
-impl ToString for SQLOrderByExpr {
-    fn to_string(&self) -> String {
+impl<I: fmt::Display> fmt::Display for SQLOrderByExpr<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.expr)?;
         match self.asc {
-            Some(true) => format!("{} ASC", self.expr.to_string()),
-            Some(false) => format!("{} DESC", self.expr.to_string()),
-            None => self.expr.to_string(),
+            Some(true) => f.write_str(" ASC")?,
+            Some(false) => f.write_str(" DESC")?,
+            None => (),
+        }
+        match self.nulls_first {
+            Some(true) => f.write_str(" NULLS FIRST")?,
+            Some(false) => f.write_str(" NULLS LAST")?,
+            None => (),
         }
+        Ok(())
     }
 }
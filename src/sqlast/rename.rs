@@ -0,0 +1,126 @@
+use super::*;
+
+impl SQLStatement {
+    /// Return a copy of this statement with every reference to the table
+    /// `from` replaced by `to`. This includes table names in `FROM` and
+    /// `JOIN` clauses (including those in subqueries and CTEs), the target
+    /// table of DML/DDL statements, and the qualifier of qualified column
+    /// names and wildcards, e.g. `from.col` or `from.*`.
+    pub fn rename_table(&self, from: &SQLObjectName, to: &SQLObjectName) -> SQLStatement {
+        let mut stmt = self.clone();
+        TableRenamer { from, to }.visit_statement(&mut stmt);
+        stmt
+    }
+}
+
+/// Renames the object names in table position, leaving the names of other
+/// objects (functions, collations, types, ...) alone.
+struct TableRenamer<'a> {
+    from: &'a SQLObjectName,
+    to: &'a SQLObjectName,
+}
+
+impl<'a> TableRenamer<'a> {
+    fn rename(&self, name: &mut SQLObjectName) {
+        if name == self.from {
+            *name = self.to.clone();
+        }
+    }
+}
+
+impl<'a> VisitorMut for TableRenamer<'a> {
+    fn visit_statement(&mut self, statement: &mut SQLStatement) {
+        match statement {
+            SQLStatement::SQLInsert { table_name, .. }
+            | SQLStatement::SQLCopy { table_name, .. }
+            | SQLStatement::SQLUpdate { table_name, .. }
+            | SQLStatement::SQLDelete { table_name, .. }
+            | SQLStatement::SQLCreateIndex { table_name, .. }
+            | SQLStatement::SQLShowColumns { table_name, .. }
+            | SQLStatement::SQLCreateTable {
+                name: table_name, ..
+            } => self.rename(table_name),
+            SQLStatement::SQLAlterTable { name, operation } => {
+                self.rename(name);
+                if let AlterOperation::RenameTable { table_name } = operation {
+                    self.rename(table_name);
+                }
+            }
+            SQLStatement::SQLDrop {
+                object_type: SQLObjectType::Table,
+                names,
+                ..
+            }
+            | SQLStatement::SQLGrant {
+                objects: GrantObjects::Tables(names),
+                ..
+            } => {
+                for name in names {
+                    self.rename(name);
+                }
+            }
+            _ => {}
+        }
+        visit_mut::walk_statement(self, statement);
+    }
+
+    fn visit_set_expr(&mut self, set_expr: &mut SQLSetExpr) {
+        if let SQLSetExpr::Table(name) = set_expr {
+            self.rename(name);
+        }
+        visit_mut::walk_set_expr(self, set_expr);
+    }
+
+    fn visit_table_factor(&mut self, table_factor: &mut TableFactor) {
+        if let TableFactor::Table { name, .. } = table_factor {
+            self.rename(name);
+        }
+        visit_mut::walk_table_factor(self, table_factor);
+    }
+
+    fn visit_lock(&mut self, lock: &mut LockClause) {
+        for name in &mut lock.of {
+            self.rename(name);
+        }
+    }
+
+    fn visit_select_item(&mut self, select_item: &mut SQLSelectItem) {
+        if let SQLSelectItem::QualifiedWildcard(prefix) = select_item {
+            self.rename(prefix);
+        }
+        visit_mut::walk_select_item(self, select_item);
+    }
+
+    fn visit_column_option(&mut self, option: &mut ColumnOption) {
+        if let ColumnOption::ForeignKey { foreign_table, .. } = option {
+            self.rename(foreign_table);
+        }
+        visit_mut::walk_column_option(self, option);
+    }
+
+    fn visit_table_constraint(&mut self, constraint: &mut TableConstraint) {
+        if let TableConstraint::ForeignKey { foreign_table, .. } = constraint {
+            self.rename(foreign_table);
+        }
+        visit_mut::walk_table_constraint(self, constraint);
+    }
+
+    fn visit_qualified_wildcard(&mut self, qualified_wildcard: &mut ASTNode) {
+        if let ASTNode::SQLQualifiedWildcard(idents) = qualified_wildcard {
+            if *idents == self.from.0 {
+                *idents = self.to.0.clone();
+            }
+        }
+    }
+
+    fn visit_compound_identifier(&mut self, compound_identifier: &mut ASTNode) {
+        if let ASTNode::SQLCompoundIdentifier(idents) = compound_identifier {
+            if let Some((_, qualifier)) = idents.split_last() {
+                if qualifier == &self.from.0[..] {
+                    let qualifier_len = qualifier.len();
+                    idents.splice(..qualifier_len, self.to.0.iter().cloned());
+                }
+            }
+        }
+    }
+}
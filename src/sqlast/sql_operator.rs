@@ -1,11 +1,18 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
 /// SQL Operator
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SQLOperator {
     Plus,
     Minus,
     Multiply,
     Divide,
     Modulus,
+    /// `^`, the (right-associative) exponentiation
+    Exponent,
     Gt,
     Lt,
     GtEq,
@@ -15,29 +22,82 @@ pub enum SQLOperator {
     And,
     Or,
     Not,
+    IsDistinctFrom,
+    IsNotDistinctFrom,
     Like,
     NotLike,
+    ILike,
+    NotILike,
+    SimilarTo,
+    NotSimilarTo,
+    /// `~`, a case-sensitive POSIX regular expression match
+    RegexMatch,
+    /// `~*`, a case-insensitive POSIX regular expression match
+    RegexIMatch,
+    /// `!~`, a case-sensitive POSIX regular expression non-match
+    RegexNotMatch,
+    /// `!~*`, a case-insensitive POSIX regular expression non-match
+    RegexNotIMatch,
+    BitwiseAnd,
+    BitwiseOr,
+    /// `#`, the bitwise exclusive or
+    BitwiseXor,
+    ShiftLeft,
+    ShiftRight,
+}
+
+impl SQLOperator {
+    /// Whether this is one of the pattern matching operators that accept an
+    /// `ESCAPE` clause, i.e. `[ NOT ] LIKE`, `[ NOT ] ILIKE` or
+    /// `[ NOT ] SIMILAR TO`
+    pub fn is_pattern_match(&self) -> bool {
+        matches!(
+            self,
+            SQLOperator::Like
+                | SQLOperator::NotLike
+                | SQLOperator::ILike
+                | SQLOperator::NotILike
+                | SQLOperator::SimilarTo
+                | SQLOperator::NotSimilarTo
+        )
+    }
 }
 
-impl ToString for SQLOperator {
-    fn to_string(&self) -> String {
-        match self {
-            SQLOperator::Plus => "+".to_string(),
-            SQLOperator::Minus => "-".to_string(),
-            SQLOperator::Multiply => "*".to_string(),
-            SQLOperator::Divide => "/".to_string(),
-            SQLOperator::Modulus => "%".to_string(),
-            SQLOperator::Gt => ">".to_string(),
-            SQLOperator::Lt => "<".to_string(),
-            SQLOperator::GtEq => ">=".to_string(),
-            SQLOperator::LtEq => "<=".to_string(),
-            SQLOperator::Eq => "=".to_string(),
-            SQLOperator::NotEq => "<>".to_string(),
-            SQLOperator::And => "AND".to_string(),
-            SQLOperator::Or => "OR".to_string(),
-            SQLOperator::Not => "NOT".to_string(),
-            SQLOperator::Like => "LIKE".to_string(),
-            SQLOperator::NotLike => "NOT LIKE".to_string(),
-        }
+impl fmt::Display for SQLOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            SQLOperator::Plus => "+",
+            SQLOperator::Minus => "-",
+            SQLOperator::Multiply => "*",
+            SQLOperator::Divide => "/",
+            SQLOperator::Modulus => "%",
+            SQLOperator::Exponent => "^",
+            SQLOperator::Gt => ">",
+            SQLOperator::Lt => "<",
+            SQLOperator::GtEq => ">=",
+            SQLOperator::LtEq => "<=",
+            SQLOperator::Eq => "=",
+            SQLOperator::NotEq => "<>",
+            SQLOperator::And => "AND",
+            SQLOperator::Or => "OR",
+            SQLOperator::Not => "NOT",
+            SQLOperator::IsDistinctFrom => "IS DISTINCT FROM",
+            SQLOperator::IsNotDistinctFrom => "IS NOT DISTINCT FROM",
+            SQLOperator::Like => "LIKE",
+            SQLOperator::NotLike => "NOT LIKE",
+            SQLOperator::ILike => "ILIKE",
+            SQLOperator::NotILike => "NOT ILIKE",
+            SQLOperator::SimilarTo => "SIMILAR TO",
+            SQLOperator::NotSimilarTo => "NOT SIMILAR TO",
+            SQLOperator::RegexMatch => "~",
+            SQLOperator::RegexIMatch => "~*",
+            SQLOperator::RegexNotMatch => "!~",
+            SQLOperator::RegexNotIMatch => "!~*",
+            SQLOperator::BitwiseAnd => "&",
+            SQLOperator::BitwiseOr => "|",
+            SQLOperator::BitwiseXor => "#",
+            SQLOperator::ShiftLeft => "<<",
+            SQLOperator::ShiftRight => ">>",
+        })
     }
 }
@@ -1,7 +1,11 @@
 use super::SQLObjectName;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// SQL datatypes for literals in SQL statements
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SQLType {
     /// Fixed-length character type e.g. CHAR(10)
     Char(Option<usize>),
@@ -11,12 +15,12 @@ pub enum SQLType {
     Uuid,
     /// Large character object e.g. CLOB(1000)
     Clob(usize),
-    /// Fixed-length binary type e.g. BINARY(10)
-    Binary(usize),
-    /// Variable-length binary type e.g. VARBINARY(10)
-    Varbinary(usize),
-    /// Large binary object e.g. BLOB(1000)
-    Blob(usize),
+    /// Fixed-length binary type with optional length e.g. BINARY(10)
+    Binary(Option<usize>),
+    /// Variable-length binary type with optional length e.g. VARBINARY(10)
+    Varbinary(Option<usize>),
+    /// Large binary object with optional length e.g. BLOB(1000)
+    Blob(Option<usize>),
     /// Decimal type with optional precision and scale e.g. DECIMAL(10,2)
     Decimal(Option<usize>, Option<usize>),
     /// Floating point with optional precision e.g. FLOAT(8)
@@ -35,62 +39,157 @@ pub enum SQLType {
     Boolean,
     /// Date
     Date,
-    /// Time
-    Time,
-    /// Timestamp
-    Timestamp,
+    /// Time with optional fractional seconds precision e.g. TIME(3)
+    Time(Option<usize>),
+    /// Interval with optional field qualifier and fractional seconds
+    /// precision e.g. INTERVAL, INTERVAL YEAR TO MONTH or INTERVAL DAY TO SECOND(3)
+    Interval {
+        leading_field: Option<DateTimeField>,
+        trailing_field: Option<DateTimeField>,
+        precision: Option<usize>,
+    },
+    /// Timestamp with optional fractional seconds precision e.g. TIMESTAMP(6)
+    Timestamp(Option<usize>),
     /// Regclass used in postgresql serial
     Regclass,
+    /// Postgres-specific object identifier
+    Oid,
+    /// Postgres-specific object identifier alias for a function name
+    Regproc,
+    /// Postgres-specific object identifier alias for a data type name
+    Regtype,
+    /// Postgres-specific object identifier alias for a schema name
+    Regnamespace,
+    /// Postgres-specific auto-incrementing smallint
+    SmallSerial,
+    /// Postgres-specific auto-incrementing int
+    Serial,
+    /// Postgres-specific auto-incrementing bigint
+    BigSerial,
     /// Text
     Text,
+    /// MySQL-specific text of up to 255 bytes
+    TinyText,
+    /// MySQL-specific text of up to 16 MiB
+    MediumText,
+    /// MySQL-specific text of up to 4 GiB
+    LongText,
     /// Bytea
     Bytea,
+    /// JSON
+    Json,
+    /// Postgres-specific binary JSON type
+    Jsonb,
     /// Custom type such as enums
     Custom(SQLObjectName),
-    /// Arrays
-    Array(Box<SQLType>),
+    /// Array of the given element type with an optional declared size,
+    /// e.g. INT[] or INT[3]. Multi-dimensional arrays are nested.
+    Array(Box<SQLType>, Option<usize>),
 }
 
-impl ToString for SQLType {
-    fn to_string(&self) -> String {
+impl fmt::Display for SQLType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            SQLType::Char(size) => format_type_with_optional_length("char", size),
-            SQLType::Varchar(size) => format_type_with_optional_length("character varying", size),
-            SQLType::Uuid => "uuid".to_string(),
-            SQLType::Clob(size) => format!("clob({})", size),
-            SQLType::Binary(size) => format!("binary({})", size),
-            SQLType::Varbinary(size) => format!("varbinary({})", size),
-            SQLType::Blob(size) => format!("blob({})", size),
+            SQLType::Char(size) => format_type_with_optional_length(f, "char", size),
+            SQLType::Varchar(size) => {
+                format_type_with_optional_length(f, "character varying", size)
+            }
+            SQLType::Uuid => f.write_str("uuid"),
+            SQLType::Clob(size) => write!(f, "clob({})", size),
+            SQLType::Binary(size) => format_type_with_optional_length(f, "binary", size),
+            SQLType::Varbinary(size) => format_type_with_optional_length(f, "varbinary", size),
+            SQLType::Blob(size) => format_type_with_optional_length(f, "blob", size),
             SQLType::Decimal(precision, scale) => {
                 if let Some(scale) = scale {
-                    format!("numeric({},{})", precision.unwrap(), scale)
+                    write!(f, "numeric({},{})", precision.unwrap(), scale)
                 } else {
-                    format_type_with_optional_length("numeric", precision)
+                    format_type_with_optional_length(f, "numeric", precision)
+                }
+            }
+            SQLType::Float(size) => format_type_with_optional_length(f, "float", size),
+            SQLType::SmallInt => f.write_str("smallint"),
+            SQLType::Int => f.write_str("int"),
+            SQLType::BigInt => f.write_str("bigint"),
+            SQLType::Real => f.write_str("real"),
+            SQLType::Double => f.write_str("double"),
+            SQLType::Boolean => f.write_str("boolean"),
+            SQLType::Date => f.write_str("date"),
+            SQLType::Time(precision) => format_type_with_optional_length(f, "time", precision),
+            SQLType::Timestamp(precision) => {
+                format_type_with_optional_length(f, "timestamp", precision)
+            }
+            SQLType::Interval {
+                leading_field,
+                trailing_field,
+                precision,
+            } => {
+                f.write_str("interval")?;
+                if let Some(leading_field) = leading_field {
+                    write!(f, " {}", leading_field.to_string().to_lowercase())?;
+                }
+                if let Some(trailing_field) = trailing_field {
+                    write!(f, " to {}", trailing_field.to_string().to_lowercase())?;
                 }
+                format_type_with_optional_length(f, "", precision)
             }
-            SQLType::Float(size) => format_type_with_optional_length("float", size),
-            SQLType::SmallInt => "smallint".to_string(),
-            SQLType::Int => "int".to_string(),
-            SQLType::BigInt => "bigint".to_string(),
-            SQLType::Real => "real".to_string(),
-            SQLType::Double => "double".to_string(),
-            SQLType::Boolean => "boolean".to_string(),
-            SQLType::Date => "date".to_string(),
-            SQLType::Time => "time".to_string(),
-            SQLType::Timestamp => "timestamp".to_string(),
-            SQLType::Regclass => "regclass".to_string(),
-            SQLType::Text => "text".to_string(),
-            SQLType::Bytea => "bytea".to_string(),
-            SQLType::Array(ty) => format!("{}[]", ty.to_string()),
-            SQLType::Custom(ty) => ty.to_string(),
+            SQLType::Regclass => f.write_str("regclass"),
+            SQLType::Oid => f.write_str("oid"),
+            SQLType::Regproc => f.write_str("regproc"),
+            SQLType::Regtype => f.write_str("regtype"),
+            SQLType::Regnamespace => f.write_str("regnamespace"),
+            SQLType::SmallSerial => f.write_str("smallserial"),
+            SQLType::Serial => f.write_str("serial"),
+            SQLType::BigSerial => f.write_str("bigserial"),
+            SQLType::Text => f.write_str("text"),
+            SQLType::TinyText => f.write_str("tinytext"),
+            SQLType::MediumText => f.write_str("mediumtext"),
+            SQLType::LongText => f.write_str("longtext"),
+            SQLType::Bytea => f.write_str("bytea"),
+            SQLType::Json => f.write_str("json"),
+            SQLType::Jsonb => f.write_str("jsonb"),
+            SQLType::Array(ty, size) => match size {
+                Some(size) => write!(f, "{}[{}]", ty, size),
+                None => write!(f, "{}[]", ty),
+            },
+            SQLType::Custom(ty) => write!(f, "{}", ty),
         }
     }
 }
 
-fn format_type_with_optional_length(sql_type: &str, len: &Option<usize>) -> String {
-    let mut s = sql_type.to_string();
+/// A field of a date, time or interval value, as used in the qualifier of
+/// an `INTERVAL` type
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DateTimeField {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+impl fmt::Display for DateTimeField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            DateTimeField::Year => "YEAR",
+            DateTimeField::Month => "MONTH",
+            DateTimeField::Day => "DAY",
+            DateTimeField::Hour => "HOUR",
+            DateTimeField::Minute => "MINUTE",
+            DateTimeField::Second => "SECOND",
+        })
+    }
+}
+
+fn format_type_with_optional_length(
+    f: &mut fmt::Formatter,
+    sql_type: &str,
+    len: &Option<usize>,
+) -> fmt::Result {
+    f.write_str(sql_type)?;
     if let Some(len) = len {
-        s += &format!("({})", len);
+        write!(f, "({})", len)?;
     }
-    s
+    Ok(())
 }
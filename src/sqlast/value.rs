@@ -1,5 +1,10 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
 /// SQL values such as int, double, string, timestamp
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Value {
     /// Literal signed long
     Long(i64),
@@ -9,33 +14,42 @@ pub enum Value {
     SingleQuotedString(String),
     /// N'string value'
     NationalStringLiteral(String),
+    /// $$string value$$ or $tag$string value$tag$ (PostgreSQL)
+    DollarQuotedString { tag: Option<String>, value: String },
+    /// A bind parameter placeholder, i.e. `?`, `$1` or `:name`, as written
+    Placeholder(String),
     /// Boolean value true or false,
     Boolean(bool),
     /// NULL value in insert statements,
     Null,
 }
 
-impl ToString for Value {
-    fn to_string(&self) -> String {
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Value::Long(v) => v.to_string(),
-            Value::Double(v) => v.to_string(),
-            Value::SingleQuotedString(v) => format!("'{}'", escape_single_quote_string(v)),
-            Value::NationalStringLiteral(v) => format!("N'{}'", v),
-            Value::Boolean(v) => v.to_string(),
-            Value::Null => "NULL".to_string(),
+            Value::Long(v) => write!(f, "{}", v),
+            Value::Double(v) => {
+                // Keep the decimal point, so that the value is parsed back as a double
+                let s = v.to_string();
+                if s.contains('.') {
+                    f.write_str(&s)
+                } else {
+                    write!(f, "{}.0", s)
+                }
+            }
+            Value::SingleQuotedString(v) => write!(f, "'{}'", escape_single_quote_string(v)),
+            Value::NationalStringLiteral(v) => write!(f, "N'{}'", escape_single_quote_string(v)),
+            Value::DollarQuotedString { tag, value } => {
+                let tag = tag.as_deref().unwrap_or("");
+                write!(f, "${}${}${}$", tag, value, tag)
+            }
+            Value::Placeholder(v) => f.write_str(v),
+            Value::Boolean(v) => write!(f, "{}", v),
+            Value::Null => f.write_str("NULL"),
         }
     }
 }
 
-fn escape_single_quote_string(s: &str) -> String {
-    let mut escaped = String::new();
-    for c in s.chars() {
-        if c == '\'' {
-            escaped.push_str("\'\'");
-        } else {
-            escaped.push(c);
-        }
-    }
-    escaped
+pub(super) fn escape_single_quote_string(s: &str) -> String {
+    s.replace('\'', "''")
 }
@@ -0,0 +1,1092 @@
+//! Recursive traversal of the AST, for building linters and rewriters on top
+//! of the parser.
+//!
+//! The `Visitor` trait (and `VisitorMut`, its counterpart that can modify
+//! the tree in place) has a method for each kind of node and for each
+//! variant of `SQLStatement` and `ASTNode`. By default, every method
+//! recurses into the node's children by calling the matching `walk_*`
+//! function from the `visit` (or `visit_mut`) module, so implementations
+//! only need to override the methods they are interested in. An overriding
+//! method can call the `walk_*` function itself to keep recursing.
+//!
+//! The method for a variant is passed the whole node, e.g. `visit_function`
+//! gets the `ASTNode` (which is always an `ASTNode::SQLFunction`), so that
+//! adding a field to a variant doesn't change the signature of its method.
+//! The corresponding `walk_*` function does nothing if it is given a node
+//! of another variant.
+//!
+//! To traverse a statement, call `visitor.visit_statement(&stmt)`.
+
+/// Defines a visitor trait named `$Visitor` and the corresponding `walk_*`
+/// functions. The nodes are passed by shared reference, or by mutable
+/// reference if `mut` is specified.
+macro_rules! define_visitor {
+    ($Visitor:ident $(, $mut:tt)?) => {
+        pub trait $Visitor {
+            fn visit_statement(&mut self, statement: & $($mut)? SQLStatement) {
+                walk_statement(self, statement)
+            }
+
+            fn visit_insert(&mut self, insert: & $($mut)? SQLStatement) {
+                walk_insert(self, insert)
+            }
+
+            fn visit_copy(&mut self, copy: & $($mut)? SQLStatement) {
+                walk_copy(self, copy)
+            }
+
+            fn visit_update(&mut self, update: & $($mut)? SQLStatement) {
+                walk_update(self, update)
+            }
+
+            fn visit_delete(&mut self, delete: & $($mut)? SQLStatement) {
+                walk_delete(self, delete)
+            }
+
+            fn visit_create_view(&mut self, create_view: & $($mut)? SQLStatement) {
+                walk_create_view(self, create_view)
+            }
+
+            fn visit_create_table(&mut self, create_table: & $($mut)? SQLStatement) {
+                walk_create_table(self, create_table)
+            }
+
+            fn visit_create_index(&mut self, create_index: & $($mut)? SQLStatement) {
+                walk_create_index(self, create_index)
+            }
+
+            fn visit_create_schema(&mut self, create_schema: & $($mut)? SQLStatement) {
+                walk_create_schema(self, create_schema)
+            }
+
+            fn visit_create_function(&mut self, create_function: & $($mut)? SQLStatement) {
+                walk_create_function(self, create_function)
+            }
+
+            fn visit_create_sequence(&mut self, create_sequence: & $($mut)? SQLStatement) {
+                walk_create_sequence(self, create_sequence)
+            }
+
+            fn visit_alter_sequence(&mut self, alter_sequence: & $($mut)? SQLStatement) {
+                walk_alter_sequence(self, alter_sequence)
+            }
+
+            fn visit_alter_table(&mut self, alter_table: & $($mut)? SQLStatement) {
+                walk_alter_table(self, alter_table)
+            }
+
+            fn visit_drop(&mut self, drop: & $($mut)? SQLStatement) {
+                walk_drop(self, drop)
+            }
+
+            fn visit_use(&mut self, use_stmt: & $($mut)? SQLStatement) {
+                walk_use(self, use_stmt)
+            }
+
+            fn visit_grant(&mut self, grant: & $($mut)? SQLStatement) {
+                walk_grant(self, grant)
+            }
+
+            fn visit_grant_role(&mut self, _grant_role: & $($mut)? SQLStatement) {}
+
+            fn visit_show_variable(&mut self, _show_variable: & $($mut)? SQLStatement) {}
+
+            fn visit_show_tables(&mut self, show_tables: & $($mut)? SQLStatement) {
+                walk_show_tables(self, show_tables)
+            }
+
+            fn visit_show_columns(&mut self, show_columns: & $($mut)? SQLStatement) {
+                walk_show_columns(self, show_columns)
+            }
+
+            fn visit_show_variables(&mut self, show_variables: & $($mut)? SQLStatement) {
+                walk_show_variables(self, show_variables)
+            }
+
+            fn visit_query(&mut self, query: & $($mut)? SQLQuery) {
+                walk_query(self, query)
+            }
+
+            fn visit_cte(&mut self, cte: & $($mut)? Cte) {
+                walk_cte(self, cte)
+            }
+
+            fn visit_fetch(&mut self, fetch: & $($mut)? Fetch) {
+                walk_fetch(self, fetch)
+            }
+
+            fn visit_lock(&mut self, lock: & $($mut)? LockClause) {
+                walk_lock(self, lock)
+            }
+
+            fn visit_set_expr(&mut self, set_expr: & $($mut)? SQLSetExpr) {
+                walk_set_expr(self, set_expr)
+            }
+
+            fn visit_set_operation(&mut self, set_operation: & $($mut)? SQLSetExpr) {
+                walk_set_operation(self, set_operation)
+            }
+
+            fn visit_values(&mut self, values: & $($mut)? SQLValues) {
+                walk_values(self, values)
+            }
+
+            fn visit_select(&mut self, select: & $($mut)? SQLSelect) {
+                walk_select(self, select)
+            }
+
+            fn visit_select_item(&mut self, select_item: & $($mut)? SQLSelectItem) {
+                walk_select_item(self, select_item)
+            }
+
+            fn visit_table_factor(&mut self, table_factor: & $($mut)? TableFactor) {
+                walk_table_factor(self, table_factor)
+            }
+
+            fn visit_join(&mut self, join: & $($mut)? Join) {
+                walk_join(self, join)
+            }
+
+            fn visit_order_by(&mut self, order_by: & $($mut)? SQLOrderByExpr) {
+                walk_order_by(self, order_by)
+            }
+
+            fn visit_expr(&mut self, expr: & $($mut)? ASTNode) {
+                walk_expr(self, expr)
+            }
+
+            fn visit_identifier(&mut self, _identifier: & $($mut)? ASTNode) {}
+
+            fn visit_variable(&mut self, _variable: & $($mut)? ASTNode) {}
+
+            fn visit_wildcard(&mut self, _wildcard: & $($mut)? ASTNode) {}
+
+            fn visit_qualified_wildcard(&mut self, _qualified_wildcard: & $($mut)? ASTNode) {}
+
+            fn visit_compound_identifier(&mut self, _compound_identifier: & $($mut)? ASTNode) {}
+
+            fn visit_is_null(&mut self, is_null: & $($mut)? ASTNode) {
+                walk_is_null(self, is_null)
+            }
+
+            fn visit_is_not_null(&mut self, is_not_null: & $($mut)? ASTNode) {
+                walk_is_not_null(self, is_not_null)
+            }
+
+            fn visit_is_normalized(&mut self, is_normalized: & $($mut)? ASTNode) {
+                walk_is_normalized(self, is_normalized)
+            }
+
+            fn visit_in_list(&mut self, in_list: & $($mut)? ASTNode) {
+                walk_in_list(self, in_list)
+            }
+
+            fn visit_in_subquery(&mut self, in_subquery: & $($mut)? ASTNode) {
+                walk_in_subquery(self, in_subquery)
+            }
+
+            fn visit_between(&mut self, between: & $($mut)? ASTNode) {
+                walk_between(self, between)
+            }
+
+            fn visit_like(&mut self, like: & $($mut)? ASTNode) {
+                walk_like(self, like)
+            }
+
+            fn visit_binary_expr(&mut self, binary_expr: & $($mut)? ASTNode) {
+                walk_binary_expr(self, binary_expr)
+            }
+
+            fn visit_cast(&mut self, cast: & $($mut)? ASTNode) {
+                walk_cast(self, cast)
+            }
+
+            fn visit_json_function(&mut self, json_function: & $($mut)? ASTNode) {
+                walk_json_function(self, json_function)
+            }
+
+            fn visit_json_key_value(&mut self, json_key_value: & $($mut)? ASTNode) {
+                walk_json_key_value(self, json_key_value)
+            }
+
+            fn visit_named_argument(&mut self, named_argument: & $($mut)? ASTNode) {
+                walk_named_argument(self, named_argument)
+            }
+
+            fn visit_collate(&mut self, collate: & $($mut)? ASTNode) {
+                walk_collate(self, collate)
+            }
+
+            fn visit_nested(&mut self, nested: & $($mut)? ASTNode) {
+                walk_nested(self, nested)
+            }
+
+            fn visit_unary(&mut self, unary: & $($mut)? ASTNode) {
+                walk_unary(self, unary)
+            }
+
+            fn visit_value(&mut self, _value: & $($mut)? Value) {}
+
+            fn visit_function(&mut self, function: & $($mut)? ASTNode) {
+                walk_function(self, function)
+            }
+
+            fn visit_case(&mut self, case: & $($mut)? ASTNode) {
+                walk_case(self, case)
+            }
+
+            fn visit_subquery(&mut self, subquery: & $($mut)? ASTNode) {
+                walk_subquery(self, subquery)
+            }
+
+            fn visit_grouping_sets(&mut self, grouping_sets: & $($mut)? ASTNode) {
+                walk_grouping_sets(self, grouping_sets)
+            }
+
+            fn visit_cube(&mut self, cube: & $($mut)? ASTNode) {
+                walk_grouping_sets(self, cube)
+            }
+
+            fn visit_rollup(&mut self, rollup: & $($mut)? ASTNode) {
+                walk_grouping_sets(self, rollup)
+            }
+
+            fn visit_window_spec(&mut self, window_spec: & $($mut)? SQLWindowSpec) {
+                walk_window_spec(self, window_spec)
+            }
+
+            fn visit_assignment(&mut self, assignment: & $($mut)? SQLAssignment) {
+                walk_assignment(self, assignment)
+            }
+
+            fn visit_column_def(&mut self, column_def: & $($mut)? SQLColumnDef) {
+                walk_column_def(self, column_def)
+            }
+
+            fn visit_column_option(&mut self, option: & $($mut)? ColumnOption) {
+                walk_column_option(self, option)
+            }
+
+            fn visit_table_constraint(&mut self, constraint: & $($mut)? TableConstraint) {
+                walk_table_constraint(self, constraint)
+            }
+
+            fn visit_data_type(&mut self, data_type: & $($mut)? SQLType) {
+                walk_data_type(self, data_type)
+            }
+
+            fn visit_object_name(&mut self, _name: & $($mut)? SQLObjectName) {}
+        }
+
+        pub fn walk_statement<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            statement: & $($mut)? SQLStatement,
+        ) {
+            match statement {
+                SQLStatement::SQLQuery(query) => visitor.visit_query(query),
+                SQLStatement::SQLInsert { .. } => visitor.visit_insert(statement),
+                SQLStatement::SQLCopy { .. } => visitor.visit_copy(statement),
+                SQLStatement::SQLUpdate { .. } => visitor.visit_update(statement),
+                SQLStatement::SQLDelete { .. } => visitor.visit_delete(statement),
+                SQLStatement::SQLCreateView { .. } => visitor.visit_create_view(statement),
+                SQLStatement::SQLCreateTable { .. } => visitor.visit_create_table(statement),
+                SQLStatement::SQLCreateIndex { .. } => visitor.visit_create_index(statement),
+                SQLStatement::SQLCreateSchema { .. } => visitor.visit_create_schema(statement),
+                SQLStatement::SQLCreateFunction { .. } => visitor.visit_create_function(statement),
+                SQLStatement::SQLCreateSequence { .. } => visitor.visit_create_sequence(statement),
+                SQLStatement::SQLAlterSequence { .. } => visitor.visit_alter_sequence(statement),
+                SQLStatement::SQLAlterTable { .. } => visitor.visit_alter_table(statement),
+                SQLStatement::SQLDrop { .. } => visitor.visit_drop(statement),
+                SQLStatement::SQLUse { .. } => visitor.visit_use(statement),
+                SQLStatement::SQLGrant { .. } => visitor.visit_grant(statement),
+                SQLStatement::SQLGrantRole { .. } => visitor.visit_grant_role(statement),
+                SQLStatement::SQLShowVariable { .. } => visitor.visit_show_variable(statement),
+                SQLStatement::SQLShowTables { .. } => visitor.visit_show_tables(statement),
+                SQLStatement::SQLShowColumns { .. } => visitor.visit_show_columns(statement),
+                SQLStatement::SQLShowVariables { .. } => visitor.visit_show_variables(statement),
+            }
+        }
+
+        pub fn walk_insert<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            insert: & $($mut)? SQLStatement,
+        ) {
+            if let SQLStatement::SQLInsert {
+                table_name,
+                source,
+                on_conflict,
+                returning,
+                ..
+            } = insert
+            {
+                visitor.visit_object_name(table_name);
+                visitor.visit_query(source);
+                match on_conflict {
+                    Some(SQLOnConflict::DoUpdate {
+                        assignments,
+                        selection,
+                        ..
+                    }) => {
+                        for assignment in assignments {
+                            visitor.visit_assignment(assignment);
+                        }
+                        if let Some(selection) = selection {
+                            visitor.visit_expr(selection);
+                        }
+                    }
+                    Some(SQLOnConflict::DuplicateKeyUpdate(assignments)) => {
+                        for assignment in assignments {
+                            visitor.visit_assignment(assignment);
+                        }
+                    }
+                    Some(SQLOnConflict::DoNothing { .. }) | None => {}
+                }
+                walk_returning(visitor, returning);
+            }
+        }
+
+        pub fn walk_copy<V: $Visitor + ?Sized>(visitor: &mut V, copy: & $($mut)? SQLStatement) {
+            if let SQLStatement::SQLCopy { table_name, .. } = copy {
+                visitor.visit_object_name(table_name);
+            }
+        }
+
+        pub fn walk_update<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            update: & $($mut)? SQLStatement,
+        ) {
+            if let SQLStatement::SQLUpdate {
+                table_name,
+                assignments,
+                selection,
+                returning,
+            } = update
+            {
+                visitor.visit_object_name(table_name);
+                for assignment in assignments {
+                    visitor.visit_assignment(assignment);
+                }
+                if let Some(selection) = selection {
+                    visitor.visit_expr(selection);
+                }
+                walk_returning(visitor, returning);
+            }
+        }
+
+        pub fn walk_delete<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            delete: & $($mut)? SQLStatement,
+        ) {
+            if let SQLStatement::SQLDelete {
+                table_name,
+                selection,
+                returning,
+            } = delete
+            {
+                visitor.visit_object_name(table_name);
+                if let Some(selection) = selection {
+                    visitor.visit_expr(selection);
+                }
+                walk_returning(visitor, returning);
+            }
+        }
+
+        fn walk_returning<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            returning: & $($mut)? Option<Vec<SQLSelectItem>>,
+        ) {
+            if let Some(returning) = returning {
+                for select_item in returning {
+                    visitor.visit_select_item(select_item);
+                }
+            }
+        }
+
+        pub fn walk_create_view<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            create_view: & $($mut)? SQLStatement,
+        ) {
+            if let SQLStatement::SQLCreateView {
+                name,
+                query,
+                with_options,
+                ..
+            } = create_view
+            {
+                visitor.visit_object_name(name);
+                visitor.visit_query(query);
+                for option in with_options {
+                    visitor.visit_value(& $($mut)? option.value);
+                }
+            }
+        }
+
+        pub fn walk_create_table<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            create_table: & $($mut)? SQLStatement,
+        ) {
+            if let SQLStatement::SQLCreateTable {
+                name,
+                columns,
+                constraints,
+                ..
+            } = create_table
+            {
+                visitor.visit_object_name(name);
+                for column in columns {
+                    visitor.visit_column_def(column);
+                }
+                for constraint in constraints {
+                    visitor.visit_table_constraint(constraint);
+                }
+            }
+        }
+
+        pub fn walk_create_index<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            create_index: & $($mut)? SQLStatement,
+        ) {
+            if let SQLStatement::SQLCreateIndex {
+                name, table_name, ..
+            } = create_index
+            {
+                visitor.visit_object_name(name);
+                visitor.visit_object_name(table_name);
+            }
+        }
+
+        pub fn walk_create_schema<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            create_schema: & $($mut)? SQLStatement,
+        ) {
+            if let SQLStatement::SQLCreateSchema { schema_name, .. } = create_schema {
+                visitor.visit_object_name(schema_name);
+            }
+        }
+
+        pub fn walk_create_function<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            create_function: & $($mut)? SQLStatement,
+        ) {
+            if let SQLStatement::SQLCreateFunction {
+                name,
+                args,
+                return_type,
+                body,
+                ..
+            } = create_function
+            {
+                visitor.visit_object_name(name);
+                for arg in args {
+                    visitor.visit_data_type(& $($mut)? arg.data_type);
+                }
+                visitor.visit_data_type(return_type);
+                visitor.visit_value(body);
+            }
+        }
+
+        pub fn walk_create_sequence<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            create_sequence: & $($mut)? SQLStatement,
+        ) {
+            if let SQLStatement::SQLCreateSequence { name, .. } = create_sequence {
+                visitor.visit_object_name(name);
+            }
+        }
+
+        pub fn walk_alter_sequence<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            alter_sequence: & $($mut)? SQLStatement,
+        ) {
+            if let SQLStatement::SQLAlterSequence { name, .. } = alter_sequence {
+                visitor.visit_object_name(name);
+            }
+        }
+
+        pub fn walk_alter_table<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            alter_table: & $($mut)? SQLStatement,
+        ) {
+            if let SQLStatement::SQLAlterTable { name, operation } = alter_table {
+                visitor.visit_object_name(name);
+                match operation {
+                    AlterOperation::AddConstraint(constraint) => {
+                        visitor.visit_table_constraint(constraint)
+                    }
+                    AlterOperation::RenameTable { table_name } => {
+                        visitor.visit_object_name(table_name)
+                    }
+                    AlterOperation::RemoveConstraint { .. }
+                    | AlterOperation::RenameColumn { .. }
+                    | AlterOperation::RenameConstraint { .. } => {}
+                }
+            }
+        }
+
+        pub fn walk_drop<V: $Visitor + ?Sized>(visitor: &mut V, drop: & $($mut)? SQLStatement) {
+            if let SQLStatement::SQLDrop { names, .. } = drop {
+                for name in names {
+                    visitor.visit_object_name(name);
+                }
+            }
+        }
+
+        pub fn walk_use<V: $Visitor + ?Sized>(visitor: &mut V, use_stmt: & $($mut)? SQLStatement) {
+            if let SQLStatement::SQLUse { db_name } = use_stmt {
+                visitor.visit_object_name(db_name);
+            }
+        }
+
+        pub fn walk_grant<V: $Visitor + ?Sized>(visitor: &mut V, grant: & $($mut)? SQLStatement) {
+            if let SQLStatement::SQLGrant { objects, .. } = grant {
+                match objects {
+                    GrantObjects::Tables(names)
+                    | GrantObjects::AllTablesInSchema { schemas: names } => {
+                        for name in names {
+                            visitor.visit_object_name(name);
+                        }
+                    }
+                }
+            }
+        }
+
+        pub fn walk_show_tables<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            show_tables: & $($mut)? SQLStatement,
+        ) {
+            if let SQLStatement::SQLShowTables { db_name, filter } = show_tables {
+                if let Some(db_name) = db_name {
+                    visitor.visit_object_name(db_name);
+                }
+                walk_show_statement_filter(visitor, filter);
+            }
+        }
+
+        pub fn walk_show_columns<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            show_columns: & $($mut)? SQLStatement,
+        ) {
+            if let SQLStatement::SQLShowColumns { table_name, filter } = show_columns {
+                visitor.visit_object_name(table_name);
+                walk_show_statement_filter(visitor, filter);
+            }
+        }
+
+        pub fn walk_show_variables<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            show_variables: & $($mut)? SQLStatement,
+        ) {
+            if let SQLStatement::SQLShowVariables { filter } = show_variables {
+                walk_show_statement_filter(visitor, filter);
+            }
+        }
+
+        fn walk_show_statement_filter<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            filter: & $($mut)? Option<SQLShowStatementFilter>,
+        ) {
+            if let Some(SQLShowStatementFilter::Where(expr)) = filter {
+                visitor.visit_expr(expr);
+            }
+        }
+
+        pub fn walk_query<V: $Visitor + ?Sized>(visitor: &mut V, query: & $($mut)? SQLQuery) {
+            for cte in & $($mut)? query.ctes {
+                visitor.visit_cte(cte);
+            }
+            visitor.visit_set_expr(& $($mut)? query.body);
+            for order_by in & $($mut)? query.order_by {
+                visitor.visit_order_by(order_by);
+            }
+            if let Some(limit) = & $($mut)? query.limit {
+                visitor.visit_expr(limit);
+            }
+            if let Some(offset) = & $($mut)? query.offset {
+                visitor.visit_expr(& $($mut)? offset.value);
+            }
+            if let Some(fetch) = & $($mut)? query.fetch {
+                visitor.visit_fetch(fetch);
+            }
+            for lock in & $($mut)? query.locks {
+                visitor.visit_lock(lock);
+            }
+        }
+
+        pub fn walk_cte<V: $Visitor + ?Sized>(visitor: &mut V, cte: & $($mut)? Cte) {
+            visitor.visit_query(& $($mut)? cte.query);
+            if let Some(CteCycle {
+                mark: Some((value, default)),
+                ..
+            }) = & $($mut)? cte.cycle
+            {
+                visitor.visit_value(value);
+                visitor.visit_value(default);
+            }
+        }
+
+        pub fn walk_fetch<V: $Visitor + ?Sized>(visitor: &mut V, fetch: & $($mut)? Fetch) {
+            if let Some(quantity) = & $($mut)? fetch.quantity {
+                visitor.visit_expr(quantity);
+            }
+        }
+
+        pub fn walk_lock<V: $Visitor + ?Sized>(visitor: &mut V, lock: & $($mut)? LockClause) {
+            for name in & $($mut)? lock.of {
+                visitor.visit_object_name(name);
+            }
+        }
+
+        pub fn walk_set_expr<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            set_expr: & $($mut)? SQLSetExpr,
+        ) {
+            match set_expr {
+                SQLSetExpr::Select(select) => visitor.visit_select(select),
+                SQLSetExpr::Query(query) => visitor.visit_query(query),
+                SQLSetExpr::SetOperation { .. } => visitor.visit_set_operation(set_expr),
+                SQLSetExpr::Values(values) => visitor.visit_values(values),
+                SQLSetExpr::Table(name) => visitor.visit_object_name(name),
+            }
+        }
+
+        pub fn walk_set_operation<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            set_operation: & $($mut)? SQLSetExpr,
+        ) {
+            if let SQLSetExpr::SetOperation { left, right, .. } = set_operation {
+                visitor.visit_set_expr(left);
+                visitor.visit_set_expr(right);
+            }
+        }
+
+        pub fn walk_values<V: $Visitor + ?Sized>(visitor: &mut V, values: & $($mut)? SQLValues) {
+            for row in & $($mut)? values.0 {
+                for expr in row {
+                    visitor.visit_expr(expr);
+                }
+            }
+        }
+
+        pub fn walk_select<V: $Visitor + ?Sized>(visitor: &mut V, select: & $($mut)? SQLSelect) {
+            for select_item in & $($mut)? select.projection {
+                visitor.visit_select_item(select_item);
+            }
+            if let Some(relation) = & $($mut)? select.relation {
+                visitor.visit_table_factor(relation);
+            }
+            for join in & $($mut)? select.joins {
+                visitor.visit_join(join);
+            }
+            if let Some(selection) = & $($mut)? select.selection {
+                visitor.visit_expr(selection);
+            }
+            for expr in & $($mut)? select.group_by {
+                visitor.visit_expr(expr);
+            }
+            if let Some(having) = & $($mut)? select.having {
+                visitor.visit_expr(having);
+            }
+            for (_, window_spec) in & $($mut)? select.window {
+                visitor.visit_window_spec(window_spec);
+            }
+        }
+
+        pub fn walk_select_item<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            select_item: & $($mut)? SQLSelectItem,
+        ) {
+            match select_item {
+                SQLSelectItem::UnnamedExpression(expr)
+                | SQLSelectItem::ExpressionWithAlias { expr, .. } => visitor.visit_expr(expr),
+                SQLSelectItem::QualifiedWildcard(prefix) => visitor.visit_object_name(prefix),
+                SQLSelectItem::Wildcard => {}
+            }
+        }
+
+        pub fn walk_table_factor<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            table_factor: & $($mut)? TableFactor,
+        ) {
+            match table_factor {
+                TableFactor::Table {
+                    name,
+                    args,
+                    sample,
+                    with_hints,
+                    ..
+                } => {
+                    visitor.visit_object_name(name);
+                    if let Some(args) = args {
+                        for expr in args {
+                            visitor.visit_expr(expr);
+                        }
+                    }
+                    if let Some(sample) = sample {
+                        visitor.visit_expr(& $($mut)? sample.quantity);
+                        if let Some(seed) = & $($mut)? sample.repeatable {
+                            visitor.visit_expr(seed);
+                        }
+                    }
+                    for expr in with_hints {
+                        visitor.visit_expr(expr);
+                    }
+                }
+                TableFactor::Derived { subquery, .. } => visitor.visit_query(subquery),
+                TableFactor::JsonTable { json_expr, .. } => visitor.visit_expr(json_expr),
+                TableFactor::Unnest { array_expr, .. } => visitor.visit_expr(array_expr),
+            }
+        }
+
+        pub fn walk_join<V: $Visitor + ?Sized>(visitor: &mut V, join: & $($mut)? Join) {
+            visitor.visit_table_factor(& $($mut)? join.relation);
+            match & $($mut)? join.join_operator {
+                JoinOperator::Inner(JoinConstraint::On(expr))
+                | JoinOperator::LeftOuter(JoinConstraint::On(expr))
+                | JoinOperator::RightOuter(JoinConstraint::On(expr))
+                | JoinOperator::FullOuter(JoinConstraint::On(expr))
+                | JoinOperator::StraightJoin(JoinConstraint::On(expr)) => visitor.visit_expr(expr),
+                _ => {}
+            }
+        }
+
+        pub fn walk_order_by<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            order_by: & $($mut)? SQLOrderByExpr,
+        ) {
+            visitor.visit_expr(& $($mut)? order_by.expr);
+        }
+
+        pub fn walk_expr<V: $Visitor + ?Sized>(visitor: &mut V, expr: & $($mut)? ASTNode) {
+            match expr {
+                ASTNode::SQLIdentifier(_) => visitor.visit_identifier(expr),
+                ASTNode::SQLVariable(_) => visitor.visit_variable(expr),
+                ASTNode::SQLWildcard => visitor.visit_wildcard(expr),
+                ASTNode::SQLQualifiedWildcard(_) => visitor.visit_qualified_wildcard(expr),
+                ASTNode::SQLCompoundIdentifier(_) => visitor.visit_compound_identifier(expr),
+                ASTNode::SQLIsNull(_) => visitor.visit_is_null(expr),
+                ASTNode::SQLIsNotNull(_) => visitor.visit_is_not_null(expr),
+                ASTNode::SQLIsNormalized { .. } => visitor.visit_is_normalized(expr),
+                ASTNode::SQLInList { .. } => visitor.visit_in_list(expr),
+                ASTNode::SQLInSubquery { .. } => visitor.visit_in_subquery(expr),
+                ASTNode::SQLBetween { .. } => visitor.visit_between(expr),
+                ASTNode::SQLLike { .. } => visitor.visit_like(expr),
+                ASTNode::SQLBinaryExpr { .. } => visitor.visit_binary_expr(expr),
+                ASTNode::SQLCast { .. } => visitor.visit_cast(expr),
+                ASTNode::SQLJsonFunction { .. } => visitor.visit_json_function(expr),
+                ASTNode::SQLJsonKeyValue { .. } => visitor.visit_json_key_value(expr),
+                ASTNode::SQLNamedArgument { .. } => visitor.visit_named_argument(expr),
+                ASTNode::SQLCollate { .. } => visitor.visit_collate(expr),
+                ASTNode::SQLNested(_) => visitor.visit_nested(expr),
+                ASTNode::SQLUnary { .. } => visitor.visit_unary(expr),
+                ASTNode::SQLValue(value) => visitor.visit_value(value),
+                ASTNode::SQLFunction { .. } => visitor.visit_function(expr),
+                ASTNode::SQLCase { .. } => visitor.visit_case(expr),
+                ASTNode::SQLSubquery(_) => visitor.visit_subquery(expr),
+                ASTNode::SQLGroupingSets(_) => visitor.visit_grouping_sets(expr),
+                ASTNode::SQLCube(_) => visitor.visit_cube(expr),
+                ASTNode::SQLRollup(_) => visitor.visit_rollup(expr),
+            }
+        }
+
+        pub fn walk_is_null<V: $Visitor + ?Sized>(visitor: &mut V, is_null: & $($mut)? ASTNode) {
+            if let ASTNode::SQLIsNull(expr) = is_null {
+                visitor.visit_expr(expr);
+            }
+        }
+
+        pub fn walk_is_not_null<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            is_not_null: & $($mut)? ASTNode,
+        ) {
+            if let ASTNode::SQLIsNotNull(expr) = is_not_null {
+                visitor.visit_expr(expr);
+            }
+        }
+
+        pub fn walk_is_normalized<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            is_normalized: & $($mut)? ASTNode,
+        ) {
+            if let ASTNode::SQLIsNormalized { expr, .. } = is_normalized {
+                visitor.visit_expr(expr);
+            }
+        }
+
+        pub fn walk_in_list<V: $Visitor + ?Sized>(visitor: &mut V, in_list: & $($mut)? ASTNode) {
+            if let ASTNode::SQLInList { expr, list, .. } = in_list {
+                visitor.visit_expr(expr);
+                for expr in list {
+                    visitor.visit_expr(expr);
+                }
+            }
+        }
+
+        pub fn walk_in_subquery<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            in_subquery: & $($mut)? ASTNode,
+        ) {
+            if let ASTNode::SQLInSubquery { expr, subquery, .. } = in_subquery {
+                visitor.visit_expr(expr);
+                visitor.visit_query(subquery);
+            }
+        }
+
+        pub fn walk_between<V: $Visitor + ?Sized>(visitor: &mut V, between: & $($mut)? ASTNode) {
+            if let ASTNode::SQLBetween {
+                expr, low, high, ..
+            } = between
+            {
+                visitor.visit_expr(expr);
+                visitor.visit_expr(low);
+                visitor.visit_expr(high);
+            }
+        }
+
+        pub fn walk_like<V: $Visitor + ?Sized>(visitor: &mut V, like: & $($mut)? ASTNode) {
+            if let ASTNode::SQLLike { expr, pattern, .. } = like {
+                visitor.visit_expr(expr);
+                visitor.visit_expr(pattern);
+            }
+        }
+
+        pub fn walk_binary_expr<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            binary_expr: & $($mut)? ASTNode,
+        ) {
+            if let ASTNode::SQLBinaryExpr { left, right, .. } = binary_expr {
+                visitor.visit_expr(left);
+                visitor.visit_expr(right);
+            }
+        }
+
+        pub fn walk_cast<V: $Visitor + ?Sized>(visitor: &mut V, cast: & $($mut)? ASTNode) {
+            if let ASTNode::SQLCast { expr, data_type } = cast {
+                visitor.visit_expr(expr);
+                visitor.visit_data_type(data_type);
+            }
+        }
+
+        pub fn walk_json_function<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            json_function: & $($mut)? ASTNode,
+        ) {
+            if let ASTNode::SQLJsonFunction {
+                json_expr,
+                returning,
+                on_empty,
+                on_error,
+                ..
+            } = json_function
+            {
+                visitor.visit_expr(json_expr);
+                if let Some(data_type) = returning {
+                    visitor.visit_data_type(data_type);
+                }
+                if let Some(SQLJsonBehavior::Default(expr)) = on_empty {
+                    visitor.visit_expr(expr);
+                }
+                if let Some(SQLJsonBehavior::Default(expr)) = on_error {
+                    visitor.visit_expr(expr);
+                }
+            }
+        }
+
+        pub fn walk_json_key_value<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            json_key_value: & $($mut)? ASTNode,
+        ) {
+            if let ASTNode::SQLJsonKeyValue { key, value } = json_key_value {
+                visitor.visit_expr(key);
+                visitor.visit_expr(value);
+            }
+        }
+
+        pub fn walk_named_argument<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            named_argument: & $($mut)? ASTNode,
+        ) {
+            if let ASTNode::SQLNamedArgument { arg, .. } = named_argument {
+                visitor.visit_expr(arg);
+            }
+        }
+
+        pub fn walk_collate<V: $Visitor + ?Sized>(visitor: &mut V, collate: & $($mut)? ASTNode) {
+            if let ASTNode::SQLCollate { expr, collation } = collate {
+                visitor.visit_expr(expr);
+                visitor.visit_object_name(collation);
+            }
+        }
+
+        pub fn walk_nested<V: $Visitor + ?Sized>(visitor: &mut V, nested: & $($mut)? ASTNode) {
+            if let ASTNode::SQLNested(expr) = nested {
+                visitor.visit_expr(expr);
+            }
+        }
+
+        pub fn walk_unary<V: $Visitor + ?Sized>(visitor: &mut V, unary: & $($mut)? ASTNode) {
+            if let ASTNode::SQLUnary { expr, .. } = unary {
+                visitor.visit_expr(expr);
+            }
+        }
+
+        pub fn walk_function<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            function: & $($mut)? ASTNode,
+        ) {
+            if let ASTNode::SQLFunction {
+                name,
+                args,
+                order_by,
+                within_group,
+                over,
+                ..
+            } = function
+            {
+                visitor.visit_object_name(name);
+                for expr in args {
+                    visitor.visit_expr(expr);
+                }
+                for order_by_expr in order_by {
+                    visitor.visit_order_by(order_by_expr);
+                }
+                for order_by_expr in within_group {
+                    visitor.visit_order_by(order_by_expr);
+                }
+                if let Some(over) = over {
+                    if let SQLWindowType::WindowSpec(window_spec) = & $($mut)? **over {
+                        visitor.visit_window_spec(window_spec);
+                    }
+                }
+            }
+        }
+
+        pub fn walk_case<V: $Visitor + ?Sized>(visitor: &mut V, case: & $($mut)? ASTNode) {
+            if let ASTNode::SQLCase {
+                operand,
+                conditions,
+                results,
+                else_result,
+            } = case
+            {
+                if let Some(operand) = operand {
+                    visitor.visit_expr(operand);
+                }
+                for expr in conditions {
+                    visitor.visit_expr(expr);
+                }
+                for expr in results {
+                    visitor.visit_expr(expr);
+                }
+                if let Some(else_result) = else_result {
+                    visitor.visit_expr(else_result);
+                }
+            }
+        }
+
+        pub fn walk_subquery<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            subquery: & $($mut)? ASTNode,
+        ) {
+            if let ASTNode::SQLSubquery(query) = subquery {
+                visitor.visit_query(query);
+            }
+        }
+
+        /// Walk the sets of a `GROUPING SETS`, `CUBE` or `ROLLUP` node
+        pub fn walk_grouping_sets<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            grouping_sets: & $($mut)? ASTNode,
+        ) {
+            match grouping_sets {
+                ASTNode::SQLGroupingSets(sets) | ASTNode::SQLCube(sets) | ASTNode::SQLRollup(sets) => {
+                    for set in sets {
+                        for expr in set {
+                            visitor.visit_expr(expr);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        pub fn walk_window_spec<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            window_spec: & $($mut)? SQLWindowSpec,
+        ) {
+            for expr in & $($mut)? window_spec.partition_by {
+                visitor.visit_expr(expr);
+            }
+            for order_by in & $($mut)? window_spec.order_by {
+                visitor.visit_order_by(order_by);
+            }
+        }
+
+        pub fn walk_assignment<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            assignment: & $($mut)? SQLAssignment,
+        ) {
+            visitor.visit_expr(& $($mut)? assignment.value);
+        }
+
+        pub fn walk_column_def<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            column_def: & $($mut)? SQLColumnDef,
+        ) {
+            visitor.visit_data_type(& $($mut)? column_def.data_type);
+            for option_def in & $($mut)? column_def.options {
+                visitor.visit_column_option(& $($mut)? option_def.option);
+            }
+        }
+
+        pub fn walk_column_option<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            option: & $($mut)? ColumnOption,
+        ) {
+            match option {
+                ColumnOption::Default(expr) | ColumnOption::Check(expr) => visitor.visit_expr(expr),
+                ColumnOption::ForeignKey { foreign_table, .. } => {
+                    visitor.visit_object_name(foreign_table)
+                }
+                ColumnOption::Null | ColumnOption::NotNull | ColumnOption::Unique { .. } => {}
+            }
+        }
+
+        pub fn walk_table_constraint<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            constraint: & $($mut)? TableConstraint,
+        ) {
+            match constraint {
+                TableConstraint::ForeignKey { foreign_table, .. } => {
+                    visitor.visit_object_name(foreign_table)
+                }
+                TableConstraint::Check { expr, .. } => visitor.visit_expr(expr),
+                TableConstraint::Unique { .. } => {}
+            }
+        }
+
+        pub fn walk_data_type<V: $Visitor + ?Sized>(
+            visitor: &mut V,
+            data_type: & $($mut)? SQLType,
+        ) {
+            match data_type {
+                SQLType::Custom(name) => visitor.visit_object_name(name),
+                SQLType::Array(data_type, _) => visitor.visit_data_type(data_type),
+                _ => {}
+            }
+        }
+    };
+}
+
+/// Traversal of the AST by shared reference
+pub mod visit {
+    use super::super::*;
+
+    define_visitor!(Visitor);
+}
+
+/// Traversal of the AST by mutable reference, to rewrite it in place
+pub mod visit_mut {
+    use super::super::*;
+
+    define_visitor!(VisitorMut, mut);
+}
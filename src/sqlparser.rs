@@ -20,15 +20,21 @@ use super::dialect::keywords;
 use super::dialect::Dialect;
 use super::sqlast::*;
 use super::sqltokenizer::*;
+use std::borrow::Cow;
 use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum ParserError {
     TokenizerError(String),
     ParserError(String),
+    /// The input was nested more deeply than the parser's recursion limit
+    /// allows, see `Parser::with_recursion_limit`
+    RecursionLimitExceeded,
 }
 
-// Use `Parser::expected` instead, if possible
+// Use `Parser::expected` or `Parser::error_at` instead, if possible
 macro_rules! parser_err {
     ($MSG:expr) => {
         Err(ParserError::ParserError($MSG.to_string()))
@@ -56,6 +62,7 @@ impl std::fmt::Display for ParserError {
             match self {
                 ParserError::TokenizerError(s) => s,
                 ParserError::ParserError(s) => s,
+                ParserError::RecursionLimitExceeded => "recursion limit exceeded",
             }
         )
     }
@@ -63,39 +70,150 @@ impl std::fmt::Display for ParserError {
 
 impl Error for ParserError {}
 
+/// The type of the identifiers in the AST built by a `Parser`: `SQLIdent`
+/// for `Parser::parse_sql`, or `Cow<'a, str>`, borrowing from the input
+/// where possible, for `Parser::parse_sql_borrowed`
+pub trait ParserIdent<'a>:
+    From<Cow<'a, str>> + Into<String> + AsRef<str> + Clone + PartialEq + fmt::Debug + fmt::Display
+{
+}
+
+impl<'a, I> ParserIdent<'a> for I where
+    I: From<Cow<'a, str>>
+        + Into<String>
+        + AsRef<str>
+        + Clone
+        + PartialEq
+        + fmt::Debug
+        + fmt::Display
+{
+}
+
 /// SQL Parser
-pub struct Parser {
-    tokens: Vec<Token>,
+///
+/// The parser works on a sequence of `Token`s, as produced by the `Tokenizer`
+/// (including the whitespace tokens, which it skips as needed). Callers that
+/// have already tokenized their input can create a parser directly from the
+/// tokens and invoke any of the public `parse_*` methods on it.
+///
+/// The identifiers in the AST it builds are of type `I`, see `ParserIdent`.
+pub struct Parser<'a, I = SQLIdent> {
+    tokens: Vec<TokenWithLocation<'a>>,
     index: usize,
+    dialect: &'a dyn Dialect,
+    /// How deeply expressions and queries may nest before the parser gives
+    /// up with `ParserError::RecursionLimitExceeded`
+    recursion_limit: usize,
+    /// How deeply nested the expression or query currently being parsed is
+    depth: usize,
+    ident: PhantomData<I>,
 }
 
-impl Parser {
-    /// Parse the specified tokens
-    pub fn new(tokens: Vec<Token>) -> Self {
-        Parser { tokens, index: 0 }
+/// The arguments of a function call, and the `ORDER BY` inside its parentheses
+type FunctionArgs<I> = (Vec<ASTNode<I>>, Vec<SQLOrderByExpr<I>>);
+
+/// The column definitions and table constraints of `CREATE TABLE`
+type Columns<I> = (Vec<SQLColumnDef<I>>, Vec<TableConstraint<I>>);
+
+/// The default `Parser::with_recursion_limit`, low enough to avoid
+/// overflowing the stack of a thread with the default size
+pub const DEFAULT_RECURSION_LIMIT: usize = 50;
+
+impl<'a> Parser<'a> {
+    /// Parse the specified tokens, as returned by `Tokenizer::tokenize`.
+    /// Since the tokens carry no location, errors will not include one.
+    pub fn new(tokens: Vec<Token<'a>>, dialect: &'a dyn Dialect) -> Self {
+        let tokens = tokens
+            .into_iter()
+            .map(|token| TokenWithLocation {
+                token,
+                location: Location::UNKNOWN,
+            })
+            .collect();
+        Parser::new_with_locations(tokens, dialect)
+    }
+
+    /// Parse the specified tokens, as returned by `Tokenizer::tokenize_with_location`
+    pub fn new_with_locations(
+        tokens: Vec<TokenWithLocation<'a>>,
+        dialect: &'a dyn Dialect,
+    ) -> Self {
+        Parser::from_tokens(tokens, dialect)
     }
 
     /// Parse a SQL statement and produce an Abstract Syntax Tree (AST)
     pub fn parse_sql(dialect: &dyn Dialect, sql: String) -> Result<Vec<SQLStatement>, ParserError> {
+        debug!("Parsing sql '{}'...", sql);
         let mut tokenizer = Tokenizer::new(dialect, &sql);
-        let tokens = tokenizer.tokenize()?;
-        let mut parser = Parser::new(tokens);
+        let tokens = tokenizer.tokenize_with_location()?;
+        Parser::new_with_locations(tokens, dialect).parse_statements()
+    }
+}
+
+impl<'a> Parser<'a, Cow<'a, str>> {
+    /// Like `parse_sql`, but produce an AST whose identifiers borrow from
+    /// `sql` instead of being copied, avoiding an allocation per identifier.
+    /// Only the identifiers that differ from their text in `sql` are owned:
+    /// the delimited ones, which keep their quotes, e.g. `"id"`.
+    pub fn parse_sql_borrowed(
+        dialect: &'a dyn Dialect,
+        sql: &'a str,
+    ) -> Result<Vec<SQLStatement<Cow<'a, str>>>, ParserError> {
+        debug!("Parsing sql '{}'...", sql);
+        let mut tokenizer = Tokenizer::new(dialect, sql);
+        let tokens = tokenizer.tokenize_with_location()?;
+        Parser::from_tokens(tokens, dialect).parse_statements()
+    }
+}
+
+impl<'a, I: ParserIdent<'a>> Parser<'a, I> {
+    fn from_tokens(tokens: Vec<TokenWithLocation<'a>>, dialect: &'a dyn Dialect) -> Self {
+        Parser {
+            tokens,
+            index: 0,
+            dialect,
+            recursion_limit: DEFAULT_RECURSION_LIMIT,
+            depth: 0,
+            ident: PhantomData,
+        }
+    }
+
+    /// Set how deeply expressions and queries may nest, instead of the
+    /// `DEFAULT_RECURSION_LIMIT`. Deeper input is rejected with
+    /// `ParserError::RecursionLimitExceeded` rather than overflowing the stack.
+    pub fn with_recursion_limit(mut self, recursion_limit: usize) -> Self {
+        self.recursion_limit = recursion_limit;
+        self
+    }
+
+    /// The dialect this parser was created with
+    pub fn dialect(&self) -> &'a dyn Dialect {
+        self.dialect
+    }
+
+    /// Return the tokens that have not yet been processed, including
+    /// whitespace, without consuming them
+    pub fn remaining_tokens(&self) -> impl Iterator<Item = &Token<'a>> + '_ {
+        self.tokens[self.index..].iter().map(|t| &t.token)
+    }
+
+    /// Parse statements separated by semicolons, up to the end of the input
+    fn parse_statements(&mut self) -> Result<Vec<SQLStatement<I>>, ParserError> {
         let mut stmts = Vec::new();
         let mut expecting_statement_delimiter = false;
-        debug!("Parsing sql '{}'...", sql);
         loop {
             // ignore empty statements (between successive statement delimiters)
-            while parser.consume_token(&Token::SemiColon) {
+            while self.consume_token(&Token::SemiColon) {
                 expecting_statement_delimiter = false;
             }
 
-            if parser.peek_token().is_none() {
+            if self.peek_token().is_none() {
                 break;
             } else if expecting_statement_delimiter {
-                return parser.expected("end of statement", parser.peek_token());
+                return self.expected("end of statement", self.til_non_whitespace());
             }
 
-            let statement = parser.parse_statement()?;
+            let statement = self.parse_statement()?;
             stmts.push(statement);
             expecting_statement_delimiter = true;
         }
@@ -104,11 +222,11 @@ impl Parser {
 
     /// Parse a single top-level statement (such as SELECT, INSERT, CREATE, etc.),
     /// stopping before the statement separator, if any.
-    pub fn parse_statement(&mut self) -> Result<SQLStatement, ParserError> {
+    pub fn parse_statement(&mut self) -> Result<SQLStatement<I>, ParserError> {
         match self.next_token() {
             Some(t) => match t {
-                Token::SQLWord(ref w) if w.keyword != "" => match w.keyword.as_ref() {
-                    "SELECT" | "WITH" => {
+                Token::SQLWord(ref w) if !w.keyword.is_empty() => match w.keyword {
+                    "SELECT" | "WITH" | "VALUES" | "TABLE" => {
                         self.prev_token();
                         Ok(SQLStatement::SQLQuery(Box::new(self.parse_query()?)))
                     }
@@ -116,16 +234,25 @@ impl Parser {
                     "DROP" => Ok(self.parse_drop()?),
                     "DELETE" => Ok(self.parse_delete()?),
                     "INSERT" => Ok(self.parse_insert()?),
+                    "UPDATE" => Ok(self.parse_update()?),
                     "ALTER" => Ok(self.parse_alter()?),
                     "COPY" => Ok(self.parse_copy()?),
-                    _ => parser_err!(format!(
-                        "Unexpected keyword {:?} at the beginning of a statement",
-                        w.to_string()
-                    )),
+                    "USE" => Ok(SQLStatement::SQLUse {
+                        db_name: self.parse_object_name()?,
+                    }),
+                    "GRANT" => Ok(self.parse_grant()?),
+                    "SHOW" => Ok(self.parse_show()?),
+                    _ => self.error_at(
+                        &format!(
+                            "Unexpected keyword {:?} at the beginning of a statement",
+                            w.to_string()
+                        ),
+                        self.prev_non_whitespace(),
+                    ),
                 },
-                unexpected => self.expected(
+                _ => self.expected(
                     "a keyword at the beginning of a statement",
-                    Some(unexpected),
+                    self.prev_non_whitespace(),
                 ),
             },
             None => self.expected("SQL statement", None),
@@ -133,29 +260,48 @@ impl Parser {
     }
 
     /// Parse a new expression
-    pub fn parse_expr(&mut self) -> Result<ASTNode, ParserError> {
+    pub fn parse_expr(&mut self) -> Result<ASTNode<I>, ParserError> {
         self.parse_subexpr(0)
     }
 
-    /// Parse tokens until the precedence changes
-    pub fn parse_subexpr(&mut self, precedence: u8) -> Result<ASTNode, ParserError> {
-        debug!("parsing expr");
-        let mut expr = self.parse_prefix()?;
-        debug!("prefix: {:?}", expr);
-        loop {
-            let next_precedence = self.get_next_precedence()?;
-            debug!("next precedence: {:?}", next_precedence);
-            if precedence >= next_precedence {
-                break;
+    /// Parse tokens until the precedence changes. Operators of the same (or
+    /// a lower) precedence are consumed by the loop rather than by recursion,
+    /// so a long chain like `a + b + c ...` doesn't nest any deeper.
+    pub fn parse_subexpr(&mut self, precedence: u8) -> Result<ASTNode<I>, ParserError> {
+        self.recursion_guarded(|parser| {
+            debug!("parsing expr");
+            let mut expr = parser.parse_prefix()?;
+            debug!("prefix: {:?}", expr);
+            loop {
+                let next_precedence = parser.get_next_precedence()?;
+                debug!("next precedence: {:?}", next_precedence);
+                if precedence >= next_precedence {
+                    break;
+                }
+
+                expr = parser.parse_infix(expr, next_precedence)?;
             }
+            Ok(expr)
+        })
+    }
 
-            expr = self.parse_infix(expr, next_precedence)?;
+    /// Run `f` one level of nesting deeper, failing instead if that would
+    /// exceed the recursion limit
+    fn recursion_guarded<T, F>(&mut self, f: F) -> Result<T, ParserError>
+    where
+        F: FnOnce(&mut Parser<'a, I>) -> Result<T, ParserError>,
+    {
+        if self.depth >= self.recursion_limit {
+            return Err(ParserError::RecursionLimitExceeded);
         }
-        Ok(expr)
+        self.depth += 1;
+        let result = f(self);
+        self.depth -= 1;
+        result
     }
 
     /// Parse expression for DEFAULT clause in CREATE TABLE
-    pub fn parse_default_expr(&mut self, precedence: u8) -> Result<ASTNode, ParserError> {
+    pub fn parse_default_expr(&mut self, precedence: u8) -> Result<ASTNode<I>, ParserError> {
         debug!("parsing expr");
         let mut expr = self.parse_prefix()?;
         debug!("prefix: {:?}", expr);
@@ -178,18 +324,24 @@ impl Parser {
     }
 
     /// Parse an expression prefix
-    pub fn parse_prefix(&mut self) -> Result<ASTNode, ParserError> {
+    pub fn parse_prefix(&mut self) -> Result<ASTNode<I>, ParserError> {
         let tok = self
             .next_token()
             .ok_or_else(|| ParserError::ParserError("Unexpected EOF".to_string()))?;
         let expr = match tok {
-            Token::SQLWord(w) => match w.keyword.as_ref() {
+            Token::SQLWord(w) => match w.keyword {
                 "TRUE" | "FALSE" | "NULL" => {
                     self.prev_token();
                     self.parse_sql_value()
                 }
                 "CASE" => self.parse_case_expression(),
                 "CAST" => self.parse_cast_expression(),
+                "JSON_VALUE" | "JSON_QUERY" | "JSON_EXISTS"
+                    if self.peek_token() == Some(Token::LParen) =>
+                {
+                    self.prev_token();
+                    self.parse_json_function()
+                }
                 "NOT" => {
                     let p = self.get_precedence(&Token::make_keyword("NOT"))?;
                     Ok(ASTNode::SQLUnary {
@@ -197,11 +349,15 @@ impl Parser {
                         expr: Box::new(self.parse_subexpr(p)?),
                     })
                 }
+                // `@` can only start a word in dialects that have variables
+                _ if w.quote_style.is_none() && w.value.starts_with('@') => {
+                    Ok(ASTNode::SQLVariable(I::from(w.value)))
+                }
                 // Here `w` is a word, check if it's a part of a multi-part
                 // identifier, a function call, or a simple identifier:
                 _ => match self.peek_token() {
                     Some(Token::LParen) | Some(Token::Period) => {
-                        let mut id_parts: Vec<SQLIdent> = vec![w.as_sql_ident()];
+                        let mut id_parts: Vec<I> = vec![w.as_sql_ident()];
                         let mut ends_with_wildcard = false;
                         while self.consume_token(&Token::Period) {
                             match self.next_token() {
@@ -211,8 +367,10 @@ impl Parser {
                                     break;
                                 }
                                 unexpected => {
-                                    return self
-                                        .expected("an identifier or a '*' after '.'", unexpected);
+                                    return self.expected(
+                                        "an identifier or a '*' after '.'",
+                                        unexpected.and(self.prev_non_whitespace()),
+                                    );
                                 }
                             }
                         }
@@ -241,7 +399,11 @@ impl Parser {
                     expr: Box::new(self.parse_subexpr(p)?),
                 })
             }
-            Token::Number(_) | Token::SingleQuotedString(_) | Token::NationalStringLiteral(_) => {
+            Token::Number(_)
+            | Token::SingleQuotedString(_)
+            | Token::NationalStringLiteral(_)
+            | Token::DollarQuotedString { .. }
+            | Token::Placeholder(_) => {
                 self.prev_token();
                 self.parse_sql_value()
             }
@@ -255,7 +417,7 @@ impl Parser {
                 self.expect_token(&Token::RParen)?;
                 Ok(expr)
             }
-            unexpected => self.expected("an expression", Some(unexpected)),
+            _ => self.expected("an expression", self.prev_non_whitespace()),
         }?;
 
         if self.parse_keyword("COLLATE") {
@@ -268,38 +430,51 @@ impl Parser {
         }
     }
 
-    pub fn parse_function(&mut self, name: SQLObjectName) -> Result<ASTNode, ParserError> {
+    pub fn parse_function(&mut self, name: SQLObjectName<I>) -> Result<ASTNode<I>, ParserError> {
         self.expect_token(&Token::LParen)?;
         let all = self.parse_keyword("ALL");
         let distinct = self.parse_keyword("DISTINCT");
-        if all && distinct {
-            return parser_err!(format!(
-                "Cannot specify both ALL and DISTINCT in function: {}",
-                name.to_string(),
-            ));
+        // Also reject the reverse order, `DISTINCT ALL`, which would otherwise
+        // have `ALL` parsed as an identifier argument.
+        if distinct && (all || self.parse_keyword("ALL")) {
+            return self.error_at(
+                &format!("Cannot specify both ALL and DISTINCT in function: {}", name,),
+                self.prev_non_whitespace(),
+            );
         }
-        let args = self.parse_optional_args()?;
-        let over = if self.parse_keyword("OVER") {
-            // TBD: support window names (`OVER mywin`) in place of inline specification
+        let is_json_object = match name.0.as_slice() {
+            [ident] => {
+                let ident = ident.as_ref();
+                ident.eq_ignore_ascii_case("JSON_OBJECT")
+                    || ident.eq_ignore_ascii_case("JSON_OBJECTAGG")
+            }
+            _ => false,
+        };
+        let (args, order_by) = if is_json_object {
+            self.parse_json_object_args()?
+        } else {
+            self.parse_function_args()?
+        };
+        let within_group = if self.parse_keywords(vec!["WITHIN", "GROUP"]) {
             self.expect_token(&Token::LParen)?;
-            let partition_by = if self.parse_keywords(vec!["PARTITION", "BY"]) {
-                // a list of possibly-qualified column names
-                self.parse_expr_list()?
-            } else {
-                vec![]
-            };
-            let order_by = if self.parse_keywords(vec!["ORDER", "BY"]) {
-                self.parse_order_by_expr_list()?
+            self.expect_keyword("ORDER")?;
+            self.expect_keyword("BY")?;
+            let order_by = self.parse_order_by_expr_list()?;
+            self.expect_token(&Token::RParen)?;
+            order_by
+        } else {
+            vec![]
+        };
+        let over = if self.parse_keyword("OVER") {
+            if self.consume_token(&Token::LParen) {
+                Some(Box::new(SQLWindowType::WindowSpec(
+                    self.parse_window_spec()?,
+                )))
             } else {
-                vec![]
-            };
-            let window_frame = self.parse_window_frame()?;
-
-            Some(SQLWindowSpec {
-                partition_by,
-                order_by,
-                window_frame,
-            })
+                Some(Box::new(SQLWindowType::NamedWindow(
+                    self.parse_identifier()?,
+                )))
+            }
         } else {
             None
         };
@@ -307,11 +482,81 @@ impl Parser {
         Ok(ASTNode::SQLFunction {
             name,
             args,
+            order_by,
+            within_group,
             over,
             distinct,
         })
     }
 
+    /// Parse the `<key> VALUE <value>` arguments of `JSON_OBJECT` or
+    /// `JSON_OBJECTAGG`, including the closing parenthesis. If the first
+    /// key is not followed by `VALUE`, the arguments are parsed as those of
+    /// an ordinary function instead, as in MySQL's `JSON_OBJECT(key, value)`.
+    fn parse_json_object_args(&mut self) -> Result<FunctionArgs<I>, ParserError> {
+        if self.consume_token(&Token::RParen) {
+            return Ok((vec![], vec![]));
+        }
+        let index = self.index;
+        self.parse_expr()?;
+        let is_key_value = self.parse_keyword("VALUE");
+        self.index = index;
+        if !is_key_value {
+            return self.parse_function_args();
+        }
+        let args = self.parse_comma_separated(|parser| {
+            let key = parser.parse_expr()?;
+            parser.expect_keyword("VALUE")?;
+            let value = parser.parse_expr()?;
+            Ok(ASTNode::SQLJsonKeyValue {
+                key: Box::new(key),
+                value: Box::new(value),
+            })
+        })?;
+        self.expect_token(&Token::RParen)?;
+        Ok((args, vec![]))
+    }
+
+    /// Parse a window specification, assuming the opening parenthesis was
+    /// already consumed. The closing parenthesis is consumed as well.
+    pub fn parse_window_spec(&mut self) -> Result<SQLWindowSpec<I>, ParserError> {
+        let window_name = match self.peek_token() {
+            Some(Token::SQLWord(ref w))
+                if !["PARTITION", "ORDER", "ROWS", "RANGE", "GROUPS"].contains(&w.keyword) =>
+            {
+                Some(self.parse_identifier()?)
+            }
+            _ => None,
+        };
+        let partition_by = if self.parse_keywords(vec!["PARTITION", "BY"]) {
+            // a list of possibly-qualified column names
+            self.parse_expr_list()?
+        } else {
+            vec![]
+        };
+        let order_by = if self.parse_keywords(vec!["ORDER", "BY"]) {
+            self.parse_order_by_expr_list()?
+        } else {
+            vec![]
+        };
+        let window_frame = self.parse_window_frame()?;
+
+        Ok(SQLWindowSpec {
+            window_name,
+            partition_by,
+            order_by,
+            window_frame,
+        })
+    }
+
+    /// Parse a `name AS (window_spec)` definition of the `WINDOW` clause
+    pub fn parse_named_window(&mut self) -> Result<(I, SQLWindowSpec<I>), ParserError> {
+        let name = self.parse_identifier()?;
+        self.expect_keyword("AS")?;
+        self.expect_token(&Token::LParen)?;
+        Ok((name, self.parse_window_spec()?))
+    }
+
     pub fn parse_window_frame(&mut self) -> Result<Option<SQLWindowFrame>, ParserError> {
         let window_frame = match self.peek_token() {
             Some(Token::SQLWord(w)) => {
@@ -337,7 +582,12 @@ impl Parser {
                 }
             }
             Some(Token::RParen) => None,
-            unexpected => return self.expected("'ROWS', 'RANGE', 'GROUPS', or ')'", unexpected),
+            _ => {
+                return self.expected(
+                    "'ROWS', 'RANGE', 'GROUPS', or ')'",
+                    self.til_non_whitespace(),
+                )
+            }
         };
         self.expect_token(&Token::RParen)?;
         Ok(window_frame)
@@ -353,10 +603,10 @@ impl Parser {
             } else {
                 let rows = self.parse_literal_int()?;
                 if rows < 0 {
-                    parser_err!(format!(
-                        "The number of rows must be non-negative, got {}",
-                        rows
-                    ))?;
+                    self.error_at(
+                        &format!("The number of rows must be non-negative, got {}", rows),
+                        self.prev_non_whitespace(),
+                    )?;
                 }
                 Some(rows as u64)
             };
@@ -365,12 +615,12 @@ impl Parser {
             } else if self.parse_keyword("FOLLOWING") {
                 Ok(SQLWindowFrameBound::Following(rows))
             } else {
-                self.expected("PRECEDING or FOLLOWING", self.peek_token())
+                self.expected("PRECEDING or FOLLOWING", self.til_non_whitespace())
             }
         }
     }
 
-    pub fn parse_case_expression(&mut self) -> Result<ASTNode, ParserError> {
+    pub fn parse_case_expression(&mut self) -> Result<ASTNode<I>, ParserError> {
         let mut operand = None;
         if !self.parse_keyword("WHEN") {
             operand = Some(Box::new(self.parse_expr()?));
@@ -401,7 +651,7 @@ impl Parser {
     }
 
     /// Parse a SQL CAST function e.g. `CAST(expr AS FLOAT)`
-    pub fn parse_cast_expression(&mut self) -> Result<ASTNode, ParserError> {
+    pub fn parse_cast_expression(&mut self) -> Result<ASTNode<I>, ParserError> {
         self.expect_token(&Token::LParen)?;
         let expr = self.parse_expr()?;
         self.expect_keyword("AS")?;
@@ -413,8 +663,80 @@ impl Parser {
         })
     }
 
+    /// Parse a SQL/JSON query function, e.g. `JSON_VALUE(data, '$.x' RETURNING int)`
+    pub fn parse_json_function(&mut self) -> Result<ASTNode<I>, ParserError> {
+        let function =
+            match self.expect_one_of_keywords(&["JSON_VALUE", "JSON_QUERY", "JSON_EXISTS"])? {
+                "JSON_VALUE" => SQLJsonFunctionName::JsonValue,
+                "JSON_QUERY" => SQLJsonFunctionName::JsonQuery,
+                "JSON_EXISTS" => SQLJsonFunctionName::JsonExists,
+                _ => unreachable!(),
+            };
+        self.expect_token(&Token::LParen)?;
+        let json_expr = self.parse_expr()?;
+        self.expect_token(&Token::Comma)?;
+        let json_path = self.parse_literal_string()?;
+        let returning = if self.parse_keyword("RETURNING") {
+            Some(self.parse_data_type()?)
+        } else {
+            None
+        };
+        let on_empty = self.parse_json_behavior_clause("EMPTY")?;
+        let on_error = self.parse_json_behavior_clause("ERROR")?;
+        self.expect_token(&Token::RParen)?;
+        Ok(ASTNode::SQLJsonFunction {
+            function,
+            json_expr: Box::new(json_expr),
+            json_path,
+            returning,
+            on_empty,
+            on_error,
+        })
+    }
+
+    /// Parse an optional `<behavior> ON <event>` clause of a SQL/JSON query
+    /// function, where `event` is either `EMPTY` or `ERROR`
+    fn parse_json_behavior_clause(
+        &mut self,
+        event: &'static str,
+    ) -> Result<Option<SQLJsonBehavior<I>>, ParserError> {
+        let index = self.index;
+        let behavior = if self.parse_keyword("ERROR") {
+            SQLJsonBehavior::Error
+        } else if self.parse_keyword("NULL") {
+            SQLJsonBehavior::Null
+        } else if self.parse_keyword("TRUE") {
+            SQLJsonBehavior::True
+        } else if self.parse_keyword("FALSE") {
+            SQLJsonBehavior::False
+        } else if self.parse_keyword("UNKNOWN") {
+            SQLJsonBehavior::Unknown
+        } else if self.parse_keywords(vec!["EMPTY", "ARRAY"]) {
+            SQLJsonBehavior::EmptyArray
+        } else if self.parse_keywords(vec!["EMPTY", "OBJECT"]) {
+            SQLJsonBehavior::EmptyObject
+        } else if self.parse_keyword("DEFAULT") {
+            SQLJsonBehavior::Default(Box::new(self.parse_expr()?))
+        } else {
+            return Ok(None);
+        };
+        self.expect_keyword("ON")?;
+        if self.parse_keyword(event) {
+            Ok(Some(behavior))
+        } else {
+            // this is the clause for the other event, so leave it to be
+            // parsed by the next call
+            self.index = index;
+            Ok(None)
+        }
+    }
+
     /// Parse an operator following an expression
-    pub fn parse_infix(&mut self, expr: ASTNode, precedence: u8) -> Result<ASTNode, ParserError> {
+    pub fn parse_infix(
+        &mut self,
+        expr: ASTNode<I>,
+        precedence: u8,
+    ) -> Result<ASTNode<I>, ParserError> {
         debug!("parsing infix");
         let tok = self.next_token().unwrap(); // safe as EOF's precedence is the lowest
 
@@ -429,14 +751,35 @@ impl Parser {
             Token::Minus => Some(SQLOperator::Minus),
             Token::Mult => Some(SQLOperator::Multiply),
             Token::Mod => Some(SQLOperator::Modulus),
+            Token::Caret => Some(SQLOperator::Exponent),
             Token::Div => Some(SQLOperator::Divide),
-            Token::SQLWord(ref k) => match k.keyword.as_ref() {
+            Token::Tilde => Some(SQLOperator::RegexMatch),
+            Token::TildeAsterisk => Some(SQLOperator::RegexIMatch),
+            Token::ExclamationMarkTilde => Some(SQLOperator::RegexNotMatch),
+            Token::ExclamationMarkTildeAsterisk => Some(SQLOperator::RegexNotIMatch),
+            Token::Ampersand => Some(SQLOperator::BitwiseAnd),
+            Token::Pipe => Some(SQLOperator::BitwiseOr),
+            Token::Sharp => Some(SQLOperator::BitwiseXor),
+            Token::ShiftLeft => Some(SQLOperator::ShiftLeft),
+            Token::ShiftRight => Some(SQLOperator::ShiftRight),
+            Token::SQLWord(ref k) => match k.keyword {
                 "AND" => Some(SQLOperator::And),
                 "OR" => Some(SQLOperator::Or),
                 "LIKE" => Some(SQLOperator::Like),
+                "ILIKE" => Some(SQLOperator::ILike),
+                "SIMILAR" => {
+                    self.expect_keyword("TO")?;
+                    Some(SQLOperator::SimilarTo)
+                }
                 "NOT" => {
                     if self.parse_keyword("LIKE") {
                         Some(SQLOperator::NotLike)
+                    } else if self.dialect.supports_ilike() && self.parse_keyword("ILIKE") {
+                        Some(SQLOperator::NotILike)
+                    } else if self.dialect.supports_regex_match()
+                        && self.parse_keywords(vec!["SIMILAR", "TO"])
+                    {
+                        Some(SQLOperator::NotSimilarTo)
                     } else {
                         None
                     }
@@ -447,20 +790,37 @@ impl Parser {
         };
 
         if let Some(op) = regular_binary_operator {
+            let right = if op == SQLOperator::Exponent {
+                // right-associative, so `2 ^ 3 ^ 2` is `2 ^ (3 ^ 2)`
+                self.parse_subexpr(precedence - 1)?
+            } else {
+                self.parse_subexpr(precedence)?
+            };
+            if op.is_pattern_match() {
+                return self.parse_pattern_match(expr, op, right);
+            }
             Ok(ASTNode::SQLBinaryExpr {
                 left: Box::new(expr),
                 op,
-                right: Box::new(self.parse_subexpr(precedence)?),
+                right: Box::new(right),
             })
         } else if let Token::SQLWord(ref k) = tok {
-            match k.keyword.as_ref() {
+            match k.keyword {
                 "IS" => {
                     if self.parse_keyword("NULL") {
                         Ok(ASTNode::SQLIsNull(Box::new(expr)))
                     } else if self.parse_keywords(vec!["NOT", "NULL"]) {
                         Ok(ASTNode::SQLIsNotNull(Box::new(expr)))
+                    } else if self.parse_keywords(vec!["DISTINCT", "FROM"]) {
+                        self.parse_is_distinct_from(expr, SQLOperator::IsDistinctFrom, precedence)
+                    } else if self.parse_keywords(vec!["NOT", "DISTINCT", "FROM"]) {
+                        self.parse_is_distinct_from(
+                            expr,
+                            SQLOperator::IsNotDistinctFrom,
+                            precedence,
+                        )
                     } else {
-                        self.expected("NULL or NOT NULL after IS", self.peek_token())
+                        self.parse_is_normalized(expr)
                     }
                 }
                 "NOT" | "IN" | "BETWEEN" => {
@@ -471,7 +831,7 @@ impl Parser {
                     } else if self.parse_keyword("BETWEEN") {
                         self.parse_between(expr, negated)
                     } else {
-                        self.expected("IN or BETWEEN after NOT", self.peek_token())
+                        self.expected("IN or BETWEEN after NOT", self.til_non_whitespace())
                     }
                 }
                 // Can only happen if `get_precedence` got out of sync with this function
@@ -486,7 +846,7 @@ impl Parser {
     }
 
     /// Parses the parens following the `[ NOT ] IN` operator
-    pub fn parse_in(&mut self, expr: ASTNode, negated: bool) -> Result<ASTNode, ParserError> {
+    pub fn parse_in(&mut self, expr: ASTNode<I>, negated: bool) -> Result<ASTNode<I>, ParserError> {
         self.expect_token(&Token::LParen)?;
         let in_op = if self.parse_keyword("SELECT") || self.parse_keyword("WITH") {
             self.prev_token();
@@ -507,7 +867,11 @@ impl Parser {
     }
 
     /// Parses `BETWEEN <low> AND <high>`, assuming the `BETWEEN` keyword was already consumed
-    pub fn parse_between(&mut self, expr: ASTNode, negated: bool) -> Result<ASTNode, ParserError> {
+    pub fn parse_between(
+        &mut self,
+        expr: ASTNode<I>,
+        negated: bool,
+    ) -> Result<ASTNode<I>, ParserError> {
         // Stop parsing subexpressions for <low> and <high> on tokens with
         // precedence lower than that of `BETWEEN`, such as `AND`, `IS`, etc.
         let prec = self.get_precedence(&Token::make_keyword("BETWEEN"))?;
@@ -522,8 +886,87 @@ impl Parser {
         })
     }
 
+    /// Parse the single-character string following `LIKE <pattern> ESCAPE`
+    /// Parse the right-hand side of `<expr> IS [ NOT ] DISTINCT FROM <expr>`
+    fn parse_is_distinct_from(
+        &mut self,
+        expr: ASTNode<I>,
+        op: SQLOperator,
+        precedence: u8,
+    ) -> Result<ASTNode<I>, ParserError> {
+        Ok(ASTNode::SQLBinaryExpr {
+            left: Box::new(expr),
+            op,
+            right: Box::new(self.parse_subexpr(precedence)?),
+        })
+    }
+
+    /// Parse the rest of `<expr> IS [ NOT ] [ <form> ] NORMALIZED`, assuming
+    /// the `IS` keyword has already been consumed
+    fn parse_is_normalized(&mut self, expr: ASTNode<I>) -> Result<ASTNode<I>, ParserError> {
+        let negated = self.parse_keyword("NOT");
+        let form = match self.parse_one_of_keywords(&["NFC", "NFD", "NFKC", "NFKD"]) {
+            Some("NFC") => Some(SQLNormalForm::NFC),
+            Some("NFD") => Some(SQLNormalForm::NFD),
+            Some("NFKC") => Some(SQLNormalForm::NFKC),
+            Some("NFKD") => Some(SQLNormalForm::NFKD),
+            Some(_) => unreachable!(),
+            None => None,
+        };
+        if !self.parse_keyword("NORMALIZED") {
+            let expected = if negated || form.is_some() {
+                "NORMALIZED"
+            } else {
+                "NULL, NOT NULL, DISTINCT FROM or NORMALIZED after IS"
+            };
+            return self.expected(expected, self.til_non_whitespace());
+        }
+        Ok(ASTNode::SQLIsNormalized {
+            expr: Box::new(expr),
+            form,
+            negated,
+        })
+    }
+
+    /// Finish parsing a `LIKE`, `ILIKE` or `SIMILAR TO` expression (or their
+    /// negations), whose pattern has already been parsed, by checking for a
+    /// trailing `ESCAPE` clause
+    fn parse_pattern_match(
+        &mut self,
+        expr: ASTNode<I>,
+        op: SQLOperator,
+        pattern: ASTNode<I>,
+    ) -> Result<ASTNode<I>, ParserError> {
+        if self.parse_keyword("ESCAPE") {
+            Ok(ASTNode::SQLLike {
+                expr: Box::new(expr),
+                op,
+                pattern: Box::new(pattern),
+                escape_char: self.parse_escape_char()?,
+            })
+        } else {
+            Ok(ASTNode::SQLBinaryExpr {
+                left: Box::new(expr),
+                op,
+                right: Box::new(pattern),
+            })
+        }
+    }
+
+    fn parse_escape_char(&mut self) -> Result<char, ParserError> {
+        match self.next_token() {
+            Some(Token::SingleQuotedString(ref s)) if s.chars().count() == 1 => {
+                Ok(s.chars().next().unwrap())
+            }
+            unexpected => self.expected(
+                "a single character string after ESCAPE",
+                unexpected.and(self.prev_non_whitespace()),
+            ),
+        }
+    }
+
     /// Parse a postgresql casting style which is in the form of `expr::datatype`
-    pub fn parse_pg_cast(&mut self, expr: ASTNode) -> Result<ASTNode, ParserError> {
+    pub fn parse_pg_cast(&mut self, expr: ASTNode<I>) -> Result<ASTNode<I>, ParserError> {
         Ok(ASTNode::SQLCast {
             expr: Box::new(expr),
             data_type: self.parse_data_type()?,
@@ -532,8 +975,8 @@ impl Parser {
 
     /// Get the precedence of the next token
     pub fn get_next_precedence(&self) -> Result<u8, ParserError> {
-        if let Some(token) = self.peek_token() {
-            self.get_precedence(&token)
+        if let Some(token) = self.peek_token_ref() {
+            self.get_precedence(token)
         } else {
             Ok(0)
         }
@@ -551,33 +994,54 @@ impl Parser {
             Token::SQLWord(k) if k.keyword == "IN" => Ok(20),
             Token::SQLWord(k) if k.keyword == "BETWEEN" => Ok(20),
             Token::SQLWord(k) if k.keyword == "LIKE" => Ok(20),
+            Token::SQLWord(k) if k.keyword == "ILIKE" && self.dialect.supports_ilike() => Ok(20),
+            Token::SQLWord(k) if k.keyword == "SIMILAR" && self.dialect.supports_regex_match() => {
+                Ok(20)
+            }
+            Token::Tilde
+            | Token::TildeAsterisk
+            | Token::ExclamationMarkTilde
+            | Token::ExclamationMarkTildeAsterisk
+                if self.dialect.supports_regex_match() =>
+            {
+                Ok(20)
+            }
             Token::Eq | Token::Lt | Token::LtEq | Token::Neq | Token::Gt | Token::GtEq => Ok(20),
+            Token::Pipe if self.dialect.supports_bitwise_operators() => Ok(21),
+            Token::Sharp if self.dialect.supports_sharp_xor() => Ok(22),
+            Token::Ampersand if self.dialect.supports_bitwise_operators() => Ok(23),
+            Token::ShiftLeft | Token::ShiftRight if self.dialect.supports_bitwise_operators() => {
+                Ok(24)
+            }
             Token::Plus | Token::Minus => Ok(30),
             Token::Mult | Token::Div | Token::Mod => Ok(40),
+            Token::Caret if self.dialect.supports_caret_exponent() => Ok(45),
             Token::DoubleColon => Ok(50),
             _ => Ok(0),
         }
     }
 
     /// Return first non-whitespace token that has not yet been processed
-    pub fn peek_token(&self) -> Option<Token> {
-        if let Some(n) = self.til_non_whitespace() {
-            self.token_at(n)
-        } else {
-            None
-        }
+    pub fn peek_token(&self) -> Option<Token<'a>> {
+        self.peek_token_ref().cloned()
+    }
+
+    /// Like `peek_token`, but borrow the token instead of cloning it, for
+    /// callers that only need to inspect it
+    pub fn peek_token_ref(&self) -> Option<&Token<'a>> {
+        self.til_non_whitespace().map(|n| &self.tokens[n].token)
     }
 
     /// Get the next token skipping whitespace and increment the token index
-    pub fn next_token(&mut self) -> Option<Token> {
-        loop {
-            match self.next_token_no_skip() {
-                Some(Token::Whitespace(_)) => {
-                    continue;
-                }
-                token => {
-                    return token;
-                }
+    pub fn next_token(&mut self) -> Option<Token<'a>> {
+        match self.til_non_whitespace() {
+            Some(n) => {
+                self.index = n + 1;
+                Some(self.tokens[n].token.clone())
+            }
+            None => {
+                self.index = self.tokens.len();
+                None
             }
         }
     }
@@ -586,7 +1050,7 @@ impl Parser {
     fn til_non_whitespace(&self) -> Option<usize> {
         let mut index = self.index;
         loop {
-            match self.token_at(index) {
+            match self.tokens.get(index).map(|t| &t.token) {
                 Some(Token::Whitespace(_)) => {
                     index += 1;
                 }
@@ -600,26 +1064,24 @@ impl Parser {
         }
     }
 
-    /// see the token at this index
-    fn token_at(&self, n: usize) -> Option<Token> {
-        if let Some(token) = self.tokens.get(n) {
-            Some(token.clone())
-        } else {
-            None
-        }
+    /// Get the index of the last non-whitespace token that has been processed
+    fn prev_non_whitespace(&self) -> Option<usize> {
+        self.tokens[..self.index]
+            .iter()
+            .rposition(|t| !matches!(t.token, Token::Whitespace(_)))
     }
 
-    pub fn next_token_no_skip(&mut self) -> Option<Token> {
+    pub fn next_token_no_skip(&mut self) -> Option<Token<'a>> {
         if self.index < self.tokens.len() {
             self.index += 1;
-            Some(self.tokens[self.index - 1].clone())
+            Some(self.tokens[self.index - 1].token.clone())
         } else {
             None
         }
     }
 
     /// Push back the last one non-whitespace token
-    pub fn prev_token(&mut self) -> Option<Token> {
+    pub fn prev_token(&mut self) -> Option<Token<'a>> {
         // TODO: returned value is unused (available via peek_token)
         loop {
             match self.prev_token_no_skip() {
@@ -634,22 +1096,41 @@ impl Parser {
     }
 
     /// Get the previous token and decrement the token index
-    fn prev_token_no_skip(&mut self) -> Option<Token> {
+    fn prev_token_no_skip(&mut self) -> Option<Token<'a>> {
         if self.index > 0 {
             self.index -= 1;
-            Some(self.tokens[self.index].clone())
+            Some(self.tokens[self.index].token.clone())
         } else {
             None
         }
     }
 
-    /// Report unexpected token
-    fn expected<T>(&self, expected: &str, found: Option<Token>) -> Result<T, ParserError> {
-        parser_err!(format!(
-            "Expected {}, found: {}",
-            expected,
-            found.map_or("EOF".to_string(), |t| t.to_string())
-        ))
+    /// Report an error caused by the token at index `at` in `self.tokens`
+    /// (`None` meaning EOF), appending the token's location, if known
+    fn error_at<T>(&self, message: &str, at: Option<usize>) -> Result<T, ParserError> {
+        let location = at
+            .map(|n| self.tokens[n].location)
+            .filter(|location| *location != Location::UNKNOWN);
+        match location {
+            Some(location) => parser_err!(format!("{} at {}", message, location)),
+            None => parser_err!(message),
+        }
+    }
+
+    /// Report unexpected token, `found` being its index in `self.tokens`
+    /// (`None` meaning EOF)
+    fn expected<T>(&self, expected: &str, found: Option<usize>) -> Result<T, ParserError> {
+        match found {
+            Some(n) => self.error_at(
+                &format!(
+                    "Expected {}, found: {}",
+                    expected,
+                    self.tokens[n].token.to_string()
+                ),
+                found,
+            ),
+            None => parser_err!(format!("Expected {}, found: EOF", expected)),
+        }
     }
 
     /// Look for an expected keyword and consume it if it exists
@@ -659,9 +1140,9 @@ impl Parser {
         // it's not trivial to maintain the enum without duplicating all
         // the keywords three times, we'll settle for a run-time check that
         // the string actually represents a known keyword...
-        assert!(keywords::ALL_KEYWORDS.contains(&expected));
-        match self.peek_token() {
-            Some(Token::SQLWord(ref k)) if expected.eq_ignore_ascii_case(&k.keyword) => {
+        assert!(keywords::is_keyword(expected));
+        match self.peek_token_ref() {
+            Some(Token::SQLWord(k)) if expected.eq_ignore_ascii_case(k.keyword) => {
                 self.next_token();
                 true
             }
@@ -684,28 +1165,47 @@ impl Parser {
         true
     }
 
+    /// Look for one of the given keywords and return the one that matches.
+    #[must_use]
+    pub fn parse_one_of_keywords(&mut self, keywords: &[&'static str]) -> Option<&'static str> {
+        keywords
+            .iter()
+            .cloned()
+            .find(|keyword| self.parse_keyword(keyword))
+    }
+
+    /// Bail out if the current token is not one of the expected keywords, or consume it if it is
+    pub fn expect_one_of_keywords(
+        &mut self,
+        keywords: &[&'static str],
+    ) -> Result<&'static str, ParserError> {
+        if let Some(keyword) = self.parse_one_of_keywords(keywords) {
+            Ok(keyword)
+        } else {
+            self.expected(
+                &format!("one of {}", keywords.join(" or ")),
+                self.til_non_whitespace(),
+            )
+        }
+    }
+
     /// Bail out if the current token is not an expected keyword, or consume it if it is
     pub fn expect_keyword(&mut self, expected: &'static str) -> Result<(), ParserError> {
         if self.parse_keyword(expected) {
             Ok(())
         } else {
-            self.expected(expected, self.peek_token())
+            self.expected(expected, self.til_non_whitespace())
         }
     }
 
     /// Consume the next token if it matches the expected token, otherwise return false
     #[must_use]
     pub fn consume_token(&mut self, expected: &Token) -> bool {
-        match self.peek_token() {
-            Some(ref t) => {
-                if *t == *expected {
-                    self.next_token();
-                    true
-                } else {
-                    false
-                }
-            }
-            _ => false,
+        if self.peek_token_ref() == Some(expected) {
+            self.next_token();
+            true
+        } else {
+            false
         }
     }
 
@@ -714,12 +1214,12 @@ impl Parser {
         if self.consume_token(expected) {
             Ok(())
         } else {
-            self.expected(&expected.to_string(), self.peek_token())
+            self.expected(&expected.to_string(), self.til_non_whitespace())
         }
     }
 
     /// Parse a SQL CREATE statement
-    pub fn parse_create(&mut self) -> Result<SQLStatement, ParserError> {
+    pub fn parse_create(&mut self) -> Result<SQLStatement<I>, ParserError> {
         if self.parse_keyword("TABLE") {
             self.parse_create_table()
         } else if self.parse_keyword("MATERIALIZED") || self.parse_keyword("VIEW") {
@@ -727,18 +1227,218 @@ impl Parser {
             self.parse_create_view()
         } else if self.parse_keyword("EXTERNAL") {
             self.parse_create_external_table()
+        } else if self.parse_keyword("SCHEMA") {
+            self.parse_create_schema()
+        } else if self.parse_keyword("FUNCTION") {
+            self.parse_create_function(false)
+        } else if self.parse_keywords(vec!["OR", "REPLACE", "FUNCTION"]) {
+            self.parse_create_function(true)
+        } else if self.parse_keyword("SEQUENCE") {
+            self.parse_create_sequence(false)
+        } else if self.parse_keywords(vec!["TEMPORARY", "SEQUENCE"]) {
+            self.parse_create_sequence(true)
+        } else if self.parse_keyword("INDEX") {
+            self.parse_create_index(false)
+        } else if self.parse_keywords(vec!["UNIQUE", "INDEX"]) {
+            self.parse_create_index(true)
+        } else {
+            self.expected(
+                "TABLE, VIEW, SCHEMA, FUNCTION, SEQUENCE or INDEX after CREATE",
+                self.til_non_whitespace(),
+            )
+        }
+    }
+
+    /// Parse the rest of a `CREATE [ UNIQUE ] INDEX` statement:
+    /// `[ IF NOT EXISTS ] name ON table [ USING method ] (column, ...)`
+    pub fn parse_create_index(&mut self, unique: bool) -> Result<SQLStatement<I>, ParserError> {
+        let if_not_exists = self.parse_keywords(vec!["IF", "NOT", "EXISTS"]);
+        let name = self.parse_object_name()?;
+        self.expect_keyword("ON")?;
+        let table_name = self.parse_object_name()?;
+        let method = if self.parse_keyword("USING") {
+            Some(self.parse_identifier()?)
         } else {
-            self.expected("TABLE or VIEW after CREATE", self.peek_token())
+            None
+        };
+        let columns = self.parse_parenthesized_column_list(Mandatory)?;
+        Ok(SQLStatement::SQLCreateIndex {
+            unique,
+            if_not_exists,
+            name,
+            table_name,
+            method,
+            columns,
+        })
+    }
+
+    pub fn parse_create_schema(&mut self) -> Result<SQLStatement<I>, ParserError> {
+        let if_not_exists = self.parse_keywords(vec!["IF", "NOT", "EXISTS"]);
+        let schema_name = self.parse_object_name()?;
+        Ok(SQLStatement::SQLCreateSchema {
+            schema_name,
+            if_not_exists,
+        })
+    }
+
+    /// Parse the rest of a `CREATE [ OR REPLACE ] FUNCTION` statement. The
+    /// function body is kept as a string literal and is not parsed.
+    pub fn parse_create_function(
+        &mut self,
+        or_replace: bool,
+    ) -> Result<SQLStatement<I>, ParserError> {
+        let name = self.parse_object_name()?;
+        self.expect_token(&Token::LParen)?;
+        let mut args = vec![];
+        if !self.consume_token(&Token::RParen) {
+            args = self.parse_comma_separated(Parser::parse_function_arg_def)?;
+            self.expect_token(&Token::RParen)?;
+        }
+        self.expect_keyword("RETURNS")?;
+        let return_type = self.parse_data_type()?;
+
+        // The remaining clauses may appear in any order
+        let mut body = None;
+        let mut language = None;
+        let mut behavior = None;
+        loop {
+            if body.is_none() && self.parse_keyword("AS") {
+                body = match self.parse_value()? {
+                    v @ Value::SingleQuotedString(_) | v @ Value::DollarQuotedString { .. } => {
+                        Some(v)
+                    }
+                    other => {
+                        return self.error_at(
+                            &format!(
+                                "Expected a string literal as the function body, found: {}",
+                                other
+                            ),
+                            self.prev_non_whitespace(),
+                        );
+                    }
+                };
+            } else if language.is_none() && self.parse_keyword("LANGUAGE") {
+                language = Some(self.parse_identifier()?);
+            } else if behavior.is_none() && self.parse_keyword("IMMUTABLE") {
+                behavior = Some(SQLFunctionBehavior::Immutable);
+            } else if behavior.is_none() && self.parse_keyword("STABLE") {
+                behavior = Some(SQLFunctionBehavior::Stable);
+            } else if behavior.is_none() && self.parse_keyword("VOLATILE") {
+                behavior = Some(SQLFunctionBehavior::Volatile);
+            } else {
+                break;
+            }
+        }
+        let body = match body {
+            Some(body) => body,
+            None => return self.expected("AS <function body>", self.til_non_whitespace()),
+        };
+
+        Ok(SQLStatement::SQLCreateFunction {
+            or_replace,
+            name,
+            args,
+            return_type,
+            body,
+            language,
+            behavior,
+        })
+    }
+
+    /// Parse a single `[ name ] data_type` parameter of `CREATE FUNCTION`
+    fn parse_function_arg_def(&mut self) -> Result<SQLFunctionArgDef<I>, ParserError> {
+        let data_type = self.parse_data_type()?;
+        match self.peek_token() {
+            Some(Token::Comma) | Some(Token::RParen) => Ok(SQLFunctionArgDef {
+                name: None,
+                data_type,
+            }),
+            // What we parsed as a (custom) data type was actually the parameter name
+            _ => match data_type {
+                SQLType::Custom(SQLObjectName(ref idents)) if idents.len() == 1 => {
+                    Ok(SQLFunctionArgDef {
+                        name: Some(I::from(Cow::Owned(idents[0].clone()))),
+                        data_type: self.parse_data_type()?,
+                    })
+                }
+                _ => self.expected("',' or ')' after parameter type", self.til_non_whitespace()),
+            },
+        }
+    }
+
+    /// Parse the rest of a `CREATE [ TEMPORARY ] SEQUENCE` statement
+    pub fn parse_create_sequence(
+        &mut self,
+        temporary: bool,
+    ) -> Result<SQLStatement<I>, ParserError> {
+        let if_not_exists = self.parse_keywords(vec!["IF", "NOT", "EXISTS"]);
+        let name = self.parse_object_name()?;
+        let options = self.parse_sequence_options()?;
+        Ok(SQLStatement::SQLCreateSequence {
+            name,
+            temporary,
+            if_not_exists,
+            options,
+        })
+    }
+
+    /// Parse the options of `CREATE SEQUENCE` or `ALTER SEQUENCE`, which may
+    /// appear in any order, and return them in the canonical order.
+    pub fn parse_sequence_options(&mut self) -> Result<Vec<SQLSequenceOption>, ParserError> {
+        let mut options: Vec<SQLSequenceOption> = vec![];
+        loop {
+            let start = self.til_non_whitespace();
+            let option = if self.parse_keyword("INCREMENT") {
+                let _ = self.parse_keyword("BY");
+                SQLSequenceOption::IncrementBy(self.parse_signed_literal_int()?)
+            } else if self.parse_keyword("MINVALUE") {
+                SQLSequenceOption::MinValue(Some(self.parse_signed_literal_int()?))
+            } else if self.parse_keyword("MAXVALUE") {
+                SQLSequenceOption::MaxValue(Some(self.parse_signed_literal_int()?))
+            } else if self.parse_keyword("START") {
+                let _ = self.parse_keyword("WITH");
+                SQLSequenceOption::StartWith(self.parse_signed_literal_int()?)
+            } else if self.parse_keyword("CACHE") {
+                SQLSequenceOption::Cache(self.parse_literal_int()?)
+            } else if self.parse_keyword("CYCLE") {
+                SQLSequenceOption::Cycle(true)
+            } else if self.parse_keyword("NO") {
+                if self.parse_keyword("MINVALUE") {
+                    SQLSequenceOption::MinValue(None)
+                } else if self.parse_keyword("MAXVALUE") {
+                    SQLSequenceOption::MaxValue(None)
+                } else if self.parse_keyword("CYCLE") {
+                    SQLSequenceOption::Cycle(false)
+                } else {
+                    return self.expected(
+                        "MINVALUE, MAXVALUE or CYCLE after NO",
+                        self.til_non_whitespace(),
+                    );
+                }
+            } else {
+                break;
+            };
+            let position = option.canonical_position();
+            if options.iter().any(|o| o.canonical_position() == position) {
+                return self.error_at(
+                    &format!("Conflicting or redundant sequence option: {}", option),
+                    start,
+                );
+            }
+            options.push(option);
         }
+        options.sort_by_key(SQLSequenceOption::canonical_position);
+        Ok(options)
     }
 
-    pub fn parse_create_external_table(&mut self) -> Result<SQLStatement, ParserError> {
+    pub fn parse_create_external_table(&mut self) -> Result<SQLStatement<I>, ParserError> {
         self.expect_keyword("TABLE")?;
+        let if_not_exists = self.parse_keywords(vec!["IF", "NOT", "EXISTS"]);
         let table_name = self.parse_object_name()?;
-        let columns = self.parse_columns()?;
+        let (columns, constraints) = self.parse_columns()?;
         self.expect_keyword("STORED")?;
         self.expect_keyword("AS")?;
-        let file_format = self.parse_identifier()?.parse::<FileFormat>()?;
+        let file_format = self.parse_identifier()?.as_ref().parse::<FileFormat>()?;
 
         self.expect_keyword("LOCATION")?;
         let location = self.parse_literal_string()?;
@@ -746,59 +1446,170 @@ impl Parser {
         Ok(SQLStatement::SQLCreateTable {
             name: table_name,
             columns,
+            constraints,
             external: true,
             file_format: Some(file_format),
             location: Some(location),
+            if_not_exists,
         })
     }
 
-    pub fn parse_create_view(&mut self) -> Result<SQLStatement, ParserError> {
+    pub fn parse_create_view(&mut self) -> Result<SQLStatement<I>, ParserError> {
         let materialized = self.parse_keyword("MATERIALIZED");
         self.expect_keyword("VIEW")?;
         // Many dialects support `OR REPLACE` | `OR ALTER` right after `CREATE`, but we don't (yet).
         // ANSI SQL and Postgres support RECURSIVE here, but we don't support it either.
         let name = self.parse_object_name()?;
-        // Parenthesized "output" columns list could be handled here.
-        // Some dialects allow WITH here, followed by some keywords (e.g. MS SQL)
-        // or `(k1=v1, k2=v2, ...)` (Postgres)
+        let columns = self.parse_parenthesized_column_list(Optional)?;
+        // Some dialects allow WITH here, followed by some keywords (e.g. MS SQL),
+        // but we only support the `(k1=v1, k2=v2, ...)` form (Postgres)
+        let with_options = if self.parse_keyword("WITH") {
+            self.parse_with_options()?
+        } else {
+            vec![]
+        };
         self.expect_keyword("AS")?;
         let query = Box::new(self.parse_query()?);
         // Optional `WITH [ CASCADED | LOCAL ] CHECK OPTION` is widely supported here.
         Ok(SQLStatement::SQLCreateView {
             name,
+            columns,
             query,
             materialized,
+            with_options,
+        })
+    }
+
+    /// Parse a parenthesized list of `name = value` options, assuming the
+    /// preceding `WITH` was already consumed
+    pub fn parse_with_options(&mut self) -> Result<Vec<SQLOption<I>>, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let mut options = vec![];
+        loop {
+            let name = self.parse_identifier()?;
+            self.expect_token(&Token::Eq)?;
+            let value = self.parse_value()?;
+            options.push(SQLOption { name, value });
+            match self.next_token() {
+                Some(Token::Comma) => {}
+                Some(Token::RParen) => break,
+                unexpected => {
+                    return self.expected(
+                        "',' or ')' after option definition",
+                        unexpected.and(self.prev_non_whitespace()),
+                    )
+                }
+            }
+        }
+        Ok(options)
+    }
+
+    /// Parse a `GRANT` statement, assuming the `GRANT` keyword was consumed
+    pub fn parse_grant(&mut self) -> Result<SQLStatement<I>, ParserError> {
+        // `GRANT <role>, ... TO ...` grants role membership, unless the
+        // first word is a privilege
+        let index = self.index;
+        let is_privilege_grant = self.parse_keyword("ALL") || self.parse_privilege().is_ok();
+        self.index = index;
+        if !is_privilege_grant {
+            let roles = self.parse_comma_separated(Parser::parse_identifier)?;
+            self.expect_keyword("TO")?;
+            let grantees = self.parse_comma_separated(Parser::parse_identifier)?;
+            let with_admin_option = self.parse_keywords(vec!["WITH", "ADMIN", "OPTION"]);
+            return Ok(SQLStatement::SQLGrantRole {
+                roles,
+                grantees,
+                with_admin_option,
+            });
+        }
+        let privileges = if self.parse_keyword("ALL") {
+            let _ = self.parse_keyword("PRIVILEGES");
+            Privileges::All
+        } else {
+            Privileges::Privileges(self.parse_comma_separated(Parser::parse_privilege)?)
+        };
+        self.expect_keyword("ON")?;
+        let objects = if self.parse_keywords(vec!["ALL", "TABLES", "IN", "SCHEMA"]) {
+            GrantObjects::AllTablesInSchema {
+                schemas: self.parse_comma_separated(Parser::parse_object_name)?,
+            }
+        } else {
+            let _ = self.parse_keyword("TABLE");
+            GrantObjects::Tables(self.parse_comma_separated(Parser::parse_object_name)?)
+        };
+        self.expect_keyword("TO")?;
+        let grantees = self.parse_comma_separated(Parser::parse_identifier)?;
+        let with_grant_option = self.parse_keywords(vec!["WITH", "GRANT", "OPTION"]);
+        Ok(SQLStatement::SQLGrant {
+            privileges,
+            objects,
+            grantees,
+            with_grant_option,
         })
     }
 
-    pub fn parse_drop(&mut self) -> Result<SQLStatement, ParserError> {
+    fn parse_privilege(&mut self) -> Result<Privilege<I>, ParserError> {
+        match self.next_token() {
+            Some(Token::SQLWord(ref w)) if w.keyword == "SELECT" => Ok(Privilege::Select {
+                columns: self.parse_privilege_columns()?,
+            }),
+            Some(Token::SQLWord(ref w)) if w.keyword == "INSERT" => Ok(Privilege::Insert {
+                columns: self.parse_privilege_columns()?,
+            }),
+            Some(Token::SQLWord(ref w)) if w.keyword == "UPDATE" => Ok(Privilege::Update {
+                columns: self.parse_privilege_columns()?,
+            }),
+            Some(Token::SQLWord(ref w)) if w.keyword == "DELETE" => Ok(Privilege::Delete),
+            Some(Token::SQLWord(ref w)) if w.keyword == "TRUNCATE" => Ok(Privilege::Truncate),
+            Some(Token::SQLWord(ref w)) if w.keyword == "REFERENCES" => Ok(Privilege::References {
+                columns: self.parse_privilege_columns()?,
+            }),
+            Some(Token::SQLWord(ref w)) if w.keyword == "TRIGGER" => Ok(Privilege::Trigger),
+            Some(Token::SQLWord(ref w)) if w.keyword == "USAGE" => Ok(Privilege::Usage),
+            Some(Token::SQLWord(ref w)) if w.keyword == "EXECUTE" => Ok(Privilege::Execute),
+            Some(Token::SQLWord(ref w)) if w.keyword == "CREATE" => Ok(Privilege::Create),
+            Some(Token::SQLWord(ref w)) if w.keyword == "CONNECT" => Ok(Privilege::Connect),
+            Some(Token::SQLWord(ref w)) if w.keyword == "TEMPORARY" || w.keyword == "TEMP" => {
+                Ok(Privilege::Temporary)
+            }
+            unexpected => self.expected("a privilege", unexpected.and(self.prev_non_whitespace())),
+        }
+    }
+
+    fn parse_privilege_columns(&mut self) -> Result<Option<Vec<I>>, ParserError> {
+        if self.peek_token_ref() == Some(&Token::LParen) {
+            Ok(Some(self.parse_parenthesized_column_list(Mandatory)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub fn parse_drop(&mut self) -> Result<SQLStatement<I>, ParserError> {
         let object_type = if self.parse_keyword("TABLE") {
             SQLObjectType::Table
         } else if self.parse_keyword("VIEW") {
             SQLObjectType::View
+        } else if self.parse_keyword("SEQUENCE") {
+            SQLObjectType::Sequence
+        } else if self.parse_keyword("SCHEMA") {
+            SQLObjectType::Schema
+        } else if self.parse_keyword("INDEX") {
+            SQLObjectType::Index
         } else {
-            return parser_err!(format!(
-                "Unexpected token after DROP: {:?}",
-                self.peek_token()
-            ));
+            return self.error_at(
+                &format!("Unexpected token after DROP: {:?}", self.peek_token()),
+                self.til_non_whitespace(),
+            );
         };
         let if_exists = self.parse_keywords(vec!["IF", "EXISTS"]);
-        let mut names = vec![self.parse_object_name()?];
-        loop {
-            let token = &self.next_token();
-            if let Some(Token::Comma) = token {
-                names.push(self.parse_object_name()?)
-            } else {
-                if token.is_some() {
-                    self.prev_token();
-                }
-                break;
-            }
-        }
+        let names = self.parse_comma_separated(Parser::parse_object_name)?;
         let cascade = self.parse_keyword("CASCADE");
         let restrict = self.parse_keyword("RESTRICT");
         if cascade && restrict {
-            return parser_err!("Cannot specify both CASCADE and RESTRICT in DROP");
+            return self.error_at(
+                "Cannot specify both CASCADE and RESTRICT in DROP",
+                self.prev_non_whitespace(),
+            );
         }
         Ok(SQLStatement::SQLDrop {
             object_type,
@@ -808,53 +1619,62 @@ impl Parser {
         })
     }
 
-    pub fn parse_create_table(&mut self) -> Result<SQLStatement, ParserError> {
+    pub fn parse_create_table(&mut self) -> Result<SQLStatement<I>, ParserError> {
+        let if_not_exists = self.parse_keywords(vec!["IF", "NOT", "EXISTS"]);
         let table_name = self.parse_object_name()?;
-        // parse optional column list (schema)
-        let columns = self.parse_columns()?;
+        // parse optional column list (schema) and constraints
+        let (columns, constraints) = self.parse_columns()?;
 
         Ok(SQLStatement::SQLCreateTable {
             name: table_name,
             columns,
+            constraints,
             external: false,
             file_format: None,
             location: None,
+            if_not_exists,
         })
     }
 
-    fn parse_columns(&mut self) -> Result<Vec<SQLColumnDef>, ParserError> {
+    fn parse_columns(&mut self) -> Result<Columns<I>, ParserError> {
         let mut columns = vec![];
+        let mut constraints = vec![];
         if !self.consume_token(&Token::LParen) {
-            return Ok(columns);
+            return Ok((columns, constraints));
         }
 
         loop {
+            if let Some(constraint) = self.parse_optional_table_constraint()? {
+                constraints.push(constraint);
+                match self.next_token() {
+                    Some(Token::Comma) => continue,
+                    Some(Token::RParen) => break,
+                    other => {
+                        return self.error_at(
+                            &format!(
+                                "Expected ',' or ')' after table constraint but found {:?}",
+                                other
+                            ),
+                            other.and(self.prev_non_whitespace()),
+                        );
+                    }
+                }
+            }
             match self.next_token() {
                 Some(Token::SQLWord(column_name)) => {
                     let data_type = self.parse_data_type()?;
-                    let is_primary = self.parse_keywords(vec!["PRIMARY", "KEY"]);
-                    let is_unique = self.parse_keyword("UNIQUE");
-                    let default = if self.parse_keyword("DEFAULT") {
-                        let expr = self.parse_default_expr(0)?;
-                        Some(expr)
-                    } else {
-                        None
-                    };
-                    let allow_null = if self.parse_keywords(vec!["NOT", "NULL"]) {
-                        false
-                    } else {
-                        let _ = self.parse_keyword("NULL");
-                        true
-                    };
-                    debug!("default: {:?}", default);
+                    let mut options = vec![];
+                    loop {
+                        match self.peek_token() {
+                            None | Some(Token::Comma) | Some(Token::RParen) => break,
+                            _ => options.push(self.parse_column_option_def()?),
+                        }
+                    }
 
                     columns.push(SQLColumnDef {
                         name: column_name.as_sql_ident(),
                         data_type,
-                        allow_null,
-                        is_primary,
-                        is_unique,
-                        default,
+                        options,
                     });
                     match self.next_token() {
                         Some(Token::Comma) => {}
@@ -862,66 +1682,150 @@ impl Parser {
                             break;
                         }
                         other => {
-                            return parser_err!(format!(
-                                "Expected ',' or ')' after column definition but found {:?}",
-                                other
-                            ));
+                            return self.error_at(
+                                &format!(
+                                    "Expected ',' or ')' after column definition but found {:?}",
+                                    other
+                                ),
+                                other.and(self.prev_non_whitespace()),
+                            );
                         }
                     }
                 }
                 unexpected => {
-                    return parser_err!(format!("Expected column name, got {:?}", unexpected));
+                    return self.error_at(
+                        &format!("Expected column name, got {:?}", unexpected),
+                        unexpected.and(self.prev_non_whitespace()),
+                    );
                 }
             }
         }
 
-        Ok(columns)
+        Ok((columns, constraints))
     }
 
-    pub fn parse_table_key(&mut self, constraint_name: SQLIdent) -> Result<TableKey, ParserError> {
-        let is_primary_key = self.parse_keywords(vec!["PRIMARY", "KEY"]);
-        let is_unique_key = self.parse_keywords(vec!["UNIQUE", "KEY"]);
-        let is_foreign_key = self.parse_keywords(vec!["FOREIGN", "KEY"]);
-        let column_names = self.parse_parenthesized_column_list(Mandatory)?;
-        let key = Key {
-            name: constraint_name,
-            columns: column_names,
+    /// Parse a table-level constraint, as used in `CREATE TABLE` and
+    /// `ALTER TABLE ... ADD`, if the next tokens start one
+    pub fn parse_optional_table_constraint(
+        &mut self,
+    ) -> Result<Option<TableConstraint<I>>, ParserError> {
+        let name = if self.parse_keyword("CONSTRAINT") {
+            Some(self.parse_identifier()?)
+        } else {
+            None
         };
-        if is_primary_key {
-            Ok(TableKey::PrimaryKey(key))
-        } else if is_unique_key {
-            Ok(TableKey::UniqueKey(key))
-        } else if is_foreign_key {
+        let is_primary = self.parse_keywords(vec!["PRIMARY", "KEY"]);
+        if is_primary || self.parse_keyword("UNIQUE") {
+            let columns = self.parse_parenthesized_column_list(Mandatory)?;
+            Ok(Some(TableConstraint::Unique {
+                name,
+                columns,
+                is_primary,
+            }))
+        } else if self.parse_keywords(vec!["FOREIGN", "KEY"]) {
+            let columns = self.parse_parenthesized_column_list(Mandatory)?;
             self.expect_keyword("REFERENCES")?;
             let foreign_table = self.parse_object_name()?;
             let referred_columns = self.parse_parenthesized_column_list(Mandatory)?;
-            Ok(TableKey::ForeignKey {
-                key,
+            Ok(Some(TableConstraint::ForeignKey {
+                name,
+                columns,
                 foreign_table,
                 referred_columns,
-            })
+            }))
+        } else if self.parse_keyword("CHECK") {
+            self.expect_token(&Token::LParen)?;
+            let expr = Box::new(self.parse_expr()?);
+            self.expect_token(&Token::RParen)?;
+            let no_inherit = self.parse_keywords(vec!["NO", "INHERIT"]);
+            Ok(Some(TableConstraint::Check {
+                name,
+                expr,
+                no_inherit,
+            }))
+        } else if name.is_some() {
+            self.expected(
+                "PRIMARY, UNIQUE, FOREIGN, or CHECK",
+                self.til_non_whitespace(),
+            )
         } else {
-            parser_err!(format!(
-                "Expecting primary key, unique key, or foreign key, found: {:?}",
-                self.peek_token()
-            ))
+            Ok(None)
         }
     }
 
-    pub fn parse_alter(&mut self) -> Result<SQLStatement, ParserError> {
+    /// Parse a column-level constraint or option, optionally preceded by
+    /// `CONSTRAINT <name>`
+    pub fn parse_column_option_def(&mut self) -> Result<ColumnOptionDef<I>, ParserError> {
+        let name = if self.parse_keyword("CONSTRAINT") {
+            Some(self.parse_identifier()?)
+        } else {
+            None
+        };
+
+        let option = if self.parse_keywords(vec!["NOT", "NULL"]) {
+            ColumnOption::NotNull
+        } else if self.parse_keyword("NULL") {
+            ColumnOption::Null
+        } else if self.parse_keyword("DEFAULT") {
+            ColumnOption::Default(self.parse_default_expr(0)?)
+        } else if self.parse_keywords(vec!["PRIMARY", "KEY"]) {
+            ColumnOption::Unique { is_primary: true }
+        } else if self.parse_keyword("UNIQUE") {
+            ColumnOption::Unique { is_primary: false }
+        } else if self.parse_keyword("REFERENCES") {
+            let foreign_table = self.parse_object_name()?;
+            let referred_columns = self.parse_parenthesized_column_list(Optional)?;
+            ColumnOption::ForeignKey {
+                foreign_table,
+                referred_columns,
+            }
+        } else if self.parse_keyword("CHECK") {
+            self.expect_token(&Token::LParen)?;
+            let expr = self.parse_expr()?;
+            self.expect_token(&Token::RParen)?;
+            ColumnOption::Check(expr)
+        } else {
+            return self.expected("column option", self.til_non_whitespace());
+        };
+
+        Ok(ColumnOptionDef { name, option })
+    }
+
+    pub fn parse_alter(&mut self) -> Result<SQLStatement<I>, ParserError> {
+        if self.parse_keyword("SEQUENCE") {
+            let name = self.parse_object_name()?;
+            let options = self.parse_sequence_options()?;
+            return Ok(SQLStatement::SQLAlterSequence { name, options });
+        }
         self.expect_keyword("TABLE")?;
         let _ = self.parse_keyword("ONLY");
         let table_name = self.parse_object_name()?;
         let operation = if self.parse_keyword("ADD") {
-            if self.parse_keyword("CONSTRAINT") {
-                let constraint_name = self.parse_identifier()?;
-                let table_key = self.parse_table_key(constraint_name)?;
-                AlterOperation::AddConstraint(table_key)
+            match self.parse_optional_table_constraint()? {
+                Some(constraint) => AlterOperation::AddConstraint(constraint),
+                None => return self.expected("a constraint after ADD", self.til_non_whitespace()),
+            }
+        } else if self.parse_keyword("RENAME") {
+            if self.parse_keyword("TO") {
+                let table_name = self.parse_object_name()?;
+                AlterOperation::RenameTable { table_name }
+            } else if self.parse_keyword("CONSTRAINT") {
+                let old_name = self.parse_identifier()?;
+                self.expect_keyword("TO")?;
+                let new_name = self.parse_identifier()?;
+                AlterOperation::RenameConstraint { old_name, new_name }
             } else {
-                return self.expected("CONSTRAINT after ADD", self.peek_token());
+                let _ = self.parse_keyword("COLUMN");
+                let old_column_name = self.parse_identifier()?;
+                self.expect_keyword("TO")?;
+                let new_column_name = self.parse_identifier()?;
+                AlterOperation::RenameColumn {
+                    old_column_name,
+                    new_column_name,
+                }
             }
         } else {
-            return self.expected("ADD after ALTER TABLE", self.peek_token());
+            return self.expected("ADD or RENAME after ALTER TABLE", self.til_non_whitespace());
         };
         Ok(SQLStatement::SQLAlterTable {
             name: table_name,
@@ -930,7 +1834,7 @@ impl Parser {
     }
 
     /// Parse a copy statement
-    pub fn parse_copy(&mut self) -> Result<SQLStatement, ParserError> {
+    pub fn parse_copy(&mut self) -> Result<SQLStatement<I>, ParserError> {
         let table_name = self.parse_object_name()?;
         let columns = self.parse_parenthesized_column_list(Optional)?;
         self.expect_keyword("FROM")?;
@@ -944,6 +1848,56 @@ impl Parser {
         })
     }
 
+    /// Parse a SHOW statement, assuming the initial `SHOW` was already consumed
+    pub fn parse_show(&mut self) -> Result<SQLStatement<I>, ParserError> {
+        let mysql_forms = self.dialect.supports_show_tables_columns_variables();
+        if mysql_forms && self.parse_keyword("TABLES") {
+            let db_name = if self.parse_keyword("FROM") || self.parse_keyword("IN") {
+                Some(self.parse_object_name()?)
+            } else {
+                None
+            };
+            let filter = self.parse_show_statement_filter()?;
+            Ok(SQLStatement::SQLShowTables { db_name, filter })
+        } else if mysql_forms && self.parse_keyword("COLUMNS") {
+            if !self.parse_keyword("FROM") && !self.parse_keyword("IN") {
+                return self.expected("FROM or IN after SHOW COLUMNS", self.til_non_whitespace());
+            }
+            let mut table_name = self.parse_object_name()?;
+            // MySQL also accepts `SHOW COLUMNS FROM tbl FROM db`, which is
+            // equivalent to `SHOW COLUMNS FROM db.tbl`
+            if self.parse_keyword("FROM") || self.parse_keyword("IN") {
+                let SQLObjectName(mut db_name) = self.parse_object_name()?;
+                db_name.append(&mut table_name.0);
+                table_name = SQLObjectName(db_name);
+            }
+            let filter = self.parse_show_statement_filter()?;
+            Ok(SQLStatement::SQLShowColumns { table_name, filter })
+        } else if mysql_forms && self.parse_keyword("VARIABLES") {
+            let filter = self.parse_show_statement_filter()?;
+            Ok(SQLStatement::SQLShowVariables { filter })
+        } else {
+            Ok(SQLStatement::SQLShowVariable {
+                variable: self.parse_identifier()?,
+            })
+        }
+    }
+
+    /// Parse the optional `LIKE 'pattern'` or `WHERE expr` at the end of a SHOW statement
+    fn parse_show_statement_filter(
+        &mut self,
+    ) -> Result<Option<SQLShowStatementFilter<I>>, ParserError> {
+        if self.parse_keyword("LIKE") {
+            Ok(Some(SQLShowStatementFilter::Like(
+                self.parse_literal_string()?,
+            )))
+        } else if self.parse_keyword("WHERE") {
+            Ok(Some(SQLShowStatementFilter::Where(self.parse_expr()?)))
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Parse a tab separated values in
     /// COPY payload
     fn parse_tsv(&mut self) -> Result<Vec<Option<String>>, ParserError> {
@@ -951,7 +1905,7 @@ impl Parser {
         Ok(values)
     }
 
-    fn parse_sql_value(&mut self) -> Result<ASTNode, ParserError> {
+    fn parse_sql_value(&mut self) -> Result<ASTNode<I>, ParserError> {
         Ok(ASTNode::SQLValue(self.parse_value()?))
     }
 
@@ -994,27 +1948,44 @@ impl Parser {
     fn parse_value(&mut self) -> Result<Value, ParserError> {
         match self.next_token() {
             Some(t) => match t {
-                Token::SQLWord(k) => match k.keyword.as_ref() {
+                Token::SQLWord(k) => match k.keyword {
                     "TRUE" => Ok(Value::Boolean(true)),
                     "FALSE" => Ok(Value::Boolean(false)),
                     "NULL" => Ok(Value::Null),
                     _ => {
-                        return parser_err!(format!("No value parser for keyword {}", k.keyword));
+                        return self.error_at(
+                            &format!("No value parser for keyword {}", k.keyword),
+                            self.prev_non_whitespace(),
+                        );
                     }
                 },
                 Token::Number(ref n) if n.contains('.') => match n.parse::<f64>() {
                     Ok(n) => Ok(Value::Double(n)),
-                    Err(e) => parser_err!(format!("Could not parse '{}' as f64: {}", n, e)),
+                    Err(e) => self.error_at(
+                        &format!("Could not parse '{}' as f64: {}", n, e),
+                        self.prev_non_whitespace(),
+                    ),
                 },
                 Token::Number(ref n) => match n.parse::<i64>() {
                     Ok(n) => Ok(Value::Long(n)),
-                    Err(e) => parser_err!(format!("Could not parse '{}' as i64: {}", n, e)),
+                    Err(e) => self.error_at(
+                        &format!("Could not parse '{}' as i64: {}", n, e),
+                        self.prev_non_whitespace(),
+                    ),
                 },
                 Token::SingleQuotedString(ref s) => Ok(Value::SingleQuotedString(s.to_string())),
                 Token::NationalStringLiteral(ref s) => {
                     Ok(Value::NationalStringLiteral(s.to_string()))
                 }
-                _ => parser_err!(format!("Unsupported value: {:?}", t)),
+                Token::DollarQuotedString { ref tag, ref value } => Ok(Value::DollarQuotedString {
+                    tag: tag.clone(),
+                    value: value.clone(),
+                }),
+                Token::Placeholder(ref s) => Ok(Value::Placeholder(s.to_string())),
+                _ => self.error_at(
+                    &format!("Unsupported value: {:?}", t),
+                    self.prev_non_whitespace(),
+                ),
             },
             None => parser_err!("Expecting a value, but found EOF"),
         }
@@ -1023,20 +1994,43 @@ impl Parser {
     /// Parse a literal integer/long
     pub fn parse_literal_int(&mut self) -> Result<i64, ParserError> {
         match self.next_token() {
-            Some(Token::Number(s)) => s.parse::<i64>().map_err(|e| {
-                ParserError::ParserError(format!("Could not parse '{}' as i64: {}", s, e))
-            }),
-            other => parser_err!(format!("Expected literal int, found {:?}", other)),
+            Some(Token::Number(s)) => match s.parse::<i64>() {
+                Ok(n) => Ok(n),
+                Err(e) => self.error_at(
+                    &format!("Could not parse '{}' as i64: {}", s, e),
+                    self.prev_non_whitespace(),
+                ),
+            },
+            other => self.error_at(
+                &format!("Expected literal int, found {:?}", other),
+                other.and(self.prev_non_whitespace()),
+            ),
+        }
+    }
+
+    /// Parse a literal integer/long, optionally preceded by a minus sign
+    pub fn parse_signed_literal_int(&mut self) -> Result<i64, ParserError> {
+        if self.consume_token(&Token::Minus) {
+            Ok(-self.parse_literal_int()?)
+        } else {
+            self.parse_literal_int()
         }
     }
 
     /// Parse a literal double
     pub fn parse_literal_double(&mut self) -> Result<f64, ParserError> {
         match self.next_token() {
-            Some(Token::Number(s)) => s.parse::<f64>().map_err(|e| {
-                ParserError::ParserError(format!("Could not parse '{}' as f64: {}", s, e))
-            }),
-            other => parser_err!(format!("Expected literal number, found {:?}", other)),
+            Some(Token::Number(s)) => match s.parse::<f64>() {
+                Ok(n) => Ok(n),
+                Err(e) => self.error_at(
+                    &format!("Could not parse '{}' as f64: {}", s, e),
+                    self.prev_non_whitespace(),
+                ),
+            },
+            other => self.error_at(
+                &format!("Expected literal number, found {:?}", other),
+                other.and(self.prev_non_whitespace()),
+            ),
         }
     }
 
@@ -1044,15 +2038,42 @@ impl Parser {
     pub fn parse_literal_string(&mut self) -> Result<String, ParserError> {
         match self.next_token() {
             Some(Token::SingleQuotedString(ref s)) => Ok(s.clone()),
-            other => parser_err!(format!("Expected literal string, found {:?}", other)),
+            other => self.error_at(
+                &format!("Expected literal string, found {:?}", other),
+                other.and(self.prev_non_whitespace()),
+            ),
         }
     }
 
     /// Parse a SQL datatype (in the context of a CREATE TABLE statement for example)
     pub fn parse_data_type(&mut self) -> Result<SQLType, ParserError> {
+        let mut data_type = self.parse_element_data_type()?;
+        // Array declarations, e.g. `int[]`, `int[3][3]` or `int ARRAY[3]`
+        // (note: this is postgresql-specific)
+        loop {
+            let has_array_keyword = self.parse_keyword("ARRAY");
+            if self.consume_token(&Token::LBracket) {
+                let size = if self.consume_token(&Token::RBracket) {
+                    None
+                } else {
+                    let size = self.parse_literal_int()?;
+                    self.expect_token(&Token::RBracket)?;
+                    Some(size as usize)
+                };
+                data_type = SQLType::Array(Box::new(data_type), size);
+            } else if has_array_keyword {
+                data_type = SQLType::Array(Box::new(data_type), None);
+            } else {
+                return Ok(data_type);
+            }
+        }
+    }
+
+    /// Parse a SQL datatype, without the array declarations that may follow it
+    fn parse_element_data_type(&mut self) -> Result<SQLType, ParserError> {
         match self.next_token() {
-            Some(Token::SQLWord(k)) => match k.keyword.as_ref() {
-                "BOOLEAN" => Ok(SQLType::Boolean),
+            Some(Token::SQLWord(k)) => match k.keyword {
+                "BOOLEAN" | "BOOL" => Ok(SQLType::Boolean),
                 "FLOAT" => Ok(SQLType::Float(self.parse_optional_precision()?)),
                 "REAL" => Ok(SQLType::Real),
                 "DOUBLE" => {
@@ -1073,43 +2094,71 @@ impl Parser {
                 "UUID" => Ok(SQLType::Uuid),
                 "DATE" => Ok(SQLType::Date),
                 "TIMESTAMP" => {
+                    let precision = self.parse_optional_precision()?;
                     // TBD: we throw away "with/without timezone" information
                     if self.parse_keyword("WITH") || self.parse_keyword("WITHOUT") {
                         self.expect_keyword("TIME")?;
                         self.expect_keyword("ZONE")?;
                     }
-                    Ok(SQLType::Timestamp)
+                    Ok(SQLType::Timestamp(precision))
                 }
                 "TIME" => {
+                    let precision = self.parse_optional_precision()?;
                     // TBD: we throw away "with/without timezone" information
                     if self.parse_keyword("WITH") || self.parse_keyword("WITHOUT") {
                         self.expect_keyword("TIME")?;
                         self.expect_keyword("ZONE")?;
                     }
-                    Ok(SQLType::Time)
+                    Ok(SQLType::Time(precision))
                 }
-                "REGCLASS" => Ok(SQLType::Regclass),
-                "TEXT" => {
-                    if self.consume_token(&Token::LBracket) {
-                        // Note: this is postgresql-specific
-                        self.expect_token(&Token::RBracket)?;
-                        Ok(SQLType::Array(Box::new(SQLType::Text)))
+                "INTERVAL" => {
+                    let leading_field = self.parse_optional_date_time_field();
+                    let trailing_field = if leading_field.is_some() && self.parse_keyword("TO") {
+                        match self.parse_optional_date_time_field() {
+                            Some(field) => Some(field),
+                            None => {
+                                return self
+                                    .expected("a date/time field", self.til_non_whitespace())
+                            }
+                        }
                     } else {
-                        Ok(SQLType::Text)
-                    }
+                        None
+                    };
+                    Ok(SQLType::Interval {
+                        leading_field,
+                        trailing_field,
+                        precision: self.parse_optional_precision()?,
+                    })
                 }
+                "REGCLASS" => Ok(SQLType::Regclass),
+                "TEXT" => Ok(SQLType::Text),
                 "BYTEA" => Ok(SQLType::Bytea),
-                "NUMERIC" => {
+                "JSON" => Ok(SQLType::Json),
+                "JSONB" => Ok(SQLType::Jsonb),
+                "BINARY" => Ok(SQLType::Binary(self.parse_optional_precision()?)),
+                "VARBINARY" => Ok(SQLType::Varbinary(self.parse_optional_precision()?)),
+                "BLOB" => Ok(SQLType::Blob(self.parse_optional_precision()?)),
+                "NUMERIC" | "DECIMAL" => {
                     let (precision, scale) = self.parse_optional_precision_scale()?;
                     Ok(SQLType::Decimal(precision, scale))
                 }
                 _ => {
+                    // a (non-qualified) dialect-specific alias, e.g. `int4`
+                    if k.quote_style.is_none() && self.peek_token_ref() != Some(&Token::Period) {
+                        if let Some(data_type) = self.dialect.resolve_type_alias(&k.value) {
+                            return Ok(data_type);
+                        }
+                    }
                     self.prev_token();
                     let type_name = self.parse_object_name()?;
-                    Ok(SQLType::Custom(type_name))
+                    // type names are owned, as `Dialect::resolve_type_alias`
+                    // returns an `SQLType` to be used in any AST
+                    Ok(SQLType::Custom(SQLObjectName(
+                        type_name.0.into_iter().map(Into::into).collect(),
+                    )))
                 }
             },
-            other => self.expected("a data type name", other),
+            other => self.expected("a data type name", other.and(self.prev_non_whitespace())),
         }
     }
 
@@ -1119,7 +2168,7 @@ impl Parser {
     pub fn parse_optional_alias(
         &mut self,
         reserved_kwds: &[&str],
-    ) -> Result<Option<SQLIdent>, ParserError> {
+    ) -> Result<Option<I>, ParserError> {
         let after_as = self.parse_keyword("AS");
         let maybe_alias = self.next_token();
         match maybe_alias {
@@ -1128,15 +2177,13 @@ impl Parser {
             // which may start a construct allowed in this position, to be parsed as aliases.
             // (For example, in `FROM t1 JOIN` the `JOIN` will always be parsed as a keyword,
             // not an alias.)
-            Some(Token::SQLWord(ref w))
-                if after_as || !reserved_kwds.contains(&w.keyword.as_str()) =>
-            {
+            Some(Token::SQLWord(ref w)) if after_as || !reserved_kwds.contains(&w.keyword) => {
                 Ok(Some(w.as_sql_ident()))
             }
-            ref not_an_ident if after_as => parser_err!(format!(
-                "Expected an identifier after AS, got {:?}",
-                not_an_ident
-            )),
+            ref not_an_ident if after_as => self.error_at(
+                &format!("Expected an identifier after AS, got {:?}", not_an_ident),
+                not_an_ident.as_ref().and(self.prev_non_whitespace()),
+            ),
             Some(_not_an_ident) => {
                 self.prev_token();
                 Ok(None) // no alias found
@@ -1145,8 +2192,42 @@ impl Parser {
         }
     }
 
+    /// Parse `AS identifier` (or simply `identifier` if it's not a reserved
+    /// keyword), optionally followed by a parenthesized list of column names
+    pub fn parse_optional_table_alias(
+        &mut self,
+        reserved_kwds: &[&str],
+    ) -> Result<Option<TableAlias<I>>, ParserError> {
+        match self.parse_optional_alias(reserved_kwds)? {
+            Some(name) => {
+                let columns = self.parse_parenthesized_column_list(Optional)?;
+                Ok(Some(TableAlias { name, columns }))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Parse an optional alias after a table factor. Besides the keywords in
+    /// `RESERVED_FOR_TABLE_ALIAS`, the MySQL keywords that may follow a table
+    /// factor are reserved in the dialects that support them.
+    fn parse_optional_table_factor_alias(&mut self) -> Result<Option<TableAlias<I>>, ParserError> {
+        let reserved_by_dialect = match self.peek_token_ref() {
+            Some(Token::SQLWord(w)) => match w.keyword {
+                "STRAIGHT_JOIN" => self.dialect.supports_straight_join(),
+                "PARTITION" => self.dialect.supports_partition_selection(),
+                _ => false,
+            },
+            _ => false,
+        };
+        if reserved_by_dialect {
+            Ok(None)
+        } else {
+            self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)
+        }
+    }
+
     /// Parse one or more identifiers with the specified separator between them
-    pub fn parse_list_of_ids(&mut self, separator: &Token) -> Result<Vec<SQLIdent>, ParserError> {
+    pub fn parse_list_of_ids(&mut self, separator: &Token) -> Result<Vec<I>, ParserError> {
         let mut idents = vec![];
         let mut expect_identifier = true;
         loop {
@@ -1169,7 +2250,7 @@ impl Parser {
             }
         }
         if expect_identifier {
-            self.expected("identifier", self.peek_token())
+            self.expected("identifier", self.til_non_whitespace())
         } else {
             Ok(idents)
         }
@@ -1177,15 +2258,15 @@ impl Parser {
 
     /// Parse a possibly qualified, possibly quoted identifier, e.g.
     /// `foo` or `myschema."table"`
-    pub fn parse_object_name(&mut self) -> Result<SQLObjectName, ParserError> {
+    pub fn parse_object_name(&mut self) -> Result<SQLObjectName<I>, ParserError> {
         Ok(SQLObjectName(self.parse_list_of_ids(&Token::Period)?))
     }
 
     /// Parse a simple one-word identifier (possibly quoted, possibly a keyword)
-    pub fn parse_identifier(&mut self) -> Result<SQLIdent, ParserError> {
+    pub fn parse_identifier(&mut self) -> Result<I, ParserError> {
         match self.next_token() {
             Some(Token::SQLWord(w)) => Ok(w.as_sql_ident()),
-            unexpected => self.expected("identifier", unexpected),
+            unexpected => self.expected("identifier", unexpected.and(self.prev_non_whitespace())),
         }
     }
 
@@ -1193,7 +2274,7 @@ impl Parser {
     pub fn parse_parenthesized_column_list(
         &mut self,
         optional: IsOptional,
-    ) -> Result<Vec<SQLIdent>, ParserError> {
+    ) -> Result<Vec<I>, ParserError> {
         if self.consume_token(&Token::LParen) {
             let cols = self.parse_list_of_ids(&Token::Comma)?;
             self.expect_token(&Token::RParen)?;
@@ -1201,7 +2282,10 @@ impl Parser {
         } else if optional == Optional {
             Ok(vec![])
         } else {
-            self.expected("a list of columns in parentheses", self.peek_token())
+            self.expected(
+                "a list of columns in parentheses",
+                self.til_non_whitespace(),
+            )
         }
     }
 
@@ -1210,6 +2294,25 @@ impl Parser {
         Ok(self.parse_optional_precision()?.unwrap())
     }
 
+    /// Parse one of `YEAR`, `MONTH`, `DAY`, `HOUR`, `MINUTE` or `SECOND`,
+    /// if the next token is such a keyword
+    pub fn parse_optional_date_time_field(&mut self) -> Option<DateTimeField> {
+        let field = match self.peek_token_ref() {
+            Some(Token::SQLWord(w)) => match w.keyword {
+                "YEAR" => DateTimeField::Year,
+                "MONTH" => DateTimeField::Month,
+                "DAY" => DateTimeField::Day,
+                "HOUR" => DateTimeField::Hour,
+                "MINUTE" => DateTimeField::Minute,
+                "SECOND" => DateTimeField::Second,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        self.next_token();
+        Some(field)
+    }
+
     pub fn parse_optional_precision(&mut self) -> Result<Option<usize>, ParserError> {
         if self.consume_token(&Token::LParen) {
             let n = self.parse_literal_int()?;
@@ -1238,7 +2341,7 @@ impl Parser {
         }
     }
 
-    pub fn parse_delete(&mut self) -> Result<SQLStatement, ParserError> {
+    pub fn parse_delete(&mut self) -> Result<SQLStatement<I>, ParserError> {
         self.expect_keyword("FROM")?;
         let table_name = self.parse_object_name()?;
         let selection = if self.parse_keyword("WHERE") {
@@ -1246,23 +2349,59 @@ impl Parser {
         } else {
             None
         };
+        let returning = self.parse_optional_returning()?;
 
         Ok(SQLStatement::SQLDelete {
             table_name,
             selection,
+            returning,
+        })
+    }
+
+    /// Parse an `UPDATE` statement, assuming the `UPDATE` keyword was
+    /// already consumed
+    pub fn parse_update(&mut self) -> Result<SQLStatement<I>, ParserError> {
+        let table_name = self.parse_object_name()?;
+        self.expect_keyword("SET")?;
+        let assignments = self.parse_comma_separated(Parser::parse_assignment)?;
+        let selection = if self.parse_keyword("WHERE") {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
+        let returning = self.parse_optional_returning()?;
+
+        Ok(SQLStatement::SQLUpdate {
+            table_name,
+            assignments,
+            selection,
+            returning,
         })
     }
 
+    /// Parse an optional `RETURNING <select list>` clause at the end of
+    /// `INSERT`, `UPDATE` or `DELETE`
+    fn parse_optional_returning(&mut self) -> Result<Option<Vec<SQLSelectItem<I>>>, ParserError> {
+        if self.dialect.supports_returning() && self.parse_keyword("RETURNING") {
+            Ok(Some(self.parse_select_list()?))
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Parse a query expression, i.e. a `SELECT` statement optionally
     /// preceeded with some `WITH` CTE declarations and optionally followed
     /// by `ORDER BY`. Unlike some other parse_... methods, this one doesn't
     /// expect the initial keyword to be already consumed
-    pub fn parse_query(&mut self) -> Result<SQLQuery, ParserError> {
-        let ctes = if self.parse_keyword("WITH") {
-            // TODO: optional RECURSIVE
-            self.parse_cte_list()?
+    pub fn parse_query(&mut self) -> Result<SQLQuery<I>, ParserError> {
+        self.recursion_guarded(Parser::parse_query_unguarded)
+    }
+
+    fn parse_query_unguarded(&mut self) -> Result<SQLQuery<I>, ParserError> {
+        let (recursive, ctes) = if self.parse_keyword("WITH") {
+            (self.parse_keyword("RECURSIVE"), self.parse_cte_list()?)
         } else {
-            vec![]
+            (false, vec![])
         };
 
         let body = self.parse_query_body(0)?;
@@ -1273,40 +2412,159 @@ impl Parser {
             vec![]
         };
 
+        let mut offset = None;
         let limit = if self.parse_keyword("LIMIT") {
-            self.parse_limit()?
+            let limit = self.parse_limit()?;
+            if limit.is_some()
+                && self.dialect.supports_limit_comma()
+                && self.consume_token(&Token::Comma)
+            {
+                // MySQL's `LIMIT <offset>, <count>`
+                offset = limit.map(|value| Offset { value, rows: false });
+                Some(ASTNode::SQLValue(Value::Long(self.parse_literal_int()?)))
+            } else {
+                limit
+            }
+        } else {
+            None
+        };
+
+        if offset.is_none() && self.parse_keyword("OFFSET") {
+            offset = Some(self.parse_offset()?);
+        }
+
+        let fetch = if self.parse_keyword("FETCH") {
+            Some(self.parse_fetch()?)
         } else {
             None
         };
 
+        let mut locks = vec![];
+        while self.parse_keyword("FOR") {
+            locks.push(self.parse_lock()?);
+        }
+
         Ok(SQLQuery {
+            recursive,
             ctes,
             body,
             limit,
             order_by,
+            offset,
+            fetch,
+            locks,
+        })
+    }
+
+    /// Parse a row-locking clause, assuming `FOR` was already consumed
+    pub fn parse_lock(&mut self) -> Result<LockClause<I>, ParserError> {
+        let lock_type = if self.parse_keyword("UPDATE") {
+            LockType::Update
+        } else if self.parse_keywords(vec!["NO", "KEY", "UPDATE"]) {
+            LockType::NoKeyUpdate
+        } else if self.parse_keyword("SHARE") {
+            LockType::Share
+        } else if self.parse_keywords(vec!["KEY", "SHARE"]) {
+            LockType::KeyShare
+        } else {
+            return self.expected(
+                "UPDATE, NO KEY UPDATE, SHARE, or KEY SHARE after FOR",
+                self.til_non_whitespace(),
+            );
+        };
+        let of = if self.parse_keyword("OF") {
+            self.parse_comma_separated(Parser::parse_object_name)?
+        } else {
+            vec![]
+        };
+        let nonblock = if self.parse_keyword("NOWAIT") {
+            Some(NonBlock::Nowait)
+        } else if self.parse_keywords(vec!["SKIP", "LOCKED"]) {
+            Some(NonBlock::SkipLocked)
+        } else {
+            None
+        };
+        Ok(LockClause {
+            lock_type,
+            of,
+            nonblock,
         })
     }
 
     /// Parse one or more (comma-separated) `alias AS (subquery)` CTEs,
     /// assuming the initial `WITH` was already consumed.
-    fn parse_cte_list(&mut self) -> Result<Vec<Cte>, ParserError> {
-        let mut cte = vec![];
-        loop {
-            let alias = self.parse_identifier()?;
-            let renamed_columns = self.parse_parenthesized_column_list(Optional)?;
-            self.expect_keyword("AS")?;
-            self.expect_token(&Token::LParen)?;
-            cte.push(Cte {
-                alias,
-                query: self.parse_query()?,
-                renamed_columns,
-            });
-            self.expect_token(&Token::RParen)?;
-            if !self.consume_token(&Token::Comma) {
-                break;
-            }
-        }
-        Ok(cte)
+    fn parse_cte_list(&mut self) -> Result<Vec<Cte<I>>, ParserError> {
+        self.parse_comma_separated(Parser::parse_cte)
+    }
+
+    /// Parse a single `alias [ (col1, col2, ...) ] AS (subquery)` CTE
+    fn parse_cte(&mut self) -> Result<Cte<I>, ParserError> {
+        let alias = self.parse_identifier()?;
+        let renamed_columns = self.parse_parenthesized_column_list(Optional)?;
+        self.expect_keyword("AS")?;
+        self.expect_token(&Token::LParen)?;
+        let query = self.parse_query()?;
+        self.expect_token(&Token::RParen)?;
+        let search = if self.parse_keyword("SEARCH") {
+            Some(self.parse_cte_search()?)
+        } else {
+            None
+        };
+        let cycle = if self.parse_keyword("CYCLE") {
+            Some(self.parse_cte_cycle()?)
+        } else {
+            None
+        };
+        Ok(Cte {
+            alias,
+            query,
+            renamed_columns,
+            search,
+            cycle,
+        })
+    }
+
+    /// Parse the rest of a CTE's `SEARCH` clause, assuming `SEARCH` was already consumed
+    fn parse_cte_search(&mut self) -> Result<CteSearch<I>, ParserError> {
+        let depth_first = if self.parse_keyword("DEPTH") {
+            true
+        } else if self.parse_keyword("BREADTH") {
+            false
+        } else {
+            return self.expected("BREADTH or DEPTH after SEARCH", self.til_non_whitespace());
+        };
+        self.expect_keyword("FIRST")?;
+        self.expect_keyword("BY")?;
+        let columns = self.parse_list_of_ids(&Token::Comma)?;
+        self.expect_keyword("SET")?;
+        let set = self.parse_identifier()?;
+        Ok(CteSearch {
+            depth_first,
+            columns,
+            set,
+        })
+    }
+
+    /// Parse the rest of a CTE's `CYCLE` clause, assuming `CYCLE` was already consumed
+    fn parse_cte_cycle(&mut self) -> Result<CteCycle<I>, ParserError> {
+        let columns = self.parse_list_of_ids(&Token::Comma)?;
+        self.expect_keyword("SET")?;
+        let set = self.parse_identifier()?;
+        let mark = if self.parse_keyword("TO") {
+            let value = self.parse_value()?;
+            self.expect_keyword("DEFAULT")?;
+            Some((value, self.parse_value()?))
+        } else {
+            None
+        };
+        self.expect_keyword("USING")?;
+        let using = self.parse_identifier()?;
+        Ok(CteCycle {
+            columns,
+            set,
+            mark,
+            using,
+        })
     }
 
     /// Parse a "query body", which is an expression with roughly the
@@ -1317,7 +2575,7 @@ impl Parser {
     ///   subquery ::= query_body [ order_by_limit ]
     ///   set_operation ::= query_body { 'UNION' | 'EXCEPT' | 'INTERSECT' } [ 'ALL' ] query_body
     /// ```
-    fn parse_query_body(&mut self, precedence: u8) -> Result<SQLSetExpr, ParserError> {
+    fn parse_query_body(&mut self, precedence: u8) -> Result<SQLSetExpr<I>, ParserError> {
         // We parse the expression using a Pratt parser, as in `parse_expr()`.
         // Start by parsing a restricted SELECT or a `(subquery)`:
         let mut expr = if self.parse_keyword("SELECT") {
@@ -1327,8 +2585,15 @@ impl Parser {
             let subquery = self.parse_query()?;
             self.expect_token(&Token::RParen)?;
             SQLSetExpr::Query(Box::new(subquery))
+        } else if self.parse_keyword("VALUES") {
+            SQLSetExpr::Values(self.parse_values()?)
+        } else if self.parse_keyword("TABLE") {
+            SQLSetExpr::Table(self.parse_object_name()?)
         } else {
-            return self.expected("SELECT or a subquery in the query body", self.peek_token());
+            return self.expected(
+                "SELECT, VALUES, TABLE, or a subquery in the query body",
+                self.til_non_whitespace(),
+            );
         };
 
         loop {
@@ -1369,12 +2634,20 @@ impl Parser {
 
     /// Parse a restricted `SELECT` statement (no CTEs / `UNION` / `ORDER BY`),
     /// assuming the initial `SELECT` was already consumed
-    pub fn parse_select(&mut self) -> Result<SQLSelect, ParserError> {
+    pub fn parse_select(&mut self) -> Result<SQLSelect<I>, ParserError> {
         let all = self.parse_keyword("ALL");
         let distinct = self.parse_keyword("DISTINCT");
         if all && distinct {
-            return parser_err!("Cannot specify both ALL and DISTINCT in SELECT");
+            return self.error_at(
+                "Cannot specify both ALL and DISTINCT in SELECT",
+                self.prev_non_whitespace(),
+            );
         }
+        let modifiers = if self.dialect.supports_select_modifiers() {
+            self.parse_select_modifiers()?
+        } else {
+            vec![]
+        };
         let projection = self.parse_select_list()?;
 
         let (relation, joins) = if self.parse_keyword("FROM") {
@@ -1391,10 +2664,14 @@ impl Parser {
             None
         };
 
-        let group_by = if self.parse_keywords(vec!["GROUP", "BY"]) {
-            self.parse_expr_list()?
+        let (group_by, with_rollup) = if self.parse_keywords(vec!["GROUP", "BY"]) {
+            let group_by = self.parse_comma_separated(Parser::parse_group_by_expr)?;
+            // MySQL-specific suffix, equivalent to `GROUP BY ROLLUP(...)`
+            let with_rollup = self.dialect.supports_group_by_with_rollup()
+                && self.parse_keywords(vec!["WITH", "ROLLUP"]);
+            (group_by, with_rollup)
         } else {
-            vec![]
+            (vec![], false)
         };
 
         let having = if self.parse_keyword("HAVING") {
@@ -1403,33 +2680,113 @@ impl Parser {
             None
         };
 
+        let window = if self.parse_keyword("WINDOW") {
+            self.parse_comma_separated(Parser::parse_named_window)?
+        } else {
+            vec![]
+        };
+
         Ok(SQLSelect {
             distinct,
+            modifiers,
             projection,
             selection,
             relation,
             joins,
             group_by,
+            with_rollup,
             having,
+            window,
         })
     }
 
+    /// Parse the MySQL-specific modifiers following `SELECT [ALL | DISTINCT]`,
+    /// returning them in canonical order
+    pub fn parse_select_modifiers(&mut self) -> Result<Vec<SQLSelectModifier>, ParserError> {
+        let mut modifiers: Vec<SQLSelectModifier> = vec![];
+        loop {
+            let modifier = if self.parse_keyword("HIGH_PRIORITY") {
+                SQLSelectModifier::HighPriority
+            } else if self.parse_keyword("STRAIGHT_JOIN") {
+                SQLSelectModifier::StraightJoin
+            } else if self.parse_keyword("SQL_SMALL_RESULT") {
+                SQLSelectModifier::SmallResult
+            } else if self.parse_keyword("SQL_BIG_RESULT") {
+                SQLSelectModifier::BigResult
+            } else if self.parse_keyword("SQL_BUFFER_RESULT") {
+                SQLSelectModifier::BufferResult
+            } else if self.parse_keyword("SQL_NO_CACHE") {
+                SQLSelectModifier::NoCache
+            } else if self.parse_keyword("SQL_CALC_FOUND_ROWS") {
+                SQLSelectModifier::CalcFoundRows
+            } else {
+                break;
+            };
+            if modifiers.contains(&modifier) {
+                return self.error_at(
+                    &format!("Redundant SELECT modifier: {}", modifier),
+                    self.prev_non_whitespace(),
+                );
+            }
+            modifiers.push(modifier);
+        }
+        modifiers.sort_by_key(SQLSelectModifier::canonical_position);
+        Ok(modifiers)
+    }
+
     /// A table name or a parenthesized subquery, followed by optional `[AS] alias`
-    pub fn parse_table_factor(&mut self) -> Result<TableFactor, ParserError> {
+    pub fn parse_table_factor(&mut self) -> Result<TableFactor<I>, ParserError> {
+        let lateral = self.parse_keyword("LATERAL");
         if self.consume_token(&Token::LParen) {
             let subquery = Box::new(self.parse_query()?);
             self.expect_token(&Token::RParen)?;
-            let alias = self.parse_optional_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
-            Ok(TableFactor::Derived { subquery, alias })
+            let alias = self.parse_optional_table_factor_alias()?;
+            Ok(TableFactor::Derived {
+                lateral,
+                subquery,
+                alias,
+            })
         } else {
+            if !lateral && self.parse_keyword("JSON_TABLE") {
+                if self.consume_token(&Token::LParen) {
+                    return self.parse_json_table();
+                }
+                // not a JSON_TABLE call, but a table that happens to be named so
+                self.prev_token();
+            }
+            if !lateral && self.parse_keyword("UNNEST") {
+                if self.consume_token(&Token::LParen) {
+                    let array_expr = self.parse_expr()?;
+                    self.expect_token(&Token::RParen)?;
+                    let with_offset = self.parse_keywords(vec!["WITH", "OFFSET"]);
+                    let alias = self.parse_optional_table_factor_alias()?;
+                    return Ok(TableFactor::Unnest {
+                        array_expr,
+                        alias,
+                        with_offset,
+                    });
+                }
+                self.prev_token();
+            }
             let name = self.parse_object_name()?;
             // Postgres, MSSQL: table-valued functions:
             let args = if self.consume_token(&Token::LParen) {
-                self.parse_optional_args()?
+                Some(self.parse_optional_args()?)
+            } else if lateral {
+                return self.expected(
+                    "subquery or function call after LATERAL",
+                    self.til_non_whitespace(),
+                );
             } else {
-                vec![]
+                None
+            };
+            let partitions = self.parse_optional_partitions()?;
+            let alias = self.parse_optional_table_factor_alias()?;
+            let sample = if self.parse_keyword("TABLESAMPLE") {
+                Some(Box::new(self.parse_table_sample()?))
+            } else {
+                None
             };
-            let alias = self.parse_optional_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
             // MSSQL-specific table hints:
             let mut with_hints = vec![];
             if self.parse_keyword("WITH") {
@@ -1442,15 +2799,87 @@ impl Parser {
                 }
             };
             Ok(TableFactor::Table {
+                lateral,
                 name,
+                partitions,
                 alias,
+                sample,
                 args,
                 with_hints,
             })
         }
     }
 
-    fn parse_join_constraint(&mut self, natural: bool) -> Result<JoinConstraint, ParserError> {
+    /// Parse the rest of a `TABLESAMPLE` clause, assuming the `TABLESAMPLE`
+    /// keyword has already been consumed
+    pub fn parse_table_sample(&mut self) -> Result<TableSample<I>, ParserError> {
+        let method = if self.parse_keyword("BERNOULLI") {
+            TableSampleMethod::Bernoulli
+        } else if self.parse_keyword("SYSTEM") {
+            TableSampleMethod::System
+        } else {
+            return self.expected(
+                "BERNOULLI or SYSTEM after TABLESAMPLE",
+                self.til_non_whitespace(),
+            );
+        };
+        self.expect_token(&Token::LParen)?;
+        let quantity = self.parse_expr()?;
+        let unit = if self.parse_keyword("PERCENT") {
+            Some(TableSampleUnit::Percent)
+        } else if self.parse_keyword("ROWS") {
+            Some(TableSampleUnit::Rows)
+        } else {
+            None
+        };
+        self.expect_token(&Token::RParen)?;
+        let repeatable = if self.parse_keyword("REPEATABLE") {
+            self.expect_token(&Token::LParen)?;
+            let seed = self.parse_expr()?;
+            self.expect_token(&Token::RParen)?;
+            Some(seed)
+        } else {
+            None
+        };
+        Ok(TableSample {
+            method,
+            quantity,
+            unit,
+            repeatable,
+        })
+    }
+
+    /// Parse the rest of a `JSON_TABLE(...)` table factor, assuming the
+    /// `JSON_TABLE` keyword and the opening paren have already been consumed
+    pub fn parse_json_table(&mut self) -> Result<TableFactor<I>, ParserError> {
+        let json_expr = self.parse_expr()?;
+        self.expect_token(&Token::Comma)?;
+        let json_path = self.parse_literal_string()?;
+        self.expect_keyword("COLUMNS")?;
+        self.expect_token(&Token::LParen)?;
+        let columns = self.parse_comma_separated(|parser| {
+            let name = parser.parse_identifier()?;
+            let data_type = parser.parse_data_type()?;
+            parser.expect_keyword("PATH")?;
+            let path = parser.parse_literal_string()?;
+            Ok(JsonTableColumn {
+                name,
+                data_type,
+                path,
+            })
+        })?;
+        self.expect_token(&Token::RParen)?;
+        self.expect_token(&Token::RParen)?;
+        let alias = self.parse_optional_table_factor_alias()?;
+        Ok(TableFactor::JsonTable {
+            json_expr,
+            json_path,
+            columns,
+            alias,
+        })
+    }
+
+    fn parse_join_constraint(&mut self, natural: bool) -> Result<JoinConstraint<I>, ParserError> {
         if natural {
             Ok(JoinConstraint::Natural)
         } else if self.parse_keyword("ON") {
@@ -1460,11 +2889,11 @@ impl Parser {
             let columns = self.parse_parenthesized_column_list(Mandatory)?;
             Ok(JoinConstraint::Using(columns))
         } else {
-            self.expected("ON, or USING after JOIN", self.peek_token())
+            self.expected("ON, or USING after JOIN", self.til_non_whitespace())
         }
     }
 
-    fn parse_joins(&mut self) -> Result<Vec<Join>, ParserError> {
+    fn parse_joins(&mut self) -> Result<Vec<Join<I>>, ParserError> {
         let mut joins = vec![];
         loop {
             let natural = match &self.peek_token() {
@@ -1546,6 +2975,25 @@ impl Parser {
                         ),
                     }
                 }
+                Some(Token::SQLWord(kw))
+                    if kw.keyword == "STRAIGHT_JOIN"
+                        && !natural
+                        && self.dialect.supports_straight_join() =>
+                {
+                    self.next_token();
+                    let relation = self.parse_table_factor()?;
+                    // unlike other inner joins, the constraint is optional
+                    let constraint = match self.peek_token_ref() {
+                        Some(Token::SQLWord(kw)) if kw.keyword == "ON" || kw.keyword == "USING" => {
+                            self.parse_join_constraint(false)?
+                        }
+                        _ => JoinConstraint::None,
+                    };
+                    Join {
+                        relation,
+                        join_operator: JoinOperator::StraightJoin(constraint),
+                    }
+                }
                 _ => break,
             };
             joins.push(join);
@@ -1555,99 +3003,279 @@ impl Parser {
     }
 
     /// Parse an INSERT statement
-    pub fn parse_insert(&mut self) -> Result<SQLStatement, ParserError> {
+    pub fn parse_insert(&mut self) -> Result<SQLStatement<I>, ParserError> {
+        let (priority, ignore) = if self.dialect.supports_insert_modifiers() {
+            let priority = if self.parse_keyword("LOW_PRIORITY") {
+                Some(SQLInsertPriority::LowPriority)
+            } else if self.parse_keyword("DELAYED") {
+                Some(SQLInsertPriority::Delayed)
+            } else if self.parse_keyword("HIGH_PRIORITY") {
+                Some(SQLInsertPriority::HighPriority)
+            } else {
+                None
+            };
+            (priority, self.parse_keyword("IGNORE"))
+        } else {
+            (None, false)
+        };
         self.expect_keyword("INTO")?;
         let table_name = self.parse_object_name()?;
+        let partitions = self.parse_optional_partitions()?;
         let columns = self.parse_parenthesized_column_list(Optional)?;
-        self.expect_keyword("VALUES")?;
-        self.expect_token(&Token::LParen)?;
-        let values = self.parse_expr_list()?;
-        self.expect_token(&Token::RParen)?;
+        let source = Box::new(self.parse_query()?);
+        let on_conflict = self.parse_optional_on_conflict()?;
+        let returning = self.parse_optional_returning()?;
         Ok(SQLStatement::SQLInsert {
+            priority,
+            ignore,
             table_name,
+            partitions,
             columns,
-            values: vec![values],
+            source,
+            on_conflict,
+            returning,
         })
     }
 
-    /// Parse a comma-delimited list of SQL expressions
-    pub fn parse_expr_list(&mut self) -> Result<Vec<ASTNode>, ParserError> {
-        let mut expr_list: Vec<ASTNode> = vec![];
+    /// Parse an optional `ON CONFLICT ...` or `ON DUPLICATE KEY UPDATE ...`
+    /// clause at the end of an `INSERT`
+    fn parse_optional_on_conflict(&mut self) -> Result<Option<SQLOnConflict<I>>, ParserError> {
+        if self.dialect.supports_on_conflict() && self.parse_keywords(vec!["ON", "CONFLICT"]) {
+            let conflict_target = self.parse_parenthesized_column_list(Optional)?;
+            self.expect_keyword("DO")?;
+            if self.parse_keyword("NOTHING") {
+                Ok(Some(SQLOnConflict::DoNothing { conflict_target }))
+            } else {
+                self.expect_keyword("UPDATE")?;
+                self.expect_keyword("SET")?;
+                let assignments = self.parse_comma_separated(Parser::parse_assignment)?;
+                let selection = if self.parse_keyword("WHERE") {
+                    Some(Box::new(self.parse_expr()?))
+                } else {
+                    None
+                };
+                Ok(Some(SQLOnConflict::DoUpdate {
+                    conflict_target,
+                    assignments,
+                    selection,
+                }))
+            }
+        } else if self.dialect.supports_on_duplicate_key_update()
+            && self.parse_keywords(vec!["ON", "DUPLICATE", "KEY", "UPDATE"])
+        {
+            let assignments = self.parse_comma_separated(Parser::parse_assignment)?;
+            Ok(Some(SQLOnConflict::DuplicateKeyUpdate(assignments)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Parse a `<column> = <expr>` assignment
+    pub fn parse_assignment(&mut self) -> Result<SQLAssignment<I>, ParserError> {
+        let id = self.parse_identifier()?;
+        self.expect_token(&Token::Eq)?;
+        let value = self.parse_expr()?;
+        Ok(SQLAssignment { id, value })
+    }
+
+    /// Parse an optional `PARTITION (p0, p1)` clause after a table name, if
+    /// the dialect supports explicit partition selection
+    fn parse_optional_partitions(&mut self) -> Result<Vec<I>, ParserError> {
+        if self.dialect.supports_partition_selection() && self.parse_keyword("PARTITION") {
+            self.parse_parenthesized_column_list(Mandatory)
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    /// Parse the rows of a `VALUES` list, assuming `VALUES` was already consumed
+    pub fn parse_values(&mut self) -> Result<SQLValues<I>, ParserError> {
+        let values = self.parse_comma_separated(|parser| {
+            parser.expect_token(&Token::LParen)?;
+            let row = parser.parse_expr_list()?;
+            parser.expect_token(&Token::RParen)?;
+            Ok(row)
+        })?;
+        Ok(SQLValues(values))
+    }
+
+    /// Parse one or more comma-separated items, each parsed by `f`. A
+    /// trailing comma is an error, as `f` is called again after it.
+    pub fn parse_comma_separated<T, F>(&mut self, mut f: F) -> Result<Vec<T>, ParserError>
+    where
+        F: FnMut(&mut Parser<'a, I>) -> Result<T, ParserError>,
+    {
+        let mut values = vec![];
         loop {
-            expr_list.push(self.parse_expr()?);
-            match self.peek_token() {
-                Some(Token::Comma) => self.next_token(),
-                _ => break,
-            };
+            values.push(f(self)?);
+            if !self.consume_token(&Token::Comma) {
+                break;
+            }
+        }
+        Ok(values)
+    }
+
+    /// Parse a comma-delimited list of SQL expressions
+    pub fn parse_expr_list(&mut self) -> Result<Vec<ASTNode<I>>, ParserError> {
+        self.parse_comma_separated(Parser::parse_expr)
+    }
+
+    /// Parse an element of the `GROUP BY` list: an expression, or one of
+    /// `GROUPING SETS (...)`, `CUBE (...)`, and `ROLLUP (...)`
+    pub fn parse_group_by_expr(&mut self) -> Result<ASTNode<I>, ParserError> {
+        if self.parse_keywords(vec!["GROUPING", "SETS"]) {
+            self.expect_token(&Token::LParen)?;
+            let sets = self.parse_comma_separated(|parser| parser.parse_grouping_set(true))?;
+            self.expect_token(&Token::RParen)?;
+            Ok(ASTNode::SQLGroupingSets(sets))
+        } else if self.parse_keyword("CUBE") {
+            self.expect_token(&Token::LParen)?;
+            let sets = self.parse_comma_separated(|parser| parser.parse_grouping_set(false))?;
+            self.expect_token(&Token::RParen)?;
+            Ok(ASTNode::SQLCube(sets))
+        } else if self.parse_keyword("ROLLUP") {
+            self.expect_token(&Token::LParen)?;
+            let sets = self.parse_comma_separated(|parser| parser.parse_grouping_set(false))?;
+            self.expect_token(&Token::RParen)?;
+            Ok(ASTNode::SQLRollup(sets))
+        } else {
+            self.parse_expr()
         }
-        Ok(expr_list)
     }
 
-    pub fn parse_optional_args(&mut self) -> Result<Vec<ASTNode>, ParserError> {
+    /// Parse an element of `GROUPING SETS`, `CUBE`, or `ROLLUP`: either a
+    /// single expression or a parenthesized list of expressions, which may
+    /// be empty if `allow_empty` is set.
+    fn parse_grouping_set(&mut self, allow_empty: bool) -> Result<Vec<ASTNode<I>>, ParserError> {
+        let index = self.index;
+        if !self.consume_token(&Token::LParen) {
+            return Ok(vec![self.parse_expr()?]);
+        }
+        if allow_empty && self.consume_token(&Token::RParen) {
+            return Ok(vec![]);
+        }
+        let exprs = self.parse_expr_list()?;
+        self.expect_token(&Token::RParen)?;
+        if self.get_next_precedence()? > 0 {
+            // The parenthesized expression is only an operand, as in
+            // `ROLLUP((a + b) * c)`, so parse the whole expression again.
+            self.index = index;
+            return Ok(vec![self.parse_expr()?]);
+        }
+        Ok(exprs)
+    }
+
+    pub fn parse_optional_args(&mut self) -> Result<Vec<ASTNode<I>>, ParserError> {
         if self.consume_token(&Token::RParen) {
             Ok(vec![])
         } else {
-            let args = self.parse_expr_list()?;
+            let args = self.parse_comma_separated(Parser::parse_function_arg)?;
             self.expect_token(&Token::RParen)?;
             Ok(args)
         }
     }
 
+    /// Parse the arguments of a function call, followed by an optional
+    /// `ORDER BY` (as in `string_agg(name, ',' ORDER BY name)`), including
+    /// the closing parenthesis
+    pub fn parse_function_args(&mut self) -> Result<FunctionArgs<I>, ParserError> {
+        if self.consume_token(&Token::RParen) {
+            return Ok((vec![], vec![]));
+        }
+        let args = self.parse_comma_separated(Parser::parse_function_arg)?;
+        let order_by = if self.parse_keywords(vec!["ORDER", "BY"]) {
+            self.parse_order_by_expr_list()?
+        } else {
+            vec![]
+        };
+        self.expect_token(&Token::RParen)?;
+        Ok((args, order_by))
+    }
+
+    /// Parse a function argument: either an expression, or a named argument
+    /// such as `name => expr`
+    pub fn parse_function_arg(&mut self) -> Result<ASTNode<I>, ParserError> {
+        let index = self.index;
+        if let Some(Token::SQLWord(w)) = self.next_token() {
+            if self.consume_token(&Token::RArrow) {
+                return Ok(ASTNode::SQLNamedArgument {
+                    name: w.as_sql_ident(),
+                    arg: Box::new(self.parse_expr()?),
+                });
+            }
+        }
+        self.index = index;
+        self.parse_expr()
+    }
+
     /// Parse a comma-delimited list of projections after SELECT
-    pub fn parse_select_list(&mut self) -> Result<Vec<SQLSelectItem>, ParserError> {
-        let mut projections: Vec<SQLSelectItem> = vec![];
-        loop {
-            let expr = self.parse_expr()?;
-            if let ASTNode::SQLWildcard = expr {
-                projections.push(SQLSelectItem::Wildcard);
-            } else if let ASTNode::SQLQualifiedWildcard(prefix) = expr {
-                projections.push(SQLSelectItem::QualifiedWildcard(SQLObjectName(prefix)));
+    pub fn parse_select_list(&mut self) -> Result<Vec<SQLSelectItem<I>>, ParserError> {
+        self.parse_comma_separated(Parser::parse_select_item)
+    }
+
+    /// Parse a single projection, i.e. an expression with an optional alias
+    /// or a (possibly qualified) wildcard
+    pub fn parse_select_item(&mut self) -> Result<SQLSelectItem<I>, ParserError> {
+        // Without this check, an empty projection such as `SELECT FROM t`
+        // would have `FROM` parsed as a column name.
+        match self.peek_token() {
+            Some(Token::SQLWord(ref w))
+                if keywords::RESERVED_FOR_COLUMN_ALIAS.contains(&w.keyword) =>
+            {
+                return self.expected("an expression", self.til_non_whitespace());
+            }
+            None => return self.expected("an expression", None),
+            _ => {}
+        }
+        let expr = self.parse_expr()?;
+        if let ASTNode::SQLWildcard = expr {
+            Ok(SQLSelectItem::Wildcard)
+        } else if let ASTNode::SQLQualifiedWildcard(prefix) = expr {
+            Ok(SQLSelectItem::QualifiedWildcard(SQLObjectName(prefix)))
+        } else {
+            // `expr` is a regular SQL expression and can be followed by an alias
+            if let Some(alias) = self.parse_optional_alias(keywords::RESERVED_FOR_COLUMN_ALIAS)? {
+                Ok(SQLSelectItem::ExpressionWithAlias { expr, alias })
             } else {
-                // `expr` is a regular SQL expression and can be followed by an alias
-                if let Some(alias) =
-                    self.parse_optional_alias(keywords::RESERVED_FOR_COLUMN_ALIAS)?
-                {
-                    projections.push(SQLSelectItem::ExpressionWithAlias { expr, alias });
-                } else {
-                    projections.push(SQLSelectItem::UnnamedExpression(expr));
-                }
+                Ok(SQLSelectItem::UnnamedExpression(expr))
             }
-
-            match self.peek_token() {
-                Some(Token::Comma) => self.next_token(),
-                _ => break,
-            };
         }
-        Ok(projections)
     }
 
     /// Parse a comma-delimited list of SQL ORDER BY expressions
-    pub fn parse_order_by_expr_list(&mut self) -> Result<Vec<SQLOrderByExpr>, ParserError> {
-        let mut expr_list: Vec<SQLOrderByExpr> = vec![];
-        loop {
-            let expr = self.parse_expr()?;
+    pub fn parse_order_by_expr_list(&mut self) -> Result<Vec<SQLOrderByExpr<I>>, ParserError> {
+        self.parse_comma_separated(Parser::parse_order_by_expr)
+    }
 
-            let asc = if self.parse_keyword("ASC") {
-                Some(true)
-            } else if self.parse_keyword("DESC") {
-                Some(false)
-            } else {
-                None
-            };
+    /// Parse an expression, optionally followed by ASC or DESC and then by
+    /// NULLS FIRST or NULLS LAST (used in ORDER BY)
+    pub fn parse_order_by_expr(&mut self) -> Result<SQLOrderByExpr<I>, ParserError> {
+        let expr = self.parse_expr()?;
 
-            expr_list.push(SQLOrderByExpr { expr, asc });
+        let asc = if self.parse_keyword("ASC") {
+            Some(true)
+        } else if self.parse_keyword("DESC") {
+            Some(false)
+        } else {
+            None
+        };
 
-            if let Some(Token::Comma) = self.peek_token() {
-                self.next_token();
-            } else {
-                break;
-            }
-        }
-        Ok(expr_list)
+        let nulls_first = if self.parse_keywords(vec!["NULLS", "FIRST"]) {
+            Some(true)
+        } else if self.parse_keywords(vec!["NULLS", "LAST"]) {
+            Some(false)
+        } else {
+            None
+        };
+        Ok(SQLOrderByExpr {
+            expr,
+            asc,
+            nulls_first,
+        })
     }
 
     /// Parse a LIMIT clause
-    pub fn parse_limit(&mut self) -> Result<Option<ASTNode>, ParserError> {
+    pub fn parse_limit(&mut self) -> Result<Option<ASTNode<I>>, ParserError> {
         if self.parse_keyword("ALL") {
             Ok(None)
         } else {
@@ -1655,17 +3283,56 @@ impl Parser {
                 .map(|n| Some(ASTNode::SQLValue(Value::Long(n))))
         }
     }
+
+    /// Parse an OFFSET clause
+    pub fn parse_offset(&mut self) -> Result<Offset<I>, ParserError> {
+        let value = self
+            .parse_literal_int()
+            .map(|n| ASTNode::SQLValue(Value::Long(n)))?;
+        let rows = self.parse_one_of_keywords(&["ROW", "ROWS"]).is_some();
+        Ok(Offset { value, rows })
+    }
+
+    /// Parse a FETCH clause
+    pub fn parse_fetch(&mut self) -> Result<Fetch<I>, ParserError> {
+        self.expect_one_of_keywords(&["FIRST", "NEXT"])?;
+        let (quantity, percent) = if self.parse_one_of_keywords(&["ROW", "ROWS"]).is_some() {
+            (None, false)
+        } else {
+            let quantity = self.parse_sql_value()?;
+            let percent = self.parse_keyword("PERCENT");
+            self.expect_one_of_keywords(&["ROW", "ROWS"])?;
+            (Some(quantity), percent)
+        };
+        let with_ties = if self.parse_keyword("ONLY") {
+            false
+        } else if self.parse_keywords(vec!["WITH", "TIES"]) {
+            true
+        } else {
+            return self.expected("one of ONLY or WITH TIES", self.til_non_whitespace());
+        };
+        Ok(Fetch {
+            with_ties,
+            percent,
+            quantity,
+        })
+    }
 }
 
-impl SQLWord {
-    pub fn as_sql_ident(&self) -> SQLIdent {
-        self.to_string()
+impl<'a> SQLWord<'a> {
+    /// The identifier this word stands for, in the originally quoted form
+    pub fn as_sql_ident<I: From<Cow<'a, str>>>(&self) -> I {
+        match self.quote_style {
+            None => I::from(self.value.clone()),
+            Some(_) => I::from(Cow::Owned(self.to_string())),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::dialect::GenericSqlDialect;
     use crate::test_utils::all_dialects;
 
     #[test]
@@ -1681,4 +3348,245 @@ mod tests {
             assert_eq!(parser.prev_token(), None);
         });
     }
+
+    #[test]
+    fn test_peek_token_does_not_consume() {
+        let sql = "SELECT  a ,\n b";
+        all_dialects().run_parser_method(sql, |parser| {
+            assert_eq!(parser.peek_token(), Some(Token::make_keyword("SELECT")));
+            assert_eq!(
+                parser.peek_token_ref(),
+                Some(&Token::make_keyword("SELECT"))
+            );
+            assert_eq!(parser.next_token(), Some(Token::make_keyword("SELECT")));
+            // whitespace is skipped, but only consumed by `next_token`
+            assert_eq!(parser.peek_token(), Some(Token::make_word("a", None)));
+            assert_eq!(parser.peek_token(), Some(Token::make_word("a", None)));
+            assert_eq!(parser.next_token(), Some(Token::make_word("a", None)));
+            assert!(parser.consume_token(&Token::Comma));
+            assert!(!parser.consume_token(&Token::Comma));
+            assert_eq!(parser.next_token(), Some(Token::make_word("b", None)));
+            assert_eq!(parser.peek_token_ref(), None);
+            assert_eq!(parser.next_token(), None);
+        });
+    }
+
+    #[test]
+    fn test_parse_large_statement() {
+        let rows = 10_000;
+        let sql = format!(
+            "INSERT INTO t (a, b) VALUES {}",
+            vec!["(1, 'x')"; rows].join(", ")
+        );
+        let dialect = GenericSqlDialect {};
+        match Parser::parse_sql(&dialect, sql.clone()).unwrap().as_slice() {
+            [stmt @ SQLStatement::SQLInsert { source, .. }] => {
+                match &source.body {
+                    SQLSetExpr::Values(SQLValues(values)) => assert_eq!(rows, values.len()),
+                    _ => panic!("Expected VALUES"),
+                }
+                assert_eq!(sql, stmt.to_string());
+            }
+            stmts => panic!("Expected one INSERT, got {:?}", stmts),
+        }
+    }
+
+    #[test]
+    fn test_parse_named_placeholder() {
+        let sql = "SELECT a FROM t WHERE a = :name";
+        let ast = Parser::parse_sql(&GenericSqlDialect {}, sql.to_string()).unwrap();
+        assert_eq!(sql, ast[0].to_string());
+        match &ast[0] {
+            SQLStatement::SQLQuery(query) => match &query.body {
+                SQLSetExpr::Select(select) => assert_eq!(
+                    Some(ASTNode::SQLBinaryExpr {
+                        left: Box::new(ASTNode::SQLIdentifier("a".to_string())),
+                        op: SQLOperator::Eq,
+                        right: Box::new(ASTNode::SQLValue(Value::Placeholder(":name".to_string()))),
+                    }),
+                    select.selection
+                ),
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_recursion_limit() {
+        let dialect = GenericSqlDialect {};
+        let nested_expr = |depth| format!("{}1{}", "(".repeat(depth), ")".repeat(depth));
+        let (depth_4, depth_5) = (nested_expr(4), nested_expr(5));
+        let tokens = |sql| {
+            Tokenizer::new(&dialect, sql)
+                .tokenize_with_location()
+                .unwrap()
+        };
+
+        // deeply nested input is rejected rather than overflowing the stack
+        let sql = format!("SELECT {}1{}", "(".repeat(10_000), ")".repeat(10_000));
+        assert_eq!(
+            Err(ParserError::RecursionLimitExceeded),
+            Parser::parse_sql(&dialect, sql)
+        );
+        let sql = format!(
+            "SELECT * FROM {}t{}",
+            "(SELECT * FROM ".repeat(10_000),
+            ")".repeat(10_000)
+        );
+        assert_eq!(
+            Err(ParserError::RecursionLimitExceeded),
+            Parser::parse_sql(&dialect, sql)
+        );
+
+        // the limit is configurable
+        let mut parser = Parser::new_with_locations(tokens(&depth_5), &dialect);
+        assert!(parser.parse_expr().is_ok());
+        let mut parser =
+            Parser::new_with_locations(tokens(&depth_5), &dialect).with_recursion_limit(5);
+        assert_eq!(
+            Err(ParserError::RecursionLimitExceeded),
+            parser.parse_expr()
+        );
+        let mut parser =
+            Parser::new_with_locations(tokens(&depth_4), &dialect).with_recursion_limit(5);
+        assert!(parser.parse_expr().is_ok());
+    }
+
+    #[test]
+    fn test_parse_long_binary_chain() {
+        let dialect = GenericSqlDialect {};
+        let terms = 10_000;
+        let sql = vec!["a"; terms].join(" + ");
+        let tokens = Tokenizer::new(&dialect, &sql).tokenize().unwrap();
+        let mut expr = Parser::new(tokens, &dialect).parse_expr().unwrap();
+
+        // `a + a + ...` nests to the left, so walk down the left spine
+        // instead of recursing (and check the recursion limit didn't kick in)
+        let mut depth = 1;
+        loop {
+            match expr {
+                ASTNode::SQLBinaryExpr { left, op, right } => {
+                    assert_eq!(SQLOperator::Plus, op);
+                    assert_eq!(ASTNode::SQLIdentifier("a".to_string()), *right);
+                    expr = *left;
+                    depth += 1;
+                }
+                ASTNode::SQLIdentifier(ref ident) => {
+                    assert_eq!("a", ident);
+                    break;
+                }
+                _ => panic!("Unexpected {:?}", expr),
+            }
+        }
+        assert_eq!(terms, depth);
+    }
+
+    #[test]
+    fn test_parser_from_tokens() {
+        let dialect = GenericSqlDialect {};
+        let tokens = vec![
+            Token::make_word("a", None),
+            Token::Plus,
+            Token::Number("1".to_string()),
+        ];
+        let mut parser = Parser::new(tokens, &dialect);
+        assert_eq!(
+            Ok(ASTNode::SQLBinaryExpr {
+                left: Box::new(ASTNode::SQLIdentifier("a".to_string())),
+                op: SQLOperator::Plus,
+                right: Box::new(ASTNode::SQLValue(Value::Long(1))),
+            }),
+            parser.parse_expr()
+        );
+        assert_eq!(None, parser.remaining_tokens().next());
+
+        // without locations, errors don't report one
+        let mut parser = Parser::new(vec![Token::RParen], &dialect);
+        assert_eq!(
+            Err(ParserError::ParserError(
+                "Expected an expression, found: )".to_string()
+            )),
+            parser.parse_expr()
+        );
+    }
+
+    #[test]
+    fn test_remaining_tokens() {
+        all_dialects().run_parser_method("a, b", |parser| {
+            assert_eq!(Ok("a".to_string()), parser.parse_identifier());
+            assert_eq!(
+                vec![
+                    &Token::Comma,
+                    &Token::Whitespace(Whitespace::Space),
+                    &Token::make_word("b", None),
+                ],
+                parser.remaining_tokens().collect::<Vec<_>>()
+            );
+        });
+    }
+
+    #[test]
+    fn test_parse_comma_separated_identifiers() {
+        let idents = all_dialects().run_parser_method("a, b , c", |parser| {
+            parser.parse_comma_separated(Parser::parse_identifier)
+        });
+        assert_eq!(
+            Ok(vec!["a".to_string(), "b".to_string(), "c".to_string()]),
+            idents
+        );
+
+        // a single item does not require a comma
+        let idents = all_dialects().run_parser_method("a b", |parser| {
+            let idents = parser.parse_comma_separated(Parser::parse_identifier);
+            // the list ends at the first item not followed by a comma
+            assert_eq!(Some(Token::make_word("b", None)), parser.peek_token());
+            idents
+        });
+        assert_eq!(Ok(vec!["a".to_string()]), idents);
+    }
+
+    #[test]
+    fn test_parse_comma_separated_exprs() {
+        let exprs = all_dialects().run_parser_method("1 + 2, f(x)", |parser| {
+            parser.parse_comma_separated(Parser::parse_expr)
+        });
+        assert_eq!(
+            vec!["1 + 2".to_string(), "f(x)".to_string()],
+            exprs
+                .unwrap()
+                .iter()
+                .map(ASTNode::to_string)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_parse_comma_separated_errors() {
+        // an empty list is an error: at least one item is required
+        let res = all_dialects().run_parser_method("", |parser| {
+            parser.parse_comma_separated(Parser::parse_identifier)
+        });
+        assert_eq!(
+            Err(ParserError::ParserError(
+                "Expected identifier, found: EOF".to_string()
+            )),
+            res
+        );
+
+        // a trailing comma is an error
+        let res = all_dialects().run_parser_method("a, b,", |parser| {
+            parser.parse_comma_separated(Parser::parse_identifier)
+        });
+        assert_eq!(
+            Err(ParserError::ParserError(
+                "Expected identifier, found: EOF".to_string()
+            )),
+            res
+        );
+        let res = all_dialects().run_parser_method("1, 2,", |parser| {
+            parser.parse_comma_separated(Parser::parse_expr)
+        });
+        assert!(res.is_err());
+    }
 }
@@ -17,18 +17,25 @@
 //! The tokenizer (a.k.a. lexer) converts a string into a sequence of tokens.
 //!
 //! The tokens then form the input for the parser, which outputs an Abstract Syntax Tree (AST).
+//!
+//! Whitespace and comments are preserved as `Token::Whitespace`, so that the
+//! original text can be reconstructed by concatenating the `to_string()` of
+//! each token. The parser skips them where insignificant, so a token stream
+//! built by hand (e.g. for `Parser::new`) need not include them.
 
+use std::borrow::Cow;
+use std::fmt;
 use std::iter::Peekable;
 use std::str::Chars;
 
-use super::dialect::keywords::ALL_KEYWORDS;
+use super::dialect::keywords;
 use super::dialect::Dialect;
 
 /// SQL Token enumeration
 #[derive(Debug, Clone, PartialEq)]
-pub enum Token {
+pub enum Token<'a> {
     /// A keyword (like SELECT) or an optionally quoted SQL identifier
-    SQLWord(SQLWord),
+    SQLWord(SQLWord<'a>),
     /// Numeric literal
     Number(String),
     /// A character that could not be tokenized
@@ -37,12 +44,18 @@ pub enum Token {
     SingleQuotedString(String),
     /// "National" string literal: i.e: N'string'
     NationalStringLiteral(String),
+    /// Dollar-quoted string: i.e: $$string$$ or $tag$string$tag$
+    DollarQuotedString { tag: Option<String>, value: String },
+    /// Bind parameter placeholder: i.e: `?`, `$1` or `:name`
+    Placeholder(String),
     /// Comma
     Comma,
     /// Whitespace (space, tab, etc)
     Whitespace(Whitespace),
     /// Equality operator `=`
     Eq,
+    /// Right arrow `=>` used for named function arguments
+    RArrow,
     /// Not Equals operator `<>` (or `!=` in some dialects)
     Neq,
     /// Less Than operator `<`
@@ -81,15 +94,35 @@ pub enum Token {
     LBracket,
     /// Right bracket `]`
     RBracket,
-    /// Ampersand &
+    /// Ampersand `&`
     Ampersand,
+    /// Pipe `|`
+    Pipe,
+    /// Double pipe `||`
+    DoublePipe,
+    /// Sharp `#` (bitwise exclusive or in PostgreSQL)
+    Sharp,
+    /// Caret `^` (exponentiation in PostgreSQL)
+    Caret,
+    /// Left shift `<<`
+    ShiftLeft,
+    /// Right shift `>>`
+    ShiftRight,
     /// Left brace `{`
     LBrace,
     /// Right brace `}`
     RBrace,
+    /// Case-sensitive regular expression match operator `~` (PostgreSQL)
+    Tilde,
+    /// Case-insensitive regular expression match operator `~*` (PostgreSQL)
+    TildeAsterisk,
+    /// Case-sensitive regular expression non-match operator `!~` (PostgreSQL)
+    ExclamationMarkTilde,
+    /// Case-insensitive regular expression non-match operator `!~*` (PostgreSQL)
+    ExclamationMarkTildeAsterisk,
 }
 
-impl ToString for Token {
+impl ToString for Token<'_> {
     fn to_string(&self) -> String {
         match self {
             Token::SQLWord(ref w) => w.to_string(),
@@ -97,9 +130,15 @@ impl ToString for Token {
             Token::Char(ref c) => c.to_string(),
             Token::SingleQuotedString(ref s) => format!("'{}'", s),
             Token::NationalStringLiteral(ref s) => format!("N'{}'", s),
+            Token::DollarQuotedString { ref tag, ref value } => {
+                let tag = tag.as_deref().unwrap_or("");
+                format!("${}${}${}$", tag, value, tag)
+            }
+            Token::Placeholder(ref s) => s.to_string(),
             Token::Comma => ",".to_string(),
             Token::Whitespace(ws) => ws.to_string(),
             Token::Eq => "=".to_string(),
+            Token::RArrow => "=>".to_string(),
             Token::Neq => "<>".to_string(),
             Token::Lt => "<".to_string(),
             Token::Gt => ">".to_string(),
@@ -120,61 +159,77 @@ impl ToString for Token {
             Token::LBracket => "[".to_string(),
             Token::RBracket => "]".to_string(),
             Token::Ampersand => "&".to_string(),
+            Token::Pipe => "|".to_string(),
+            Token::DoublePipe => "||".to_string(),
+            Token::Sharp => "#".to_string(),
+            Token::Caret => "^".to_string(),
+            Token::ShiftLeft => "<<".to_string(),
+            Token::ShiftRight => ">>".to_string(),
             Token::LBrace => "{".to_string(),
             Token::RBrace => "}".to_string(),
+            Token::Tilde => "~".to_string(),
+            Token::TildeAsterisk => "~*".to_string(),
+            Token::ExclamationMarkTilde => "!~".to_string(),
+            Token::ExclamationMarkTildeAsterisk => "!~*".to_string(),
         }
     }
 }
 
-impl Token {
-    pub fn make_keyword(keyword: &str) -> Self {
+impl<'a> Token<'a> {
+    pub fn make_keyword(keyword: &'a str) -> Self {
         Token::make_word(keyword, None)
     }
-    pub fn make_word(word: &str, quote_style: Option<char>) -> Self {
-        let word_uppercase = word.to_uppercase();
-        //TODO: need to reintroduce FnvHashSet at some point .. iterating over keywords is
-        // not fast but I want the simplicity for now while I experiment with pluggable
-        // dialects
-        let is_keyword = quote_style == None && ALL_KEYWORDS.contains(&word_uppercase.as_str());
+    pub fn make_word(word: impl Into<Cow<'a, str>>, quote_style: Option<char>) -> Self {
+        let value = word.into();
+        let keyword = match quote_style {
+            None => keywords::lookup_keyword(&value),
+            Some(_) => None,
+        };
         Token::SQLWord(SQLWord {
-            value: word.to_string(),
+            value,
             quote_style,
-            keyword: if is_keyword {
-                word_uppercase
-            } else {
-                "".to_string()
-            },
+            keyword: keyword.unwrap_or(""),
         })
     }
 }
 
 /// A keyword (like SELECT) or an optionally quoted SQL identifier
 #[derive(Debug, Clone, PartialEq)]
-pub struct SQLWord {
+pub struct SQLWord<'a> {
     /// The value of the token, without the enclosing quotes, and with the
-    /// escape sequences (if any) processed (TODO: escapes are not handled)
-    pub value: String,
+    /// escape sequences (if any) processed. The only escape sequence for
+    /// delimited identifiers is a doubled closing quote, e.g. `[a]]b]`
+    /// has the value `a]b`. Borrowed from the tokenized input, unless
+    /// escape sequences had to be processed.
+    pub value: Cow<'a, str>,
     /// An identifier can be "quoted" (&lt;delimited identifier> in ANSI parlance).
     /// The standard and most implementations allow using double quotes for this,
     /// but some implementations support other quoting styles as well (e.g. \[MS SQL])
     pub quote_style: Option<char>,
     /// If the word was not quoted and it matched one of the known keywords,
     /// this will have one of the values from dialect::keywords, otherwise empty
-    pub keyword: String,
+    pub keyword: &'static str,
 }
 
-impl ToString for SQLWord {
+impl ToString for SQLWord<'_> {
     fn to_string(&self) -> String {
         match self.quote_style {
             Some(s) if s == '"' || s == '[' || s == '`' => {
-                format!("{}{}{}", s, self.value, SQLWord::matching_end_quote(s))
+                let end_quote = SQLWord::matching_end_quote(s);
+                let escaped_end_quote = format!("{}{}", end_quote, end_quote);
+                format!(
+                    "{}{}{}",
+                    s,
+                    self.value.replace(end_quote, &escaped_end_quote),
+                    end_quote
+                )
             }
-            None => self.value.clone(),
+            None => self.value.to_string(),
             _ => panic!("Unexpected quote_style!"),
         }
     }
 }
-impl SQLWord {
+impl SQLWord<'_> {
     fn matching_end_quote(ch: char) -> char {
         match ch {
             '"' => '"', // ANSI and most dialects
@@ -206,57 +261,134 @@ impl ToString for Whitespace {
     }
 }
 
+/// A position in the tokenized input, counting lines and columns from 1
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Location {
+    pub line: u64,
+    pub column: u64,
+}
+
+impl Location {
+    /// The location of a token that was not produced by the tokenizer
+    pub const UNKNOWN: Location = Location { line: 0, column: 0 };
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// A token along with the location of its first character
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenWithLocation<'a> {
+    pub token: Token<'a>,
+    pub location: Location,
+}
+
 /// Tokenizer error
 #[derive(Debug, PartialEq)]
 pub struct TokenizerError(String);
 
+/// The input of the tokenizer, keeping track of the current location
+#[derive(Clone)]
+struct State<'a> {
+    query: &'a str,
+    peekable: Peekable<Chars<'a>>,
+    /// The byte offset of the next char in `query`
+    offset: usize,
+    line: u64,
+    col: u64,
+}
+
+impl<'a> State<'a> {
+    fn next(&mut self) -> Option<char> {
+        let ch = self.peekable.next()?;
+        self.offset += ch.len_utf8();
+        // a lone `\r` is a newline too, but `\r\n` counts only once
+        if ch == '\n' || (ch == '\r' && self.peekable.peek() != Some(&'\n')) {
+            self.line += 1;
+            self.col = 1;
+        } else {
+            self.col += 1;
+        }
+        Some(ch)
+    }
+
+    fn peek(&mut self) -> Option<&char> {
+        self.peekable.peek()
+    }
+
+    /// Look at the `n`th char after the next one, without consuming anything
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        self.peekable.clone().nth(n)
+    }
+
+    /// The input from byte offset `start` up to (but excluding) the next char
+    fn slice_from(&self, start: usize) -> &'a str {
+        &self.query[start..self.offset]
+    }
+
+    fn location(&self) -> Location {
+        Location {
+            line: self.line,
+            column: self.col,
+        }
+    }
+}
+
 /// SQL Tokenizer
 pub struct Tokenizer<'a> {
     dialect: &'a dyn Dialect,
-    pub query: String,
+    pub query: &'a str,
     pub line: u64,
     pub col: u64,
 }
 
 impl<'a> Tokenizer<'a> {
     /// Create a new SQL tokenizer for the specified SQL statement
-    pub fn new(dialect: &'a dyn Dialect, query: &str) -> Self {
+    /// The words of the resulting tokens borrow from `query` where possible
+    pub fn new(dialect: &'a dyn Dialect, query: &'a str) -> Self {
         Self {
             dialect,
-            query: query.to_string(),
+            query,
             line: 1,
             col: 1,
         }
     }
 
     /// Tokenize the statement and produce a vector of tokens
-    pub fn tokenize(&mut self) -> Result<Vec<Token>, TokenizerError> {
-        let mut peekable = self.query.chars().peekable();
-
-        let mut tokens: Vec<Token> = vec![];
+    pub fn tokenize(&mut self) -> Result<Vec<Token<'a>>, TokenizerError> {
+        let tokens = self.tokenize_with_location()?;
+        Ok(tokens.into_iter().map(|t| t.token).collect())
+    }
 
-        while let Some(token) = self.next_token(&mut peekable)? {
-            match &token {
-                Token::Whitespace(Whitespace::Newline) => {
-                    self.line += 1;
-                    self.col = 1;
-                }
+    /// Tokenize the statement and produce a vector of tokens, each with the
+    /// location in the input where it starts
+    pub fn tokenize_with_location(&mut self) -> Result<Vec<TokenWithLocation<'a>>, TokenizerError> {
+        let mut state = State {
+            query: self.query,
+            peekable: self.query.chars().peekable(),
+            offset: 0,
+            line: 1,
+            col: 1,
+        };
 
-                Token::Whitespace(Whitespace::Tab) => self.col += 4,
-                Token::SQLWord(w) if w.quote_style == None => self.col += w.value.len() as u64,
-                Token::SQLWord(w) if w.quote_style != None => self.col += w.value.len() as u64 + 2,
-                Token::Number(s) => self.col += s.len() as u64,
-                Token::SingleQuotedString(s) => self.col += s.len() as u64,
-                _ => self.col += 1,
+        let mut tokens: Vec<TokenWithLocation<'a>> = vec![];
+        loop {
+            let location = state.location();
+            self.line = location.line;
+            self.col = location.column;
+            match self.next_token(&mut state)? {
+                Some(token) => tokens.push(TokenWithLocation { token, location }),
+                None => break,
             }
-
-            tokens.push(token);
         }
         Ok(tokens)
     }
 
     /// Get the next token or return None
-    fn next_token(&self, chars: &mut Peekable<Chars<'_>>) -> Result<Option<Token>, TokenizerError> {
+    fn next_token(&self, chars: &mut State<'a>) -> Result<Option<Token<'a>>, TokenizerError> {
         //println!("next_token: {:?}", chars.peek());
         match chars.peek() {
             Some(&ch) => match ch {
@@ -282,7 +414,7 @@ impl<'a> Tokenizer<'a> {
                         _ => {
                             // regular identifier starting with an "N"
                             let s = self.tokenize_word('N', chars);
-                            Ok(Some(Token::make_word(&s, None)))
+                            Ok(Some(Token::make_word(s, None)))
                         }
                     }
                 }
@@ -290,25 +422,64 @@ impl<'a> Tokenizer<'a> {
                 ch if self.dialect.is_identifier_start(ch) => {
                     chars.next(); // consume the first char
                     let s = self.tokenize_word(ch, chars);
-                    Ok(Some(Token::make_word(&s, None)))
+                    Ok(Some(Token::make_word(s, None)))
                 }
                 // string
                 '\'' => {
                     let s = self.tokenize_single_quoted_string(chars);
                     Ok(Some(Token::SingleQuotedString(s)))
                 }
+                // numbered placeholder, e.g. `$1`
+                '$' if self.dialect.supports_numbered_placeholders()
+                    && matches!(chars.peek_nth(1), Some('0'..='9')) =>
+                {
+                    chars.next(); // consume the '$'
+                    let mut s = String::from("$");
+                    while let Some(&ch) = chars.peek() {
+                        if !ch.is_ascii_digit() {
+                            break;
+                        }
+                        chars.next(); // consume
+                        s.push(ch);
+                    }
+                    Ok(Some(Token::Placeholder(s)))
+                }
+                // dollar-quoted string
+                '$' if self.dialect.supports_dollar_quoted_strings() => {
+                    match self.tokenize_dollar_quote_tag(chars) {
+                        Some(tag) => self.tokenize_dollar_quoted_string(tag, chars),
+                        None => self.consume_and_return(chars, Token::Char('$')),
+                    }
+                }
                 // delimited (quoted) identifier
                 quote_start if self.dialect.is_delimited_identifier_start(quote_start) => {
-                    let mut s = String::new();
                     chars.next(); // consume the opening quote
                     let quote_end = SQLWord::matching_end_quote(quote_start);
+                    let start = chars.offset;
+                    let mut end = None;
+                    let mut escaped = false;
                     while let Some(ch) = chars.next() {
                         match ch {
-                            c if c == quote_end => break,
-                            _ => s.push(ch),
+                            // a doubled closing quote stands for itself
+                            c if c == quote_end && chars.peek() == Some(&quote_end) => {
+                                chars.next();
+                                escaped = true;
+                            }
+                            c if c == quote_end => {
+                                end = Some(chars.offset - c.len_utf8());
+                                break;
+                            }
+                            _ => {}
                         }
                     }
-                    Ok(Some(Token::make_word(&s, Some(quote_start))))
+                    let s = &chars.query[start..end.unwrap_or(chars.offset)];
+                    let value = if escaped {
+                        let end_quote = quote_end.to_string();
+                        Cow::Owned(s.replace(&end_quote.repeat(2), &end_quote))
+                    } else {
+                        Cow::Borrowed(s)
+                    };
+                    Ok(Some(Token::make_word(value, Some(quote_start))))
                 }
                 // numbers
                 '0'..='9' => {
@@ -369,13 +540,29 @@ impl<'a> Tokenizer<'a> {
                 '+' => self.consume_and_return(chars, Token::Plus),
                 '*' => self.consume_and_return(chars, Token::Mult),
                 '%' => self.consume_and_return(chars, Token::Mod),
-                '=' => self.consume_and_return(chars, Token::Eq),
+                '=' => {
+                    chars.next(); // consume
+                    match chars.peek() {
+                        Some('>') => self.consume_and_return(chars, Token::RArrow),
+                        _ => Ok(Some(Token::Eq)),
+                    }
+                }
                 '.' => self.consume_and_return(chars, Token::Period),
                 '!' => {
                     chars.next(); // consume
                     match chars.peek() {
                         Some(&ch) => match ch {
                             '=' => self.consume_and_return(chars, Token::Neq),
+                            '~' => {
+                                chars.next(); // consume
+                                match chars.peek() {
+                                    Some('*') => self.consume_and_return(
+                                        chars,
+                                        Token::ExclamationMarkTildeAsterisk,
+                                    ),
+                                    _ => Ok(Some(Token::ExclamationMarkTilde)),
+                                }
+                            }
                             _ => Err(TokenizerError(format!(
                                 "Tokenizer Error at Line: {}, Col: {}",
                                 self.line, self.col
@@ -393,6 +580,7 @@ impl<'a> Tokenizer<'a> {
                         Some(&ch) => match ch {
                             '=' => self.consume_and_return(chars, Token::LtEq),
                             '>' => self.consume_and_return(chars, Token::Neq),
+                            '<' => self.consume_and_return(chars, Token::ShiftLeft),
                             _ => Ok(Some(Token::Lt)),
                         },
                         None => Ok(Some(Token::Lt)),
@@ -403,6 +591,7 @@ impl<'a> Tokenizer<'a> {
                     match chars.peek() {
                         Some(&ch) => match ch {
                             '=' => self.consume_and_return(chars, Token::GtEq),
+                            '>' => self.consume_and_return(chars, Token::ShiftRight),
                             _ => Ok(Some(Token::Gt)),
                         },
                         None => Ok(Some(Token::Gt)),
@@ -415,6 +604,13 @@ impl<'a> Tokenizer<'a> {
                         Some(&ch) => match ch {
                             // double colon
                             ':' => self.consume_and_return(chars, Token::DoubleColon),
+                            // named placeholder, e.g. `:name`
+                            ch if self.dialect.supports_named_placeholders()
+                                && self.dialect.is_identifier_start(ch) =>
+                            {
+                                let s = self.tokenize_word(':', chars);
+                                Ok(Some(Token::Placeholder(s.to_string())))
+                            }
                             _ => Ok(Some(Token::Colon)),
                         },
                         None => Ok(Some(Token::Colon)),
@@ -426,6 +622,23 @@ impl<'a> Tokenizer<'a> {
                 '[' => self.consume_and_return(chars, Token::LBracket),
                 ']' => self.consume_and_return(chars, Token::RBracket),
                 '&' => self.consume_and_return(chars, Token::Ampersand),
+                '|' => {
+                    chars.next(); // consume
+                    match chars.peek() {
+                        Some('|') => self.consume_and_return(chars, Token::DoublePipe),
+                        _ => Ok(Some(Token::Pipe)),
+                    }
+                }
+                '#' => self.consume_and_return(chars, Token::Sharp),
+                '?' => self.consume_and_return(chars, Token::Placeholder("?".to_string())),
+                '^' => self.consume_and_return(chars, Token::Caret),
+                '~' => {
+                    chars.next(); // consume
+                    match chars.peek() {
+                        Some('*') => self.consume_and_return(chars, Token::TildeAsterisk),
+                        _ => Ok(Some(Token::Tilde)),
+                    }
+                }
                 '{' => self.consume_and_return(chars, Token::LBrace),
                 '}' => self.consume_and_return(chars, Token::RBrace),
                 other => self.consume_and_return(chars, Token::Char(other)),
@@ -435,22 +648,20 @@ impl<'a> Tokenizer<'a> {
     }
 
     /// Tokenize an identifier or keyword, after the first char is already consumed.
-    fn tokenize_word(&self, first_char: char, chars: &mut Peekable<Chars<'_>>) -> String {
-        let mut s = String::new();
-        s.push(first_char);
+    fn tokenize_word(&self, first_char: char, chars: &mut State<'a>) -> &'a str {
+        let start = chars.offset - first_char.len_utf8();
         while let Some(&ch) = chars.peek() {
             if self.dialect.is_identifier_part(ch) {
                 chars.next(); // consume
-                s.push(ch);
             } else {
                 break;
             }
         }
-        s
+        chars.slice_from(start)
     }
 
     /// Read a single quoted string, starting with the opening quote.
-    fn tokenize_single_quoted_string(&self, chars: &mut Peekable<Chars<'_>>) -> String {
+    fn tokenize_single_quoted_string(&self, chars: &mut State<'_>) -> String {
         //TODO: handle escaped quotes in string
         //TODO: handle newlines in string
         //TODO: handle EOF before terminating quote
@@ -478,28 +689,83 @@ impl<'a> Tokenizer<'a> {
         s
     }
 
-    fn tokenize_multiline_comment(
+    /// Read the opening tag of a dollar-quoted string (`$$` or `$tag$`),
+    /// starting with the first `$`. Returns `None` without consuming anything
+    /// if the input at this position is not an opening tag.
+    fn tokenize_dollar_quote_tag(&self, chars: &mut State<'_>) -> Option<Option<String>> {
+        let mut lookahead = chars.clone();
+        lookahead.next(); // skip the opening '$'
+        let mut tag = String::new();
+        loop {
+            match lookahead.next() {
+                Some('$') => break,
+                // a tag follows the rules of unquoted identifiers, but
+                // cannot contain a dollar sign, nor begin with a digit (to
+                // not confuse it with a `$1` placeholder)
+                Some(ch) if ch.is_alphabetic() || ch == '_' => tag.push(ch),
+                Some(ch) if ch.is_ascii_digit() && !tag.is_empty() => tag.push(ch),
+                _ => return None,
+            }
+        }
+        *chars = lookahead;
+        if tag.is_empty() {
+            Some(None)
+        } else {
+            Some(Some(tag))
+        }
+    }
+
+    /// Read the contents of a dollar-quoted string, after the opening tag
+    /// was consumed, up to and including the matching closing tag.
+    fn tokenize_dollar_quoted_string(
         &self,
-        chars: &mut Peekable<Chars<'_>>,
-    ) -> Result<Option<Token>, TokenizerError> {
-        let mut s = String::new();
-        let mut maybe_closing_comment = false;
-        // TODO: deal with nested comments
+        tag: Option<String>,
+        chars: &mut State<'_>,
+    ) -> Result<Option<Token<'a>>, TokenizerError> {
+        let closing_tag = format!("${}$", tag.as_deref().unwrap_or(""));
+        let mut value = String::new();
         loop {
             match chars.next() {
                 Some(ch) => {
-                    if maybe_closing_comment {
-                        if ch == '/' {
-                            break Ok(Some(Token::Whitespace(Whitespace::MultiLineComment(s))));
-                        } else {
-                            s.push('*');
-                        }
+                    value.push(ch);
+                    if value.ends_with(&closing_tag) {
+                        value.truncate(value.len() - closing_tag.len());
+                        break Ok(Some(Token::DollarQuotedString { tag, value }));
                     }
-                    maybe_closing_comment = ch == '*';
-                    if !maybe_closing_comment {
-                        s.push(ch);
+                }
+                None => {
+                    break Err(TokenizerError(format!(
+                        "Unexpected EOF while in a dollar-quoted string, expected {}",
+                        closing_tag
+                    )));
+                }
+            }
+        }
+    }
+
+    fn tokenize_multiline_comment(
+        &self,
+        chars: &mut State<'_>,
+    ) -> Result<Option<Token<'a>>, TokenizerError> {
+        let mut s = String::new();
+        let supports_nesting = self.dialect.supports_nested_comments();
+        let mut nesting_level = 1;
+        loop {
+            match chars.next() {
+                Some('/') if supports_nesting && chars.peek() == Some(&'*') => {
+                    chars.next(); // consume the '*'
+                    s.push_str("/*");
+                    nesting_level += 1;
+                }
+                Some('*') if chars.peek() == Some(&'/') => {
+                    chars.next(); // consume the '/'
+                    nesting_level -= 1;
+                    if nesting_level == 0 {
+                        break Ok(Some(Token::Whitespace(Whitespace::MultiLineComment(s))));
                     }
+                    s.push_str("*/");
                 }
+                Some(ch) => s.push(ch),
                 None => {
                     break Err(TokenizerError(
                         "Unexpected EOF while in a multi-line comment".to_string(),
@@ -511,9 +777,9 @@ impl<'a> Tokenizer<'a> {
 
     fn consume_and_return(
         &self,
-        chars: &mut Peekable<Chars<'_>>,
-        t: Token,
-    ) -> Result<Option<Token>, TokenizerError> {
+        chars: &mut State<'a>,
+        t: Token<'a>,
+    ) -> Result<Option<Token<'a>>, TokenizerError> {
         chars.next();
         Ok(Some(t))
     }
@@ -521,7 +787,8 @@ impl<'a> Tokenizer<'a> {
 
 #[cfg(test)]
 mod tests {
-    use super::super::dialect::GenericSqlDialect;
+    use super::super::dialect::{GenericSqlDialect, MsSqlDialect, PostgreSqlDialect};
+    use super::keywords::ALL_KEYWORDS;
     use super::*;
 
     #[test]
@@ -619,6 +886,96 @@ mod tests {
         compare(expected, tokens);
     }
 
+    #[test]
+    fn tokenize_regex_match_operators() {
+        let sql = String::from("a ~ b ~* c !~ d !~* e");
+        let dialect = GenericSqlDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, &sql);
+        let tokens = tokenizer.tokenize().unwrap();
+
+        let expected = vec![
+            Token::make_word("a", None),
+            Token::Whitespace(Whitespace::Space),
+            Token::Tilde,
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("b", None),
+            Token::Whitespace(Whitespace::Space),
+            Token::TildeAsterisk,
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("c", None),
+            Token::Whitespace(Whitespace::Space),
+            Token::ExclamationMarkTilde,
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("d", None),
+            Token::Whitespace(Whitespace::Space),
+            Token::ExclamationMarkTildeAsterisk,
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("e", None),
+        ];
+
+        compare(expected, tokens);
+    }
+
+    #[test]
+    fn tokenize_bitwise_operators() {
+        let sql = String::from("a&b|c||d#e<<f>>g<=h>=i<>j");
+        let dialect = PostgreSqlDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, &sql);
+        let tokens = tokenizer.tokenize().unwrap();
+
+        let expected = vec![
+            Token::make_word("a", None),
+            Token::Ampersand,
+            Token::make_word("b", None),
+            Token::Pipe,
+            Token::make_word("c", None),
+            Token::DoublePipe,
+            Token::make_word("d", None),
+            Token::Sharp,
+            Token::make_word("e", None),
+            Token::ShiftLeft,
+            Token::make_word("f", None),
+            Token::ShiftRight,
+            Token::make_word("g", None),
+            Token::LtEq,
+            Token::make_word("h", None),
+            Token::GtEq,
+            Token::make_word("i", None),
+            Token::Neq,
+            Token::make_word("j", None),
+        ];
+
+        compare(expected, tokens);
+    }
+
+    #[test]
+    fn tokenize_keywords() {
+        // `is_keyword` and `lookup_keyword` rely on binary search
+        assert!(ALL_KEYWORDS.windows(2).all(|w| w[0] < w[1]));
+
+        for keyword in ALL_KEYWORDS {
+            for word in &[keyword.to_string(), keyword.to_lowercase()] {
+                match Token::make_word(word, None) {
+                    Token::SQLWord(w) => assert_eq!(*keyword, w.keyword),
+                    _ => unreachable!(),
+                }
+            }
+        }
+
+        let sql = String::from("SeLeCt \"select\" selects");
+        let dialect = GenericSqlDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, &sql);
+        let tokens = tokenizer.tokenize().unwrap();
+        let keywords: Vec<_> = tokens
+            .iter()
+            .filter_map(|token| match token {
+                Token::SQLWord(w) => Some(w.keyword),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(vec!["SELECT", "", ""], keywords);
+    }
+
     #[test]
     fn tokenize_invalid_string() {
         let sql = String::from("\nمصطفىh");
@@ -748,6 +1105,67 @@ mod tests {
         compare(expected, tokens);
     }
 
+    #[test]
+    fn tokenize_nested_multiline_comment() {
+        let sql = String::from("0/* a /* b */ c */1");
+
+        let dialect = GenericSqlDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, &sql);
+        let tokens = tokenizer.tokenize().unwrap();
+        let expected = vec![
+            Token::Number("0".to_string()),
+            Token::Whitespace(Whitespace::MultiLineComment(" a /* b */ c ".to_string())),
+            Token::Number("1".to_string()),
+        ];
+        compare(expected, tokens);
+
+        // without nesting, the first `*/` ends the comment
+        let dialect = MsSqlDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, &sql);
+        let tokens = tokenizer.tokenize().unwrap();
+        assert_eq!(
+            Token::Whitespace(Whitespace::MultiLineComment(" a /* b ".to_string())),
+            tokens[1]
+        );
+    }
+
+    #[test]
+    fn tokenize_unterminated_nested_multiline_comment() {
+        let sql = String::from("/* a /* b */");
+
+        let dialect = GenericSqlDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, &sql);
+        assert_eq!(
+            Err(TokenizerError(
+                "Unexpected EOF while in a multi-line comment".to_string()
+            )),
+            tokenizer.tokenize()
+        );
+    }
+
+    #[test]
+    fn tokenize_with_location() {
+        let sql = String::from("SELECT a,\r\n  'b'\nFROM\t[c]");
+
+        let dialect = MsSqlDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, &sql);
+        let tokens = tokenizer.tokenize_with_location().unwrap();
+        let locations: Vec<(Token, u64, u64)> = tokens
+            .into_iter()
+            .filter(|t| !matches!(t.token, Token::Whitespace(_)))
+            .map(|t| (t.token, t.location.line, t.location.column))
+            .collect();
+        let expected = vec![
+            (Token::make_keyword("SELECT"), 1, 1),
+            (Token::make_word("a", None), 1, 8),
+            (Token::Comma, 1, 9),
+            (Token::SingleQuotedString("b".to_string()), 2, 3),
+            (Token::make_keyword("FROM"), 3, 1),
+            (Token::make_word("c", Some('[')), 3, 6),
+        ];
+        assert_eq!(expected, locations);
+    }
+
     #[test]
     fn tokenize_newlines() {
         let sql = String::from("line1\nline2\rline3\r\nline4\r");
@@ -768,6 +1186,141 @@ mod tests {
         compare(expected, tokens);
     }
 
+    #[test]
+    fn tokenize_delimited_identifier_with_escaped_quote() {
+        let sql = String::from("[a]]b] [c]");
+
+        let dialect = MsSqlDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, &sql);
+        let tokens = tokenizer.tokenize().unwrap();
+        let expected = vec![
+            Token::make_word("a]b", Some('[')),
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("c", Some('[')),
+        ];
+        assert_eq!("[a]]b]", tokens[0].to_string());
+        compare(expected, tokens);
+
+        let sql = String::from(r#""a""b""#);
+        let dialect = GenericSqlDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, &sql);
+        let tokens = tokenizer.tokenize().unwrap();
+        compare(vec![Token::make_word(r#"a"b"#, Some('"'))], tokens);
+    }
+
+    #[test]
+    fn tokenize_dollar_quoted_string() {
+        let sql = String::from("$$a'b$$ $tag$x $$y$$ $z$tag$ $1");
+
+        let dialect = GenericSqlDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, &sql);
+        let tokens = tokenizer.tokenize().unwrap();
+        let expected = vec![
+            Token::DollarQuotedString {
+                tag: None,
+                value: "a'b".to_string(),
+            },
+            Token::Whitespace(Whitespace::Space),
+            Token::DollarQuotedString {
+                tag: Some("tag".to_string()),
+                value: "x $$y$$ $z".to_string(),
+            },
+            Token::Whitespace(Whitespace::Space),
+            Token::Placeholder("$1".to_string()),
+        ];
+        compare(expected, tokens);
+    }
+
+    #[test]
+    fn tokenize_unterminated_dollar_quoted_string() {
+        let sql = String::from("$tag$x$$");
+
+        let dialect = GenericSqlDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, &sql);
+        assert_eq!(
+            Err(TokenizerError(
+                "Unexpected EOF while in a dollar-quoted string, expected $tag$".to_string()
+            )),
+            tokenizer.tokenize()
+        );
+    }
+
+    #[test]
+    fn tokenize_placeholders() {
+        let sql = String::from("? $1 $23 :name ::");
+
+        let dialect = GenericSqlDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, &sql);
+        let tokens = tokenizer.tokenize().unwrap();
+        let expected = vec![
+            Token::Placeholder("?".to_string()),
+            Token::Whitespace(Whitespace::Space),
+            Token::Placeholder("$1".to_string()),
+            Token::Whitespace(Whitespace::Space),
+            Token::Placeholder("$23".to_string()),
+            Token::Whitespace(Whitespace::Space),
+            Token::Placeholder(":name".to_string()),
+            Token::Whitespace(Whitespace::Space),
+            Token::DoubleColon,
+        ];
+        compare(expected, tokens);
+    }
+
+    #[test]
+    fn tokenize_words_borrow_from_input() {
+        let sql = String::from("SELECT a, \"b\", \"c\"\"d\"");
+
+        let dialect = GenericSqlDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, &sql);
+        let words: Vec<_> = tokenizer
+            .tokenize()
+            .unwrap()
+            .into_iter()
+            .filter_map(|token| match token {
+                Token::SQLWord(w) => Some(w.value),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            vec![
+                Cow::Borrowed("SELECT"),
+                Cow::Borrowed("a"),
+                Cow::Borrowed("b"),
+                Cow::Owned::<str>("c\"d".to_string()),
+            ],
+            words
+        );
+        // Only the identifier with an escaped quote needs a copy
+        match (&words[0], &words[1], &words[2], &words[3]) {
+            (Cow::Borrowed(_), Cow::Borrowed(_), Cow::Borrowed(_), Cow::Owned(_)) => {}
+            _ => panic!("unexpected words {:?}", words),
+        }
+    }
+
+    #[test]
+    fn tokenize_non_ascii_keyword() {
+        #[derive(Debug)]
+        struct UnicodeDialect {}
+        impl Dialect for UnicodeDialect {
+            fn is_identifier_start(&self, ch: char) -> bool {
+                ch.is_alphabetic()
+            }
+            fn is_identifier_part(&self, ch: char) -> bool {
+                ch.is_alphanumeric()
+            }
+        }
+
+        // `ı` (dotless i) uppercases to `I`
+        let sql = String::from("ınsert");
+        let dialect = UnicodeDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, &sql);
+        let tokens = tokenizer.tokenize().unwrap();
+        match &tokens[..] {
+            [Token::SQLWord(w)] => assert_eq!("INSERT", w.keyword),
+            _ => panic!("unexpected tokens {:?}", tokens),
+        }
+    }
+
     fn compare(expected: Vec<Token>, actual: Vec<Token>) {
         //println!("------------------------------");
         //println!("tokens   = {:?}", actual);
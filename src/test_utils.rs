@@ -40,8 +40,8 @@ impl TestedDialects {
     {
         self.one_of_identical_results(|dialect| {
             let mut tokenizer = Tokenizer::new(dialect, sql);
-            let tokens = tokenizer.tokenize().unwrap();
-            f(&mut Parser::new(tokens))
+            let tokens = tokenizer.tokenize_with_location().unwrap();
+            f(&mut Parser::new_with_locations(tokens, dialect))
         })
     }
 
@@ -92,10 +92,46 @@ impl TestedDialects {
     /// Ensures that `sql` parses as an expression, and is not modified
     /// after a serialization round-trip.
     pub fn verified_expr(&self, sql: &str) -> ASTNode {
-        let ast = self.run_parser_method(sql, Parser::parse_expr).unwrap();
+        let ast = self
+            .run_parser_method(sql, |parser| parser.parse_expr())
+            .unwrap();
         assert_eq!(sql, &ast.to_string(), "round-tripping without changes");
         ast
     }
+
+    /// Ensures that each statement in `corpus` parses as a single statement
+    /// in all of `self.dialects`, and is not modified after a serialization
+    /// round-trip. Every statement is checked before failing, so that the
+    /// panic message lists all the inputs that did not round-trip.
+    pub fn verify_round_trips(&self, corpus: &[&str]) {
+        let mut failures = vec![];
+        for sql in corpus {
+            for dialect in &self.dialects {
+                let failure = match Parser::parse_sql(&**dialect, sql.to_string()) {
+                    Ok(ref statements) if statements.len() != 1 => {
+                        format!("parsed as {} statements", statements.len())
+                    }
+                    Ok(statements) => {
+                        let serialized = statements[0].to_string();
+                        if serialized == *sql {
+                            continue;
+                        }
+                        format!("serialized as: {}", serialized)
+                    }
+                    Err(e) => format!("failed to parse: {:?}", e),
+                };
+                failures.push(format!("{}\n  with {:?} {}", sql, dialect, failure));
+            }
+        }
+        if !failures.is_empty() {
+            panic!(
+                "{} failures in {} statements:\n{}",
+                failures.len(),
+                corpus.len(),
+                failures.join("\n")
+            );
+        }
+    }
 }
 
 pub fn all_dialects() -> TestedDialects {
@@ -14,16 +14,16 @@ use sqlparser::test_utils::{all_dialects, expr_from_projection, only};
 
 #[test]
 fn parse_insert_values() {
-    let sql = "INSERT INTO customer VALUES(1, 2, 3)";
+    let sql = "INSERT INTO customer VALUES (1, 2, 3)";
     check_one(sql, "customer", vec![]);
 
-    let sql = "INSERT INTO public.customer VALUES(1, 2, 3)";
+    let sql = "INSERT INTO public.customer VALUES (1, 2, 3)";
     check_one(sql, "public.customer", vec![]);
 
-    let sql = "INSERT INTO db.public.customer VALUES(1, 2, 3)";
+    let sql = "INSERT INTO db.public.customer VALUES (1, 2, 3)";
     check_one(sql, "db.public.customer", vec![]);
 
-    let sql = "INSERT INTO public.customer (id, name, active) VALUES(1, 2, 3)";
+    let sql = "INSERT INTO public.customer (id, name, active) VALUES (1, 2, 3)";
     check_one(
         sql,
         "public.customer",
@@ -35,19 +35,22 @@ fn parse_insert_values() {
             SQLStatement::SQLInsert {
                 table_name,
                 columns,
-                values,
+                source,
                 ..
             } => {
                 assert_eq!(table_name.to_string(), expected_table_name);
                 assert_eq!(columns, expected_columns);
-                assert_eq!(
-                    vec![vec![
-                        ASTNode::SQLValue(Value::Long(1)),
-                        ASTNode::SQLValue(Value::Long(2)),
-                        ASTNode::SQLValue(Value::Long(3))
-                    ]],
-                    values
-                );
+                match &source.body {
+                    SQLSetExpr::Values(SQLValues(values)) => assert_eq!(
+                        &vec![vec![
+                            ASTNode::SQLValue(Value::Long(1)),
+                            ASTNode::SQLValue(Value::Long(2)),
+                            ASTNode::SQLValue(Value::Long(3))
+                        ]],
+                        values
+                    ),
+                    _ => unreachable!(),
+                }
             }
             _ => unreachable!(),
         }
@@ -59,23 +62,191 @@ fn parse_insert_invalid() {
     let sql = "INSERT public.customer (id, name, active) VALUES (1, 2, 3)";
     let res = parse_sql_statements(sql);
     assert_eq!(
-        ParserError::ParserError("Expected INTO, found: public".to_string()),
+        ParserError::ParserError("Expected INTO, found: public at line 1, column 8".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_insert_multiple_rows() {
+    let sql = "INSERT INTO t VALUES (1, 'a'), (2, 'b')";
+    match verified_stmt(sql) {
+        SQLStatement::SQLInsert { source, .. } => match source.body {
+            SQLSetExpr::Values(SQLValues(rows)) => assert_eq!(2, rows.len()),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_insert_select() {
+    let sql = "INSERT INTO t (a, b) SELECT a, b FROM u WHERE c = 1";
+    match verified_stmt(sql) {
+        SQLStatement::SQLInsert {
+            columns, source, ..
+        } => {
+            assert_eq!(vec!["a".to_string(), "b".to_string()], columns);
+            assert_eq!("SELECT a, b FROM u WHERE c = 1", source.to_string());
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_values() {
+    let query = verified_query("VALUES (1, 'a'), (2, 'b')");
+    assert_eq!(
+        SQLSetExpr::Values(SQLValues(vec![
+            vec![
+                ASTNode::SQLValue(Value::Long(1)),
+                ASTNode::SQLValue(Value::SingleQuotedString("a".to_string())),
+            ],
+            vec![
+                ASTNode::SQLValue(Value::Long(2)),
+                ASTNode::SQLValue(Value::SingleQuotedString("b".to_string())),
+            ],
+        ])),
+        query.body
+    );
+
+    verified_stmt("VALUES (1) UNION SELECT 2");
+    verified_stmt("SELECT * FROM (VALUES (1), (2)) AS v (x)");
+
+    let stmt = one_statement_parses_to(
+        "SELECT * FROM (VALUES (1, 'a'), (2, 'b')) t(a, b)",
+        "SELECT * FROM (VALUES (1, 'a'), (2, 'b')) AS t (a, b)",
+    );
+    let relation = match stmt {
+        SQLStatement::SQLQuery(query) => match query.body {
+            SQLSetExpr::Select(select) => select.relation,
+            _ => panic!("Expected a SELECT"),
+        },
+        _ => panic!("Expected a query"),
+    };
+    match relation {
+        Some(TableFactor::Derived {
+            subquery, alias, ..
+        }) => {
+            assert_eq!(query.body, subquery.body);
+            assert_eq!(
+                Some(TableAlias {
+                    name: "t".to_string(),
+                    columns: vec!["a".to_string(), "b".to_string()],
+                }),
+                alias
+            );
+        }
+        _ => panic!("Expected a derived table"),
+    }
+    one_statement_parses_to(
+        "SELECT * FROM (VALUES (1), (2)) AS v(x)",
+        "SELECT * FROM (VALUES (1), (2)) AS v (x)",
+    );
+
+    let res = parse_sql_statements("VALUES 1");
+    assert_eq!(
+        ParserError::ParserError("Expected (, found: 1 at line 1, column 8".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_table_query_body() {
+    let query = verified_query("TABLE db.foo");
+    assert_eq!(
+        SQLSetExpr::Table(SQLObjectName(vec!["db".to_string(), "foo".to_string()])),
+        query.body
+    );
+
+    let query = verified_query("TABLE a UNION TABLE b ORDER BY 1 LIMIT 10");
+    match query.body {
+        SQLSetExpr::SetOperation { left, right, .. } => {
+            assert_eq!("TABLE a", left.to_string());
+            assert_eq!("TABLE b", right.to_string());
+        }
+        _ => unreachable!(),
+    }
+    assert_eq!(1, query.order_by.len());
+    assert_eq!(Some(ASTNode::SQLValue(Value::Long(10))), query.limit);
+
+    verified_stmt("SELECT * FROM (TABLE foo) AS f");
+    verified_stmt("INSERT INTO t TABLE foo");
+
+    let res = parse_sql_statements("TABLE");
+    assert_eq!(
+        ParserError::ParserError("Expected identifier, found: EOF".to_string()),
         res.unwrap_err()
     );
 }
 
+#[test]
+fn parse_derived_table_alias_columns() {
+    let select = verified_only_select("SELECT * FROM (SELECT 1, 2) AS t (a, b)");
+    match select.relation {
+        Some(TableFactor::Derived { alias, .. }) => assert_eq!(
+            Some(TableAlias {
+                name: "t".to_string(),
+                columns: vec!["a".to_string(), "b".to_string()],
+            }),
+            alias
+        ),
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_invalid_table_name() {
-    let ast = all_dialects().run_parser_method("db.public..customer", Parser::parse_object_name);
+    let ast = all_dialects()
+        .run_parser_method("db.public..customer", |parser| parser.parse_object_name());
     assert!(ast.is_err());
 }
 
 #[test]
 fn parse_no_table_name() {
-    let ast = all_dialects().run_parser_method("", Parser::parse_object_name);
+    let ast = all_dialects().run_parser_method("", |parser| parser.parse_object_name());
     assert!(ast.is_err());
 }
 
+#[test]
+fn parse_update() {
+    let sql = "UPDATE t SET a = 1, b = 'x' WHERE c > 0";
+    match verified_stmt(sql) {
+        SQLStatement::SQLUpdate {
+            table_name,
+            assignments,
+            selection,
+            returning,
+        } => {
+            assert_eq!("t", table_name.to_string());
+            assert_eq!(
+                vec![
+                    SQLAssignment {
+                        id: "a".to_string(),
+                        value: ASTNode::SQLValue(Value::Long(1)),
+                    },
+                    SQLAssignment {
+                        id: "b".to_string(),
+                        value: ASTNode::SQLValue(Value::SingleQuotedString("x".to_string())),
+                    },
+                ],
+                assignments
+            );
+            assert_eq!(Some(verified_expr("c > 0")), selection);
+            assert_eq!(None, returning);
+        }
+        _ => unreachable!(),
+    }
+
+    verified_stmt("UPDATE t SET a = a + 1");
+
+    let res = parse_sql_statements("UPDATE t WHERE a = 1");
+    assert_eq!(
+        ParserError::ParserError("Expected SET, found: WHERE at line 1, column 10".to_string()),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_delete_statement() {
     let sql = "DELETE FROM \"table\"";
@@ -134,6 +305,91 @@ fn parse_select_with_limit_but_no_where() {
     assert_eq!(Some(ASTNode::SQLValue(Value::Long(5))), select.limit);
 }
 
+#[test]
+fn parse_select_without_from() {
+    let select = verified_only_select("SELECT 1 + 1");
+    assert_eq!(None, select.relation);
+    assert!(select.joins.is_empty());
+    assert_eq!(
+        &ASTNode::SQLBinaryExpr {
+            left: Box::new(ASTNode::SQLValue(Value::Long(1))),
+            op: SQLOperator::Plus,
+            right: Box::new(ASTNode::SQLValue(Value::Long(1))),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    let select = verified_only_select("SELECT 1 AS a, 2 AS b");
+    assert_eq!(None, select.relation);
+    assert_eq!(None, select.selection);
+    assert_eq!(
+        vec![
+            SQLSelectItem::ExpressionWithAlias {
+                expr: ASTNode::SQLValue(Value::Long(1)),
+                alias: "a".to_string(),
+            },
+            SQLSelectItem::ExpressionWithAlias {
+                expr: ASTNode::SQLValue(Value::Long(2)),
+                alias: "b".to_string(),
+            },
+        ],
+        select.projection
+    );
+}
+
+#[test]
+fn parse_select_empty_projection() {
+    let res = parse_sql_statements("SELECT FROM t");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected an expression, found: FROM at line 1, column 8".to_string()
+        ),
+        res.unwrap_err()
+    );
+
+    let res = parse_sql_statements("SELECT a, FROM t");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected an expression, found: FROM at line 1, column 11".to_string()
+        ),
+        res.unwrap_err()
+    );
+
+    let res = parse_sql_statements("SELECT");
+    assert_eq!(
+        ParserError::ParserError("Expected an expression, found: EOF".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_comments() {
+    one_statement_parses_to("SELECT 1 -- note\nFROM t", "SELECT 1 FROM t");
+    one_statement_parses_to(
+        "SELECT a /* first */, b /* multi-line\ncomment */ FROM t WHERE a = 1 -- at the end",
+        "SELECT a, b FROM t WHERE a = 1",
+    );
+    let statements = parse_sql_statements("SELECT 1; -- trailing comment\n/* another */").unwrap();
+    assert_eq!(1, statements.len());
+}
+
+#[test]
+fn parse_select_from_dual() {
+    let select = verified_only_select("SELECT 1 FROM DUAL");
+    assert_eq!(
+        Some(TableFactor::Table {
+            lateral: false,
+            name: SQLObjectName(vec!["DUAL".to_string()]),
+            partitions: vec![],
+            alias: None,
+            args: None,
+            sample: None,
+            with_hints: vec![],
+        }),
+        select.relation
+    );
+}
+
 #[test]
 fn parse_select_distinct() {
     let sql = "SELECT DISTINCT name FROM customer";
@@ -154,7 +410,9 @@ fn parse_select_all() {
 fn parse_select_all_distinct() {
     let result = parse_sql_statements("SELECT ALL DISTINCT name FROM customer");
     assert_eq!(
-        ParserError::ParserError("Cannot specify both ALL and DISTINCT in SELECT".to_string()),
+        ParserError::ParserError(
+            "Cannot specify both ALL and DISTINCT in SELECT at line 1, column 12".to_string()
+        ),
         result.unwrap_err(),
     );
 }
@@ -220,6 +478,8 @@ fn parse_select_count_wildcard() {
         &ASTNode::SQLFunction {
             name: SQLObjectName(vec!["COUNT".to_string()]),
             args: vec![ASTNode::SQLWildcard],
+            order_by: vec![],
+            within_group: vec![],
             over: None,
             distinct: false,
         },
@@ -238,6 +498,8 @@ fn parse_select_count_distinct() {
                 operator: SQLOperator::Plus,
                 expr: Box::new(ASTNode::SQLIdentifier("x".to_string()))
             }],
+            order_by: vec![],
+            within_group: vec![],
             over: None,
             distinct: true,
         },
@@ -253,12 +515,32 @@ fn parse_select_count_distinct() {
     let res = parse_sql_statements(sql);
     assert_eq!(
         ParserError::ParserError(
-            "Cannot specify both ALL and DISTINCT in function: COUNT".to_string()
+            "Cannot specify both ALL and DISTINCT in function: COUNT at line 1, column 18"
+                .to_string()
         ),
         res.unwrap_err()
     );
 }
 
+#[test]
+fn parse_aggregate_all_distinct() {
+    for (sql, name, column) in &[
+        ("SELECT SUM(ALL DISTINCT x) FROM customer", "SUM", 16),
+        ("SELECT AVG(ALL DISTINCT x) FROM customer", "AVG", 16),
+        ("SELECT MAX(DISTINCT ALL x) FROM customer", "MAX", 21),
+        ("SELECT db.agg(ALL DISTINCT x) FROM customer", "db.agg", 19),
+    ] {
+        let res = parse_sql_statements(sql);
+        assert_eq!(
+            ParserError::ParserError(format!(
+                "Cannot specify both ALL and DISTINCT in function: {} at line 1, column {}",
+                name, column
+            )),
+            res.unwrap_err()
+        );
+    }
+}
+
 #[test]
 fn parse_not() {
     let sql = "SELECT id FROM customer WHERE NOT salary = ''";
@@ -266,11 +548,24 @@ fn parse_not() {
     //TODO: add assertions
 }
 
+#[test]
+fn parse_error_location() {
+    let res = parse_sql_statements("SELECT foo\nFROM bar\nWHERE a NOT (1)");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected IN or BETWEEN after NOT, found: ( at line 3, column 13".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_invalid_infix_not() {
     let res = parse_sql_statements("SELECT c FROM t WHERE c NOT (");
     assert_eq!(
-        ParserError::ParserError("Expected IN or BETWEEN after NOT, found: (".to_string()),
+        ParserError::ParserError(
+            "Expected IN or BETWEEN after NOT, found: ( at line 1, column 29".to_string()
+        ),
         res.unwrap_err(),
     );
 }
@@ -284,6 +579,25 @@ fn parse_collate() {
     );
 }
 
+#[test]
+fn parse_collate_in_comparison() {
+    let sql = "SELECT * FROM customer WHERE name COLLATE \"C\" = 'x'";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        ASTNode::SQLBinaryExpr {
+            left: Box::new(ASTNode::SQLCollate {
+                expr: Box::new(ASTNode::SQLIdentifier("name".to_string())),
+                collation: SQLObjectName(vec!["\"C\"".to_string()]),
+            }),
+            op: SQLOperator::Eq,
+            right: Box::new(ASTNode::SQLValue(Value::SingleQuotedString(
+                "x".to_string()
+            ))),
+        },
+        select.selection.unwrap()
+    );
+}
+
 #[test]
 fn parse_select_string_predicate() {
     let sql = "SELECT id, fname, lname FROM customer \
@@ -356,6 +670,39 @@ fn parse_compound_expr_2() {
     );
 }
 
+#[test]
+fn parse_compound_expr_modulus() {
+    use self::ASTNode::*;
+    use self::SQLOperator::*;
+    let sql = "a + b % c";
+    assert_eq!(
+        SQLBinaryExpr {
+            left: Box::new(SQLIdentifier("a".to_string())),
+            op: Plus,
+            right: Box::new(SQLBinaryExpr {
+                left: Box::new(SQLIdentifier("b".to_string())),
+                op: Modulus,
+                right: Box::new(SQLIdentifier("c".to_string()))
+            })
+        },
+        verified_expr(sql)
+    );
+
+    let sql = "a % b * c";
+    assert_eq!(
+        SQLBinaryExpr {
+            left: Box::new(SQLBinaryExpr {
+                left: Box::new(SQLIdentifier("a".to_string())),
+                op: Modulus,
+                right: Box::new(SQLIdentifier("b".to_string()))
+            }),
+            op: Multiply,
+            right: Box::new(SQLIdentifier("c".to_string()))
+        },
+        verified_expr(sql)
+    );
+}
+
 #[test]
 fn parse_unary_math() {
     use self::ASTNode::*;
@@ -397,6 +744,108 @@ fn parse_is_not_null() {
     );
 }
 
+#[test]
+fn parse_is_normalized() {
+    use self::ASTNode::*;
+    let sql = "a IS NORMALIZED";
+    assert_eq!(
+        SQLIsNormalized {
+            expr: Box::new(SQLIdentifier("a".to_string())),
+            form: None,
+            negated: false,
+        },
+        verified_expr(sql)
+    );
+
+    let sql = "a IS NFKC NORMALIZED";
+    assert_eq!(
+        SQLIsNormalized {
+            expr: Box::new(SQLIdentifier("a".to_string())),
+            form: Some(SQLNormalForm::NFKC),
+            negated: false,
+        },
+        verified_expr(sql)
+    );
+
+    let sql = "a IS NOT NFD NORMALIZED";
+    assert_eq!(
+        SQLIsNormalized {
+            expr: Box::new(SQLIdentifier("a".to_string())),
+            form: Some(SQLNormalForm::NFD),
+            negated: true,
+        },
+        verified_expr(sql)
+    );
+
+    verified_expr("a IS NOT NORMALIZED");
+
+    let res = parse_sql_statements("SELECT a IS NFC FROM t");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected NORMALIZED, found: FROM at line 1, column 17".to_string()
+        ),
+        res.unwrap_err()
+    );
+
+    let res = parse_sql_statements("SELECT a IS TRUE FROM t");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected NULL, NOT NULL, DISTINCT FROM or NORMALIZED after IS, found: TRUE \
+             at line 1, column 13"
+                .to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_is_distinct_from() {
+    use self::ASTNode::*;
+    let sql = "a IS DISTINCT FROM b";
+    assert_eq!(
+        SQLBinaryExpr {
+            left: Box::new(SQLIdentifier("a".to_string())),
+            op: SQLOperator::IsDistinctFrom,
+            right: Box::new(SQLIdentifier("b".to_string())),
+        },
+        verified_expr(sql)
+    );
+
+    let sql = "a IS NOT DISTINCT FROM b + 1 AND c IS NULL";
+    assert_eq!(
+        SQLBinaryExpr {
+            left: Box::new(SQLBinaryExpr {
+                left: Box::new(SQLIdentifier("a".to_string())),
+                op: SQLOperator::IsNotDistinctFrom,
+                right: Box::new(SQLBinaryExpr {
+                    left: Box::new(SQLIdentifier("b".to_string())),
+                    op: SQLOperator::Plus,
+                    right: Box::new(SQLValue(Value::Long(1))),
+                }),
+            }),
+            op: SQLOperator::And,
+            right: Box::new(SQLIsNull(Box::new(SQLIdentifier("c".to_string())))),
+        },
+        verified_expr(sql)
+    );
+
+    // the token following IS (and NOT) tells the predicates apart
+    assert_eq!(
+        SQLIsNotNull(Box::new(SQLIdentifier("a".to_string()))),
+        verified_expr("a IS NOT NULL")
+    );
+
+    let res = parse_sql_statements("SELECT a IS DISTINCT b");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected NULL, NOT NULL, DISTINCT FROM or NORMALIZED after IS, found: DISTINCT \
+             at line 1, column 13"
+                .to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_not_precedence() {
     use self::ASTNode::*;
@@ -448,13 +897,66 @@ fn parse_not_like() {
 }
 
 #[test]
-fn parse_in_list() {
-    fn chk(negated: bool) {
-        let sql = &format!(
-            "SELECT * FROM customers WHERE segment {}IN ('HIGH', 'MED')",
-            if negated { "NOT " } else { "" }
-        );
-        let select = verified_only_select(sql);
+fn parse_like_escape() {
+    let sql = "SELECT * FROM customers WHERE name LIKE '%a!%' ESCAPE '!'";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        ASTNode::SQLLike {
+            expr: Box::new(ASTNode::SQLIdentifier("name".to_string())),
+            op: SQLOperator::Like,
+            pattern: Box::new(ASTNode::SQLValue(Value::SingleQuotedString(
+                "%a!%".to_string()
+            ))),
+            escape_char: '!',
+        },
+        select.selection.unwrap()
+    );
+
+    let sql = "SELECT * FROM customers WHERE name NOT LIKE '%a''%' ESCAPE '''' AND id = 1";
+    let select = verified_only_select(sql);
+    match select.selection.unwrap() {
+        ASTNode::SQLBinaryExpr {
+            left,
+            op: SQLOperator::And,
+            ..
+        } => match *left {
+            ASTNode::SQLLike {
+                op, escape_char, ..
+            } => {
+                assert_eq!(SQLOperator::NotLike, op);
+                assert_eq!('\'', escape_char);
+            }
+            _ => panic!("Expected LIKE ... ESCAPE"),
+        },
+        _ => panic!("Expected AND"),
+    }
+
+    let res = parse_sql_statements("SELECT * FROM customers WHERE name = 'a' ESCAPE '!'");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected end of statement, found: ESCAPE at line 1, column 42".to_string()
+        ),
+        res.unwrap_err()
+    );
+
+    let res = parse_sql_statements("SELECT * FROM customers WHERE name LIKE 'a' ESCAPE '!!'");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected a single character string after ESCAPE, found: '!!' at line 1, column 52"
+                .to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_in_list() {
+    fn chk(negated: bool) {
+        let sql = &format!(
+            "SELECT * FROM customers WHERE segment {}IN ('HIGH', 'MED')",
+            if negated { "NOT " } else { "" }
+        );
+        let select = verified_only_select(sql);
         assert_eq!(
             ASTNode::SQLInList {
                 expr: Box::new(ASTNode::SQLIdentifier("segment".to_string())),
@@ -565,14 +1067,17 @@ fn parse_select_order_by() {
                 SQLOrderByExpr {
                     expr: ASTNode::SQLIdentifier("lname".to_string()),
                     asc: Some(true),
+                    nulls_first: None,
                 },
                 SQLOrderByExpr {
                     expr: ASTNode::SQLIdentifier("fname".to_string()),
                     asc: Some(false),
+                    nulls_first: None,
                 },
                 SQLOrderByExpr {
                     expr: ASTNode::SQLIdentifier("id".to_string()),
                     asc: None,
+                    nulls_first: None,
                 },
             ],
             select.order_by
@@ -584,6 +1089,61 @@ fn parse_select_order_by() {
     chk("SELECT 1 AS lname, 2 AS fname, 3 AS id, 4 ORDER BY lname ASC, fname DESC, id");
 }
 
+#[test]
+fn parse_order_by_nulls_first_last() {
+    let sql = "SELECT id FROM customer ORDER BY lname DESC NULLS LAST, fname NULLS FIRST, id ASC";
+    let query = verified_query(sql);
+    assert_eq!(
+        vec![
+            SQLOrderByExpr {
+                expr: ASTNode::SQLIdentifier("lname".to_string()),
+                asc: Some(false),
+                nulls_first: Some(false),
+            },
+            SQLOrderByExpr {
+                expr: ASTNode::SQLIdentifier("fname".to_string()),
+                asc: None,
+                nulls_first: Some(true),
+            },
+            SQLOrderByExpr {
+                expr: ASTNode::SQLIdentifier("id".to_string()),
+                asc: Some(true),
+                nulls_first: None,
+            },
+        ],
+        query.order_by
+    );
+
+    verified_query("SELECT * FROM t ORDER BY a ASC NULLS LAST, b DESC NULLS FIRST");
+
+    let sql = "SELECT row_number() OVER (ORDER BY x NULLS FIRST) FROM t";
+    let select = verified_only_select(sql);
+    match expr_from_projection(only(&select.projection)) {
+        ASTNode::SQLFunction {
+            over: Some(over), ..
+        } => match over.as_ref() {
+            SQLWindowType::WindowSpec(spec) => assert_eq!(
+                vec![SQLOrderByExpr {
+                    expr: ASTNode::SQLIdentifier("x".to_string()),
+                    asc: None,
+                    nulls_first: Some(true),
+                }],
+                spec.order_by
+            ),
+            _ => panic!("Expected a window spec"),
+        },
+        _ => panic!("Expected a window function"),
+    }
+
+    let res = parse_sql_statements("SELECT id FROM customer ORDER BY id NULLS");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected end of statement, found: NULLS at line 1, column 37".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_select_order_by_limit() {
     let sql = "SELECT id, fname, lname FROM customer WHERE id < 5 \
@@ -594,10 +1154,12 @@ fn parse_select_order_by_limit() {
             SQLOrderByExpr {
                 expr: ASTNode::SQLIdentifier("lname".to_string()),
                 asc: Some(true),
+                nulls_first: None,
             },
             SQLOrderByExpr {
                 expr: ASTNode::SQLIdentifier("fname".to_string()),
                 asc: Some(false),
+                nulls_first: None,
             },
         ],
         select.order_by
@@ -618,6 +1180,102 @@ fn parse_select_group_by() {
     );
 }
 
+#[test]
+fn parse_grouping_function() {
+    let sql = "SELECT a, GROUPING(a) FROM t GROUP BY ROLLUP (a)";
+    let canonical = "SELECT a, GROUPING(a) FROM t GROUP BY ROLLUP(a)";
+    let select = match one_statement_parses_to(sql, canonical) {
+        SQLStatement::SQLQuery(query) => match query.body {
+            SQLSetExpr::Select(select) => *select,
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        &ASTNode::SQLFunction {
+            name: SQLObjectName(vec!["GROUPING".to_string()]),
+            args: vec![ASTNode::SQLIdentifier("a".to_string())],
+            order_by: vec![],
+            within_group: vec![],
+            over: None,
+            distinct: false,
+        },
+        expr_from_projection(&select.projection[1])
+    );
+    assert_eq!(
+        vec![ASTNode::SQLRollup(vec![vec![ASTNode::SQLIdentifier(
+            "a".to_string()
+        )]])],
+        select.group_by
+    );
+
+    verified_only_select("SELECT a, b, GROUPING(a, b) FROM t GROUP BY CUBE(a, b)");
+}
+
+#[test]
+fn parse_group_by_grouping_sets() {
+    let a = || ASTNode::SQLIdentifier("a".to_string());
+    let b = || ASTNode::SQLIdentifier("b".to_string());
+    let c = || ASTNode::SQLIdentifier("c".to_string());
+
+    let sql = "SELECT a, b, sum(c) FROM t GROUP BY GROUPING SETS ((a), (a, b), ())";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        vec![ASTNode::SQLGroupingSets(vec![
+            vec![a()],
+            vec![a(), b()],
+            vec![],
+        ])],
+        select.group_by
+    );
+
+    let sql = "SELECT a, b, c FROM t GROUP BY a, CUBE(b, c)";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        vec![a(), ASTNode::SQLCube(vec![vec![b()], vec![c()]])],
+        select.group_by
+    );
+
+    let sql = "SELECT a, b, c FROM t GROUP BY ROLLUP(a, (b, c)), GROUPING SETS ((c))";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        vec![
+            ASTNode::SQLRollup(vec![vec![a()], vec![b(), c()]]),
+            ASTNode::SQLGroupingSets(vec![vec![c()]]),
+        ],
+        select.group_by
+    );
+
+    one_statement_parses_to(
+        "SELECT a FROM t GROUP BY GROUPING SETS (a, (b))",
+        "SELECT a FROM t GROUP BY GROUPING SETS ((a), (b))",
+    );
+
+    // a parenthesized expression can also be the operand of a single one
+    let select = verified_only_select("SELECT a FROM t GROUP BY ROLLUP((a + b) * c)");
+    assert_eq!(
+        vec![ASTNode::SQLRollup(vec![vec![ASTNode::SQLBinaryExpr {
+            left: Box::new(ASTNode::SQLNested(Box::new(ASTNode::SQLBinaryExpr {
+                left: Box::new(a()),
+                op: SQLOperator::Plus,
+                right: Box::new(b()),
+            }))),
+            op: SQLOperator::Multiply,
+            right: Box::new(c()),
+        }]])],
+        select.group_by
+    );
+    verified_only_select("SELECT a FROM t GROUP BY GROUPING SETS (((a + b) * c), (d))");
+
+    let res = parse_sql_statements("SELECT a FROM t GROUP BY CUBE(a, ())");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected an expression, found: ) at line 1, column 35".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_limit_accepts_all() {
     one_statement_parses_to(
@@ -626,6 +1284,130 @@ fn parse_limit_accepts_all() {
     );
 }
 
+#[test]
+fn parse_offset() {
+    let query = verified_query("SELECT foo FROM bar ORDER BY foo OFFSET 2 ROWS");
+    assert_eq!(
+        Some(Offset {
+            value: ASTNode::SQLValue(Value::Long(2)),
+            rows: true,
+        }),
+        query.offset
+    );
+    assert_eq!(None, query.fetch);
+    one_statement_parses_to(
+        "SELECT foo FROM bar OFFSET 1 ROW",
+        "SELECT foo FROM bar OFFSET 1 ROWS",
+    );
+
+    // `ROW` / `ROWS` is optional, as in `LIMIT <N> OFFSET <M>`
+    let query = verified_query("SELECT foo FROM bar LIMIT 5 OFFSET 2");
+    assert_eq!(
+        Some(Offset {
+            value: ASTNode::SQLValue(Value::Long(2)),
+            rows: false,
+        }),
+        query.offset
+    );
+}
+
+#[test]
+fn parse_fetch() {
+    let query = verified_query("SELECT foo FROM bar FETCH FIRST 2 ROWS ONLY");
+    assert_eq!(
+        Some(Fetch {
+            with_ties: false,
+            percent: false,
+            quantity: Some(ASTNode::SQLValue(Value::Long(2))),
+        }),
+        query.fetch
+    );
+    let query = verified_query("SELECT foo FROM bar FETCH FIRST ROWS ONLY");
+    assert_eq!(None, query.fetch.unwrap().quantity);
+    let query = verified_query("SELECT foo FROM bar OFFSET 2 ROWS FETCH FIRST 5 ROWS ONLY");
+    assert_eq!(
+        Some(ASTNode::SQLValue(Value::Long(2))),
+        query.offset.map(|offset| offset.value)
+    );
+    assert!(query.fetch.is_some());
+    one_statement_parses_to(
+        "SELECT foo FROM bar FETCH NEXT 1 ROW ONLY",
+        "SELECT foo FROM bar FETCH FIRST 1 ROWS ONLY",
+    );
+
+    let res = parse_sql_statements("SELECT foo FROM bar FETCH FIRST 2 ROWS");
+    assert_eq!(
+        ParserError::ParserError("Expected one of ONLY or WITH TIES, found: EOF".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_fetch_percent_with_ties() {
+    let query = verified_query("SELECT foo FROM bar FETCH FIRST 10 PERCENT ROWS WITH TIES");
+    assert_eq!(
+        Some(Fetch {
+            with_ties: true,
+            percent: true,
+            quantity: Some(ASTNode::SQLValue(Value::Long(10))),
+        }),
+        query.fetch
+    );
+    let query = verified_query("SELECT foo FROM bar FETCH FIRST 10 PERCENT ROWS ONLY");
+    let fetch = query.fetch.unwrap();
+    assert!(fetch.percent);
+    assert!(!fetch.with_ties);
+}
+
+#[test]
+fn parse_locking_clauses() {
+    let query = verified_query("SELECT * FROM t FOR UPDATE");
+    assert_eq!(
+        vec![LockClause {
+            lock_type: LockType::Update,
+            of: vec![],
+            nonblock: None,
+        }],
+        query.locks
+    );
+
+    let query = verified_query(
+        "SELECT * FROM t1, t2 LIMIT 1 FOR UPDATE OF t1, s.t2 NOWAIT FOR SHARE SKIP LOCKED",
+    );
+    assert_eq!(
+        vec![
+            LockClause {
+                lock_type: LockType::Update,
+                of: vec![
+                    SQLObjectName(vec!["t1".to_string()]),
+                    SQLObjectName(vec!["s".to_string(), "t2".to_string()]),
+                ],
+                nonblock: Some(NonBlock::Nowait),
+            },
+            LockClause {
+                lock_type: LockType::Share,
+                of: vec![],
+                nonblock: Some(NonBlock::SkipLocked),
+            },
+        ],
+        query.locks
+    );
+
+    verified_stmt("SELECT * FROM t FOR NO KEY UPDATE FOR KEY SHARE OF t");
+    verified_stmt("SELECT 1 FOR SHARE");
+    verified_stmt("SELECT * FROM (SELECT * FROM t FOR UPDATE) AS x");
+
+    let res = parse_sql_statements("SELECT * FROM t FOR DELETE");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected UPDATE, NO KEY UPDATE, SHARE, or KEY SHARE after FOR, \
+             found: DELETE at line 1, column 21"
+                .to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_cast() {
     let sql = "SELECT CAST(id AS bigint) FROM customer";
@@ -644,748 +1426,2756 @@ fn parse_cast() {
 }
 
 #[test]
-fn parse_create_table() {
-    let sql = "CREATE TABLE uk_cities (\
-               name VARCHAR(100) NOT NULL,\
-               lat DOUBLE NULL,\
-               lng DOUBLE NULL)";
-    let ast = one_statement_parses_to(
-        sql,
-        "CREATE TABLE uk_cities (\
-         name character varying(100) NOT NULL, \
-         lat double, \
-         lng double)",
+fn parse_boolean_type() {
+    let select = verified_only_select("SELECT CAST(x AS boolean)");
+    assert_eq!(
+        &ASTNode::SQLCast {
+            expr: Box::new(ASTNode::SQLIdentifier("x".to_string())),
+            data_type: SQLType::Boolean,
+        },
+        expr_from_projection(only(&select.projection))
     );
-    match ast {
-        SQLStatement::SQLCreateTable {
-            name,
-            columns,
-            external: false,
-            file_format: None,
-            location: None,
-        } => {
-            assert_eq!("uk_cities", name.to_string());
-            assert_eq!(3, columns.len());
-
-            let c_name = &columns[0];
-            assert_eq!("name", c_name.name);
-            assert_eq!(SQLType::Varchar(Some(100)), c_name.data_type);
-            assert_eq!(false, c_name.allow_null);
+    one_statement_parses_to("SELECT CAST(x AS BOOL)", "SELECT CAST(x AS boolean)");
 
-            let c_lat = &columns[1];
-            assert_eq!("lat", c_lat.name);
-            assert_eq!(SQLType::Double, c_lat.data_type);
-            assert_eq!(true, c_lat.allow_null);
-
-            let c_lng = &columns[2];
-            assert_eq!("lng", c_lng.name);
-            assert_eq!(SQLType::Double, c_lng.data_type);
-            assert_eq!(true, c_lng.allow_null);
+    match one_statement_parses_to(
+        "CREATE TABLE t (a boolean NOT NULL, b bool)",
+        "CREATE TABLE t (a boolean NOT NULL, b boolean)",
+    ) {
+        SQLStatement::SQLCreateTable { columns, .. } => {
+            assert_eq!(SQLType::Boolean, columns[0].data_type);
+            assert_eq!(SQLType::Boolean, columns[1].data_type);
         }
         _ => unreachable!(),
     }
 }
 
 #[test]
-fn parse_create_external_table() {
-    let sql = "CREATE EXTERNAL TABLE uk_cities (\
-               name VARCHAR(100) NOT NULL,\
-               lat DOUBLE NULL,\
-               lng DOUBLE NULL)\
-               STORED AS TEXTFILE LOCATION '/tmp/example.csv";
-    let ast = one_statement_parses_to(
+fn parse_binary_types() {
+    let sql = "CREATE TABLE t (a BYTEA, b BLOB, c BLOB(1000), \
+               d BINARY, e BINARY(16), f VARBINARY(255))";
+    match one_statement_parses_to(
         sql,
-        "CREATE EXTERNAL TABLE uk_cities (\
-         name character varying(100) NOT NULL, \
-         lat double, \
-         lng double) \
-         STORED AS TEXTFILE LOCATION '/tmp/example.csv'",
-    );
-    match ast {
-        SQLStatement::SQLCreateTable {
-            name,
-            columns,
-            external,
-            file_format,
-            location,
-        } => {
-            assert_eq!("uk_cities", name.to_string());
-            assert_eq!(3, columns.len());
-
-            let c_name = &columns[0];
-            assert_eq!("name", c_name.name);
-            assert_eq!(SQLType::Varchar(Some(100)), c_name.data_type);
-            assert_eq!(false, c_name.allow_null);
-
-            let c_lat = &columns[1];
-            assert_eq!("lat", c_lat.name);
-            assert_eq!(SQLType::Double, c_lat.data_type);
-            assert_eq!(true, c_lat.allow_null);
-
-            let c_lng = &columns[2];
-            assert_eq!("lng", c_lng.name);
-            assert_eq!(SQLType::Double, c_lng.data_type);
-            assert_eq!(true, c_lng.allow_null);
-
-            assert!(external);
-            assert_eq!(FileFormat::TEXTFILE, file_format.unwrap());
-            assert_eq!("/tmp/example.csv", location.unwrap());
+        "CREATE TABLE t (a bytea, b blob, c blob(1000), \
+         d binary, e binary(16), f varbinary(255))",
+    ) {
+        SQLStatement::SQLCreateTable { columns, .. } => {
+            let data_types: Vec<_> = columns.iter().map(|c| c.data_type.clone()).collect();
+            assert_eq!(
+                vec![
+                    SQLType::Bytea,
+                    SQLType::Blob(None),
+                    SQLType::Blob(Some(1000)),
+                    SQLType::Binary(None),
+                    SQLType::Binary(Some(16)),
+                    SQLType::Varbinary(Some(255)),
+                ],
+                data_types
+            );
         }
         _ => unreachable!(),
     }
-}
 
-#[test]
-fn parse_alter_table_constraint_primary_key() {
-    let sql = "ALTER TABLE bazaar.address \
-               ADD CONSTRAINT address_pkey PRIMARY KEY (address_id)";
-    match verified_stmt(sql) {
-        SQLStatement::SQLAlterTable { name, .. } => {
-            assert_eq!(name.to_string(), "bazaar.address");
-        }
-        _ => unreachable!(),
-    }
+    let select = verified_only_select("SELECT CAST(a AS bytea), CAST(b AS varbinary(16))");
+    assert_eq!(
+        &ASTNode::SQLCast {
+            expr: Box::new(ASTNode::SQLIdentifier("a".to_string())),
+            data_type: SQLType::Bytea,
+        },
+        expr_from_projection(&select.projection[0])
+    );
+    assert_eq!(
+        &ASTNode::SQLCast {
+            expr: Box::new(ASTNode::SQLIdentifier("b".to_string())),
+            data_type: SQLType::Varbinary(Some(16)),
+        },
+        expr_from_projection(&select.projection[1])
+    );
 }
 
 #[test]
-fn parse_alter_table_constraint_foreign_key() {
-    let sql = "ALTER TABLE public.customer \
-        ADD CONSTRAINT customer_address_id_fkey FOREIGN KEY (address_id) REFERENCES public.address(address_id)";
-    match verified_stmt(sql) {
-        SQLStatement::SQLAlterTable { name, .. } => {
-            assert_eq!(name.to_string(), "public.customer");
+fn parse_uuid_type() {
+    let select = verified_only_select("SELECT CAST(x AS uuid)");
+    assert_eq!(
+        &ASTNode::SQLCast {
+            expr: Box::new(ASTNode::SQLIdentifier("x".to_string())),
+            data_type: SQLType::Uuid,
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    match one_statement_parses_to(
+        "CREATE TABLE t (id UUID PRIMARY KEY)",
+        "CREATE TABLE t (id uuid PRIMARY KEY)",
+    ) {
+        SQLStatement::SQLCreateTable { columns, .. } => {
+            assert_eq!(SQLType::Uuid, only(&columns).data_type)
         }
         _ => unreachable!(),
     }
 }
 
 #[test]
-fn parse_scalar_function_in_projection() {
-    let sql = "SELECT sqrt(id) FROM foo";
-    let select = verified_only_select(sql);
+fn parse_decimal_type() {
+    let expr = verified_expr("CAST(x AS numeric(10,2))");
     assert_eq!(
-        &ASTNode::SQLFunction {
-            name: SQLObjectName(vec!["sqrt".to_string()]),
-            args: vec![ASTNode::SQLIdentifier("id".to_string())],
-            over: None,
-            distinct: false,
+        ASTNode::SQLCast {
+            expr: Box::new(ASTNode::SQLIdentifier("x".to_string())),
+            data_type: SQLType::Decimal(Some(10), Some(2)),
         },
-        expr_from_projection(only(&select.projection))
+        expr
+    );
+    one_statement_parses_to(
+        "SELECT CAST(x AS DECIMAL(10, 2))",
+        "SELECT CAST(x AS numeric(10,2))",
     );
+
+    match one_statement_parses_to(
+        "CREATE TABLE t (a NUMERIC(12,4), b DECIMAL(5), c decimal, d numeric)",
+        "CREATE TABLE t (a numeric(12,4), b numeric(5), c numeric, d numeric)",
+    ) {
+        SQLStatement::SQLCreateTable { columns, .. } => {
+            let data_types: Vec<_> = columns.iter().map(|c| c.data_type.clone()).collect();
+            assert_eq!(
+                vec![
+                    SQLType::Decimal(Some(12), Some(4)),
+                    SQLType::Decimal(Some(5), None),
+                    SQLType::Decimal(None, None),
+                    SQLType::Decimal(None, None),
+                ],
+                data_types
+            );
+        }
+        _ => unreachable!(),
+    }
 }
 
 #[test]
-fn parse_window_functions() {
-    let sql = "SELECT row_number() OVER (ORDER BY dt DESC), \
-               sum(foo) OVER (PARTITION BY a, b ORDER BY c, d \
-               ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW), \
-               avg(bar) OVER (ORDER BY a \
-               RANGE BETWEEN 1 PRECEDING AND 1 FOLLOWING), \
-               max(baz) OVER (ORDER BY a \
-               ROWS UNBOUNDED PRECEDING) \
-               FROM foo";
-    let select = verified_only_select(sql);
-    assert_eq!(4, select.projection.len());
+fn parse_float_types() {
+    let sql = "CREATE TABLE t (a FLOAT(24), b FLOAT, c DOUBLE PRECISION)";
+    match one_statement_parses_to(sql, "CREATE TABLE t (a float(24), b float, c double)") {
+        SQLStatement::SQLCreateTable { columns, .. } => {
+            let types: Vec<SQLType> = columns.into_iter().map(|c| c.data_type).collect();
+            assert_eq!(
+                vec![
+                    SQLType::Float(Some(24)),
+                    SQLType::Float(None),
+                    SQLType::Double
+                ],
+                types
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    let select = verified_only_select("SELECT CAST(a AS float(53)), CAST(b AS float)");
     assert_eq!(
-        &ASTNode::SQLFunction {
-            name: SQLObjectName(vec!["row_number".to_string()]),
-            args: vec![],
-            over: Some(SQLWindowSpec {
-                partition_by: vec![],
-                order_by: vec![SQLOrderByExpr {
-                    expr: ASTNode::SQLIdentifier("dt".to_string()),
-                    asc: Some(false)
-                }],
-                window_frame: None,
-            }),
-            distinct: false,
+        &ASTNode::SQLCast {
+            expr: Box::new(ASTNode::SQLIdentifier("a".to_string())),
+            data_type: SQLType::Float(Some(53)),
         },
         expr_from_projection(&select.projection[0])
     );
+    one_statement_parses_to(
+        "SELECT CAST(c AS DOUBLE PRECISION)",
+        "SELECT CAST(c AS double)",
+    );
 }
 
 #[test]
-fn parse_aggregate_with_group_by() {
-    let sql = "SELECT a, COUNT(1), MIN(b), MAX(b) FROM foo GROUP BY a";
-    let _ast = verified_only_select(sql);
-    //TODO: assertions
-}
+fn parse_time_precision() {
+    let sql = "CREATE TABLE t (a time(3), b timestamp(6), c timestamp(0), d time, e timestamp)";
+    match verified_stmt(sql) {
+        SQLStatement::SQLCreateTable { columns, .. } => {
+            let types: Vec<SQLType> = columns.into_iter().map(|c| c.data_type).collect();
+            assert_eq!(
+                vec![
+                    SQLType::Time(Some(3)),
+                    SQLType::Timestamp(Some(6)),
+                    SQLType::Timestamp(Some(0)),
+                    SQLType::Time(None),
+                    SQLType::Timestamp(None),
+                ],
+                types
+            );
+        }
+        _ => unreachable!(),
+    }
 
-#[test]
-fn parse_literal_string() {
-    let sql = "SELECT 'one', N'national string'";
-    let select = verified_only_select(sql);
-    assert_eq!(2, select.projection.len());
+    let select = verified_only_select("SELECT CAST(a AS time(0)), CAST(b AS timestamp)");
     assert_eq!(
-        &ASTNode::SQLValue(Value::SingleQuotedString("one".to_string())),
+        &ASTNode::SQLCast {
+            expr: Box::new(ASTNode::SQLIdentifier("a".to_string())),
+            data_type: SQLType::Time(Some(0)),
+        },
         expr_from_projection(&select.projection[0])
     );
     assert_eq!(
-        &ASTNode::SQLValue(Value::NationalStringLiteral("national string".to_string())),
+        &ASTNode::SQLCast {
+            expr: Box::new(ASTNode::SQLIdentifier("b".to_string())),
+            data_type: SQLType::Timestamp(None),
+        },
         expr_from_projection(&select.projection[1])
     );
+
+    // the time zone is not retained
+    one_statement_parses_to(
+        "SELECT CAST(a AS TIMESTAMP(3) WITH TIME ZONE)",
+        "SELECT CAST(a AS timestamp(3))",
+    );
 }
 
 #[test]
-fn parse_simple_math_expr_plus() {
-    let sql = "SELECT a + b, 2 + a, 2.5 + a, a_f + b_f, 2 + a_f, 2.5 + a_f FROM c";
-    verified_only_select(sql);
+fn parse_text_type() {
+    match verified_stmt("CREATE TABLE t (a text, b character varying(10))") {
+        SQLStatement::SQLCreateTable { columns, .. } => {
+            assert_eq!(SQLType::Text, columns[0].data_type);
+            assert_eq!(SQLType::Varchar(Some(10)), columns[1].data_type);
+        }
+        _ => unreachable!(),
+    }
+
+    let select = verified_only_select("SELECT CAST(a AS text)");
+    assert_eq!(
+        &ASTNode::SQLCast {
+            expr: Box::new(ASTNode::SQLIdentifier("a".to_string())),
+            data_type: SQLType::Text,
+        },
+        expr_from_projection(only(&select.projection))
+    );
 }
 
 #[test]
-fn parse_simple_math_expr_minus() {
-    let sql = "SELECT a - b, 2 - a, 2.5 - a, a_f - b_f, 2 - a_f, 2.5 - a_f FROM c";
-    verified_only_select(sql);
+fn parse_interval_type() {
+    let sql = "CREATE TABLE t (a interval, b interval year to month, \
+               c interval day to second(3), d interval(6), e interval hour)";
+    match verified_stmt(sql) {
+        SQLStatement::SQLCreateTable { columns, .. } => {
+            let types: Vec<SQLType> = columns.into_iter().map(|c| c.data_type).collect();
+            assert_eq!(
+                vec![
+                    SQLType::Interval {
+                        leading_field: None,
+                        trailing_field: None,
+                        precision: None,
+                    },
+                    SQLType::Interval {
+                        leading_field: Some(DateTimeField::Year),
+                        trailing_field: Some(DateTimeField::Month),
+                        precision: None,
+                    },
+                    SQLType::Interval {
+                        leading_field: Some(DateTimeField::Day),
+                        trailing_field: Some(DateTimeField::Second),
+                        precision: Some(3),
+                    },
+                    SQLType::Interval {
+                        leading_field: None,
+                        trailing_field: None,
+                        precision: Some(6),
+                    },
+                    SQLType::Interval {
+                        leading_field: Some(DateTimeField::Hour),
+                        trailing_field: None,
+                        precision: None,
+                    },
+                ],
+                types
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    one_statement_parses_to(
+        "SELECT CAST(a AS INTERVAL DAY TO SECOND)",
+        "SELECT CAST(a AS interval day to second)",
+    );
+    // `interval` remains usable as a column name
+    verified_only_select("SELECT interval FROM t WHERE interval > 1");
+
+    let res = parse_sql_statements("CREATE TABLE t (a INTERVAL DAY TO 1)");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected a date/time field, found: 1 at line 1, column 35".to_string()
+        ),
+        res.unwrap_err()
+    );
 }
 
 #[test]
-fn parse_delimited_identifiers() {
-    // check that quoted identifiers in any position remain quoted after serialization
-    let select = verified_only_select(
-        r#"SELECT "alias"."bar baz", "myfun"(), "simple id" AS "column alias" FROM "a table" AS "alias""#
+fn parse_create_table() {
+    let sql = "CREATE TABLE uk_cities (\
+               name VARCHAR(100) NOT NULL,\
+               lat DOUBLE NULL,\
+               lng DOUBLE)";
+    let ast = one_statement_parses_to(
+        sql,
+        "CREATE TABLE uk_cities (\
+         name character varying(100) NOT NULL, \
+         lat double NULL, \
+         lng double)",
     );
-    // check FROM
-    match select.relation.unwrap() {
-        TableFactor::Table {
+    match ast {
+        SQLStatement::SQLCreateTable {
             name,
-            alias,
-            args,
-            with_hints,
+            columns,
+            constraints,
+            external: false,
+            file_format: None,
+            location: None,
+            if_not_exists: false,
         } => {
-            assert_eq!(vec![r#""a table""#.to_string()], name.0);
-            assert_eq!(r#""alias""#, alias.unwrap());
-            assert!(args.is_empty());
-            assert!(with_hints.is_empty());
+            assert_eq!("uk_cities", name.to_string());
+            assert_eq!(3, columns.len());
+            assert!(constraints.is_empty());
+
+            assert_eq!(
+                columns,
+                vec![
+                    SQLColumnDef {
+                        name: "name".into(),
+                        data_type: SQLType::Varchar(Some(100)),
+                        options: vec![ColumnOptionDef {
+                            name: None,
+                            option: ColumnOption::NotNull
+                        }],
+                    },
+                    SQLColumnDef {
+                        name: "lat".into(),
+                        data_type: SQLType::Double,
+                        options: vec![ColumnOptionDef {
+                            name: None,
+                            option: ColumnOption::Null
+                        }],
+                    },
+                    SQLColumnDef {
+                        name: "lng".into(),
+                        data_type: SQLType::Double,
+                        options: vec![],
+                    },
+                ]
+            );
         }
-        _ => panic!("Expecting TableFactor::Table"),
+        _ => unreachable!(),
     }
-    // check SELECT
-    assert_eq!(3, select.projection.len());
-    assert_eq!(
-        &ASTNode::SQLCompoundIdentifier(vec![r#""alias""#.to_string(), r#""bar baz""#.to_string()]),
-        expr_from_projection(&select.projection[0]),
-    );
+}
+
+#[test]
+fn parse_create_table_column_options() {
+    let sql = "CREATE TABLE t (\
+               id int PRIMARY KEY, \
+               email character varying(50) UNIQUE NOT NULL, \
+               age int CONSTRAINT positive_age CHECK (age > 0), \
+               dept int REFERENCES depts(id), \
+               manager int NULL REFERENCES employees, \
+               created timestamp DEFAULT now() NOT NULL)";
+    match verified_stmt(sql) {
+        SQLStatement::SQLCreateTable { columns, .. } => {
+            let options: Vec<Vec<ColumnOptionDef>> =
+                columns.into_iter().map(|column| column.options).collect();
+            let unnamed = |option| ColumnOptionDef { name: None, option };
+            assert_eq!(
+                vec![
+                    vec![unnamed(ColumnOption::Unique { is_primary: true })],
+                    vec![
+                        unnamed(ColumnOption::Unique { is_primary: false }),
+                        unnamed(ColumnOption::NotNull),
+                    ],
+                    vec![ColumnOptionDef {
+                        name: Some("positive_age".to_string()),
+                        option: ColumnOption::Check(ASTNode::SQLBinaryExpr {
+                            left: Box::new(ASTNode::SQLIdentifier("age".to_string())),
+                            op: SQLOperator::Gt,
+                            right: Box::new(ASTNode::SQLValue(Value::Long(0))),
+                        }),
+                    }],
+                    vec![unnamed(ColumnOption::ForeignKey {
+                        foreign_table: SQLObjectName(vec!["depts".to_string()]),
+                        referred_columns: vec!["id".to_string()],
+                    })],
+                    vec![
+                        unnamed(ColumnOption::Null),
+                        unnamed(ColumnOption::ForeignKey {
+                            foreign_table: SQLObjectName(vec!["employees".to_string()]),
+                            referred_columns: vec![],
+                        }),
+                    ],
+                    vec![
+                        unnamed(ColumnOption::Default(ASTNode::SQLFunction {
+                            name: SQLObjectName(vec!["now".to_string()]),
+                            args: vec![],
+                            order_by: vec![],
+                            within_group: vec![],
+                            over: None,
+                            distinct: false,
+                        })),
+                        unnamed(ColumnOption::NotNull),
+                    ],
+                ],
+                options
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    let res = parse_sql_statements("CREATE TABLE t (a int NOT NULL BOGUS)");
     assert_eq!(
-        &ASTNode::SQLFunction {
-            name: SQLObjectName(vec![r#""myfun""#.to_string()]),
-            args: vec![],
-            over: None,
-            distinct: false,
-        },
-        expr_from_projection(&select.projection[1]),
+        ParserError::ParserError(
+            "Expected column option, found: BOGUS at line 1, column 32".to_string()
+        ),
+        res.unwrap_err()
     );
-    match &select.projection[2] {
-        SQLSelectItem::ExpressionWithAlias { expr, alias } => {
-            assert_eq!(&ASTNode::SQLIdentifier(r#""simple id""#.to_string()), expr);
-            assert_eq!(r#""column alias""#, alias);
+}
+
+#[test]
+fn parse_create_table_if_not_exists() {
+    let sql = "CREATE TABLE IF NOT EXISTS uk_cities (name character varying(100) NOT NULL)";
+    match verified_stmt(sql) {
+        SQLStatement::SQLCreateTable {
+            name,
+            if_not_exists,
+            ..
+        } => {
+            assert_eq!("uk_cities", name.to_string());
+            assert!(if_not_exists);
         }
-        _ => panic!("Expected ExpressionWithAlias"),
+        _ => unreachable!(),
     }
 
-    verified_stmt(r#"CREATE TABLE "foo" ("bar" "int")"#);
-    verified_stmt(r#"ALTER TABLE foo ADD CONSTRAINT "bar" PRIMARY KEY (baz)"#);
-    //TODO verified_stmt(r#"UPDATE foo SET "bar" = 5"#);
+    verified_stmt(
+        "CREATE EXTERNAL TABLE IF NOT EXISTS uk_cities (name character varying(100) NOT NULL) \
+         STORED AS TEXTFILE LOCATION '/tmp/example.csv'",
+    );
 }
 
 #[test]
-fn parse_parens() {
-    use self::ASTNode::*;
-    use self::SQLOperator::*;
-    let sql = "(a + b) - (c + d)";
+fn parse_create_table_constraints() {
+    let sql = "CREATE TABLE t (\
+               a int, \
+               b int, \
+               PRIMARY KEY (a, b), \
+               CONSTRAINT fk FOREIGN KEY (b) REFERENCES u(id), \
+               UNIQUE (a), \
+               CONSTRAINT a_gt_b CHECK (a > b))";
+    match verified_stmt(sql) {
+        SQLStatement::SQLCreateTable {
+            columns,
+            constraints,
+            ..
+        } => {
+            assert_eq!(2, columns.len());
+            assert_eq!(
+                vec![
+                    TableConstraint::Unique {
+                        name: None,
+                        columns: vec!["a".to_string(), "b".to_string()],
+                        is_primary: true,
+                    },
+                    TableConstraint::ForeignKey {
+                        name: Some("fk".to_string()),
+                        columns: vec!["b".to_string()],
+                        foreign_table: SQLObjectName(vec!["u".to_string()]),
+                        referred_columns: vec!["id".to_string()],
+                    },
+                    TableConstraint::Unique {
+                        name: None,
+                        columns: vec!["a".to_string()],
+                        is_primary: false,
+                    },
+                    TableConstraint::Check {
+                        name: Some("a_gt_b".to_string()),
+                        expr: Box::new(verified_expr("a > b")),
+                        no_inherit: false,
+                    },
+                ],
+                constraints
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    // constraints may be interleaved with the columns, but are printed last
+    one_statement_parses_to(
+        "CREATE TABLE t (a int, CONSTRAINT pk PRIMARY KEY (a), b int)",
+        "CREATE TABLE t (a int, b int, CONSTRAINT pk PRIMARY KEY (a))",
+    );
+
+    let res = parse_sql_statements("CREATE TABLE t (a int, CONSTRAINT c KEY (a))");
     assert_eq!(
-        SQLBinaryExpr {
-            left: Box::new(SQLNested(Box::new(SQLBinaryExpr {
-                left: Box::new(SQLIdentifier("a".to_string())),
-                op: Plus,
-                right: Box::new(SQLIdentifier("b".to_string()))
-            }))),
-            op: Minus,
-            right: Box::new(SQLNested(Box::new(SQLBinaryExpr {
-                left: Box::new(SQLIdentifier("c".to_string())),
-                op: Plus,
-                right: Box::new(SQLIdentifier("d".to_string()))
-            })))
-        },
-        verified_expr(sql)
+        ParserError::ParserError(
+            "Expected PRIMARY, UNIQUE, FOREIGN, or CHECK, found: KEY at line 1, column 37"
+                .to_string()
+        ),
+        res.unwrap_err()
     );
 }
 
 #[test]
-fn parse_searched_case_expression() {
-    let sql = "SELECT CASE WHEN bar IS NULL THEN 'null' WHEN bar = 0 THEN '=0' WHEN bar >= 0 THEN '>=0' ELSE '<0' END FROM foo";
-    use self::ASTNode::{SQLBinaryExpr, SQLCase, SQLIdentifier, SQLIsNull, SQLValue};
-    use self::SQLOperator::*;
-    let select = verified_only_select(sql);
-    assert_eq!(
-        &SQLCase {
-            operand: None,
-            conditions: vec![
-                SQLIsNull(Box::new(SQLIdentifier("bar".to_string()))),
-                SQLBinaryExpr {
-                    left: Box::new(SQLIdentifier("bar".to_string())),
-                    op: Eq,
-                    right: Box::new(SQLValue(Value::Long(0)))
+fn parse_table_sample() {
+    let select = verified_only_select("SELECT * FROM t TABLESAMPLE SYSTEM (10) REPEATABLE (42)");
+    match select.relation {
+        Some(TableFactor::Table { name, sample, .. }) => {
+            assert_eq!("t", name.to_string());
+            assert_eq!(
+                Some(Box::new(TableSample {
+                    method: TableSampleMethod::System,
+                    quantity: ASTNode::SQLValue(Value::Long(10)),
+                    unit: None,
+                    repeatable: Some(ASTNode::SQLValue(Value::Long(42))),
+                })),
+                sample
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    let select = verified_only_select(
+        "SELECT * FROM t AS a TABLESAMPLE BERNOULLI (2.5) JOIN u ON a.x = u.x WHERE a.y = 1",
+    );
+    match select.relation {
+        Some(TableFactor::Table { alias, sample, .. }) => {
+            assert_eq!("a", alias.unwrap().name);
+            let sample = sample.unwrap();
+            assert_eq!(TableSampleMethod::Bernoulli, sample.method);
+            assert_eq!(ASTNode::SQLValue(Value::Double(2.5)), sample.quantity);
+            assert_eq!(None, sample.repeatable);
+        }
+        _ => unreachable!(),
+    }
+    assert_eq!(1, select.joins.len());
+
+    let res = parse_sql_statements("SELECT * FROM t TABLESAMPLE (10)");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected BERNOULLI or SYSTEM after TABLESAMPLE, found: ( at line 1, column 29"
+                .to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_create_external_table() {
+    let sql = "CREATE EXTERNAL TABLE uk_cities (\
+               name VARCHAR(100) NOT NULL,\
+               lat DOUBLE NULL,\
+               lng DOUBLE NULL)\
+               STORED AS TEXTFILE LOCATION '/tmp/example.csv";
+    let ast = one_statement_parses_to(
+        sql,
+        "CREATE EXTERNAL TABLE uk_cities (\
+         name character varying(100) NOT NULL, \
+         lat double NULL, \
+         lng double NULL) \
+         STORED AS TEXTFILE LOCATION '/tmp/example.csv'",
+    );
+    match ast {
+        SQLStatement::SQLCreateTable {
+            name,
+            columns,
+            constraints,
+            external,
+            file_format,
+            location,
+            if_not_exists: false,
+        } => {
+            assert_eq!("uk_cities", name.to_string());
+            assert_eq!(3, columns.len());
+            assert!(constraints.is_empty());
+
+            let c_name = &columns[0];
+            assert_eq!("name", c_name.name);
+            assert_eq!(SQLType::Varchar(Some(100)), c_name.data_type);
+            assert_eq!(ColumnOption::NotNull, only(&c_name.options).option);
+
+            let c_lat = &columns[1];
+            assert_eq!("lat", c_lat.name);
+            assert_eq!(SQLType::Double, c_lat.data_type);
+            assert_eq!(ColumnOption::Null, only(&c_lat.options).option);
+
+            let c_lng = &columns[2];
+            assert_eq!("lng", c_lng.name);
+            assert_eq!(SQLType::Double, c_lng.data_type);
+            assert_eq!(ColumnOption::Null, only(&c_lng.options).option);
+
+            assert!(external);
+            assert_eq!(FileFormat::TEXTFILE, file_format.unwrap());
+            assert_eq!("/tmp/example.csv", location.unwrap());
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_alter_table_constraint_primary_key() {
+    let sql = "ALTER TABLE bazaar.address \
+               ADD CONSTRAINT address_pkey PRIMARY KEY (address_id)";
+    match verified_stmt(sql) {
+        SQLStatement::SQLAlterTable { name, operation } => {
+            assert_eq!(name.to_string(), "bazaar.address");
+            assert_eq!(
+                AlterOperation::AddConstraint(TableConstraint::Unique {
+                    name: Some("address_pkey".to_string()),
+                    columns: vec!["address_id".to_string()],
+                    is_primary: true,
+                }),
+                operation
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    // the constraint name is optional
+    verified_stmt("ALTER TABLE t ADD UNIQUE (a, b)");
+}
+
+#[test]
+fn parse_alter_table_constraint_foreign_key() {
+    let sql = "ALTER TABLE public.customer \
+        ADD CONSTRAINT customer_address_id_fkey FOREIGN KEY (address_id) REFERENCES public.address(address_id)";
+    match verified_stmt(sql) {
+        SQLStatement::SQLAlterTable { name, operation } => {
+            assert_eq!(name.to_string(), "public.customer");
+            assert_eq!(
+                AlterOperation::AddConstraint(TableConstraint::ForeignKey {
+                    name: Some("customer_address_id_fkey".to_string()),
+                    columns: vec!["address_id".to_string()],
+                    foreign_table: SQLObjectName(vec!["public".to_string(), "address".to_string()]),
+                    referred_columns: vec!["address_id".to_string()],
+                }),
+                operation
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_alter_table_constraint_check() {
+    let sql = "ALTER TABLE t ADD CONSTRAINT positive_a CHECK (a > 0) NO INHERIT";
+    match verified_stmt(sql) {
+        SQLStatement::SQLAlterTable {
+            operation:
+                AlterOperation::AddConstraint(TableConstraint::Check {
+                    name,
+                    expr,
+                    no_inherit,
+                }),
+            ..
+        } => {
+            assert_eq!(Some("positive_a".to_string()), name);
+            assert_eq!("a > 0", expr.to_string());
+            assert!(no_inherit);
+        }
+        _ => unreachable!(),
+    }
+
+    let sql = "ALTER TABLE t ADD CONSTRAINT positive_a CHECK (a > 0)";
+    match verified_stmt(sql) {
+        SQLStatement::SQLAlterTable {
+            operation: AlterOperation::AddConstraint(TableConstraint::Check { no_inherit, .. }),
+            ..
+        } => assert!(!no_inherit),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_alter_table_rename() {
+    match verified_stmt("ALTER TABLE s.t RENAME TO t2") {
+        SQLStatement::SQLAlterTable { name, operation } => {
+            assert_eq!("s.t", name.to_string());
+            assert_eq!(
+                AlterOperation::RenameTable {
+                    table_name: SQLObjectName(vec!["t2".to_string()]),
+                },
+                operation
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    match verified_stmt("ALTER TABLE t RENAME COLUMN a TO b") {
+        SQLStatement::SQLAlterTable { name, operation } => {
+            assert_eq!("t", name.to_string());
+            assert_eq!(
+                AlterOperation::RenameColumn {
+                    old_column_name: "a".to_string(),
+                    new_column_name: "b".to_string(),
+                },
+                operation
+            );
+        }
+        _ => unreachable!(),
+    }
+    // COLUMN is optional
+    one_statement_parses_to(
+        "ALTER TABLE t RENAME a TO b",
+        "ALTER TABLE t RENAME COLUMN a TO b",
+    );
+
+    match verified_stmt("ALTER TABLE t RENAME CONSTRAINT c1 TO c2") {
+        SQLStatement::SQLAlterTable { name, operation } => {
+            assert_eq!("t", name.to_string());
+            assert_eq!(
+                AlterOperation::RenameConstraint {
+                    old_name: "c1".to_string(),
+                    new_name: "c2".to_string(),
+                },
+                operation
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    let res = parse_sql_statements("ALTER TABLE t RENAME COLUMN a b");
+    assert_eq!(
+        ParserError::ParserError("Expected TO, found: b at line 1, column 31".to_string()),
+        res.unwrap_err()
+    );
+
+    let res = parse_sql_statements("ALTER TABLE t RENAME CONSTRAINT c1");
+    assert_eq!(
+        ParserError::ParserError("Expected TO, found: EOF".to_string()),
+        res.unwrap_err()
+    );
+
+    let res = parse_sql_statements("ALTER TABLE t DROP COLUMN a");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected ADD or RENAME after ALTER TABLE, found: DROP at line 1, column 15"
+                .to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_placeholders() {
+    let select = verified_only_select("SELECT * FROM t WHERE a = ? AND b = ?");
+    assert_eq!(
+        ASTNode::SQLBinaryExpr {
+            left: Box::new(ASTNode::SQLIdentifier("a".to_string())),
+            op: SQLOperator::Eq,
+            right: Box::new(ASTNode::SQLValue(Value::Placeholder("?".to_string()))),
+        },
+        match select.selection {
+            Some(ASTNode::SQLBinaryExpr { left, .. }) => *left,
+            _ => unreachable!(),
+        }
+    );
+
+    let stmt = verified_stmt("INSERT INTO t (a, b) VALUES (?, ?), (?, lower(?))");
+    assert_eq!(vec!["?".to_string(); 4], stmt.parameters());
+}
+
+#[test]
+fn parse_named_function_args() {
+    let select = verified_only_select("SELECT make_point(x => 1, y => 2)");
+    assert_eq!(
+        &ASTNode::SQLFunction {
+            name: SQLObjectName(vec!["make_point".to_string()]),
+            args: vec![
+                ASTNode::SQLNamedArgument {
+                    name: "x".to_string(),
+                    arg: Box::new(ASTNode::SQLValue(Value::Long(1))),
+                },
+                ASTNode::SQLNamedArgument {
+                    name: "y".to_string(),
+                    arg: Box::new(ASTNode::SQLValue(Value::Long(2))),
                 },
-                SQLBinaryExpr {
-                    left: Box::new(SQLIdentifier("bar".to_string())),
-                    op: GtEq,
-                    right: Box::new(SQLValue(Value::Long(0)))
-                }
             ],
-            results: vec![
-                SQLValue(Value::SingleQuotedString("null".to_string())),
-                SQLValue(Value::SingleQuotedString("=0".to_string())),
-                SQLValue(Value::SingleQuotedString(">=0".to_string()))
+            order_by: vec![],
+            within_group: vec![],
+            over: None,
+            distinct: false,
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    // positional and named arguments can be mixed, and positional ones
+    // remain plain expressions
+    let select = verified_only_select("SELECT my_schema.f(a, b => c = 1) FROM t");
+    assert_eq!(
+        &ASTNode::SQLFunction {
+            name: SQLObjectName(vec!["my_schema".to_string(), "f".to_string()]),
+            args: vec![
+                ASTNode::SQLIdentifier("a".to_string()),
+                ASTNode::SQLNamedArgument {
+                    name: "b".to_string(),
+                    arg: Box::new(ASTNode::SQLBinaryExpr {
+                        left: Box::new(ASTNode::SQLIdentifier("c".to_string())),
+                        op: SQLOperator::Eq,
+                        right: Box::new(ASTNode::SQLValue(Value::Long(1))),
+                    }),
+                },
             ],
-            else_result: Some(Box::new(SQLValue(Value::SingleQuotedString(
-                "<0".to_string()
-            ))))
+            order_by: vec![],
+            within_group: vec![],
+            over: None,
+            distinct: false,
         },
-        expr_from_projection(only(&select.projection)),
+        expr_from_projection(only(&select.projection))
+    );
+
+    one_statement_parses_to("SELECT f(x=>1)", "SELECT f(x => 1)");
+}
+
+#[test]
+fn parse_within_group() {
+    let sql = "SELECT percentile_cont(0.5) WITHIN GROUP (ORDER BY x DESC, y) FROM t";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        &ASTNode::SQLFunction {
+            name: SQLObjectName(vec!["percentile_cont".to_string()]),
+            args: vec![ASTNode::SQLValue(Value::Double(0.5))],
+            order_by: vec![],
+            within_group: vec![
+                SQLOrderByExpr {
+                    expr: ASTNode::SQLIdentifier("x".to_string()),
+                    asc: Some(false),
+                    nulls_first: None,
+                },
+                SQLOrderByExpr {
+                    expr: ASTNode::SQLIdentifier("y".to_string()),
+                    asc: None,
+                    nulls_first: None,
+                },
+            ],
+            over: None,
+            distinct: false,
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    let sql = "SELECT mode() WITHIN GROUP (ORDER BY x) OVER (PARTITION BY y) FROM t";
+    let select = verified_only_select(sql);
+    match expr_from_projection(only(&select.projection)) {
+        ASTNode::SQLFunction {
+            within_group,
+            over: Some(over),
+            ..
+        } => {
+            assert_eq!(1, within_group.len());
+            let spec = match over.as_ref() {
+                SQLWindowType::WindowSpec(spec) => spec,
+                _ => panic!("Expected a window spec"),
+            };
+            assert_eq!(
+                vec![ASTNode::SQLIdentifier("y".to_string())],
+                spec.partition_by
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    let sql = "SELECT mode() WITHIN GROUP (ORDER BY x NULLS FIRST) FROM t";
+    let select = verified_only_select(sql);
+    match expr_from_projection(only(&select.projection)) {
+        ASTNode::SQLFunction { within_group, .. } => assert_eq!(
+            &vec![SQLOrderByExpr {
+                expr: ASTNode::SQLIdentifier("x".to_string()),
+                asc: None,
+                nulls_first: Some(true),
+            }],
+            within_group
+        ),
+        _ => unreachable!(),
+    }
+
+    let res = parse_sql_statements("SELECT mode() WITHIN GROUP (x) FROM t");
+    assert_eq!(
+        ParserError::ParserError("Expected ORDER, found: x at line 1, column 29".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_cast_in_aggregate() {
+    let select = one_statement_parses_to(
+        "SELECT SUM(CAST(x AS DECIMAL(10,2))), COUNT(DISTINCT CAST(x AS int)) FROM t",
+        "SELECT SUM(CAST(x AS numeric(10,2))), COUNT(DISTINCT CAST(x AS int)) FROM t",
+    );
+    let projection = match select {
+        SQLStatement::SQLQuery(query) => match query.body {
+            SQLSetExpr::Select(select) => select.projection,
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        &ASTNode::SQLFunction {
+            name: SQLObjectName(vec!["SUM".to_string()]),
+            args: vec![ASTNode::SQLCast {
+                expr: Box::new(ASTNode::SQLIdentifier("x".to_string())),
+                data_type: SQLType::Decimal(Some(10), Some(2)),
+            }],
+            order_by: vec![],
+            within_group: vec![],
+            over: None,
+            distinct: false,
+        },
+        expr_from_projection(&projection[0])
+    );
+    assert_eq!(
+        &ASTNode::SQLFunction {
+            name: SQLObjectName(vec!["COUNT".to_string()]),
+            args: vec![ASTNode::SQLCast {
+                expr: Box::new(ASTNode::SQLIdentifier("x".to_string())),
+                data_type: SQLType::Int,
+            }],
+            order_by: vec![],
+            within_group: vec![],
+            over: None,
+            distinct: true,
+        },
+        expr_from_projection(&projection[1])
     );
 }
 
 #[test]
-fn parse_simple_case_expression() {
-    // ANSI calls a CASE expression with an operand "<simple case>"
-    let sql = "SELECT CASE foo WHEN 1 THEN 'Y' ELSE 'N' END";
-    let select = verified_only_select(sql);
-    use self::ASTNode::{SQLCase, SQLIdentifier, SQLValue};
+fn parse_aggregate_with_order_by() {
+    let sql = "SELECT string_agg(name, ',' ORDER BY name DESC) FROM t";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        &ASTNode::SQLFunction {
+            name: SQLObjectName(vec!["string_agg".to_string()]),
+            args: vec![
+                ASTNode::SQLIdentifier("name".to_string()),
+                ASTNode::SQLValue(Value::SingleQuotedString(",".to_string())),
+            ],
+            order_by: vec![SQLOrderByExpr {
+                expr: ASTNode::SQLIdentifier("name".to_string()),
+                asc: Some(false),
+                nulls_first: None,
+            }],
+            within_group: vec![],
+            over: None,
+            distinct: false,
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    verified_stmt("SELECT group_concat(DISTINCT a ORDER BY a, b ASC) FROM t GROUP BY c");
+    verified_stmt("SELECT array_agg(a ORDER BY b) OVER (PARTITION BY c) FROM t");
+
+    // `ORDER BY` must follow the arguments
+    let res = parse_sql_statements("SELECT string_agg(ORDER BY name) FROM t");
+    assert!(res.is_err());
+}
+
+#[test]
+fn parse_scalar_function_in_projection() {
+    let sql = "SELECT sqrt(id) FROM foo";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        &ASTNode::SQLFunction {
+            name: SQLObjectName(vec!["sqrt".to_string()]),
+            args: vec![ASTNode::SQLIdentifier("id".to_string())],
+            order_by: vec![],
+            within_group: vec![],
+            over: None,
+            distinct: false,
+        },
+        expr_from_projection(only(&select.projection))
+    );
+}
+
+#[test]
+fn parse_window_functions() {
+    let sql = "SELECT row_number() OVER (ORDER BY dt DESC), \
+               sum(foo) OVER (PARTITION BY a, b ORDER BY c, d \
+               ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW), \
+               avg(bar) OVER (ORDER BY a \
+               RANGE BETWEEN 1 PRECEDING AND 1 FOLLOWING), \
+               max(baz) OVER (ORDER BY a \
+               ROWS UNBOUNDED PRECEDING) \
+               FROM foo";
+    let select = verified_only_select(sql);
+    assert_eq!(4, select.projection.len());
+    assert_eq!(
+        &ASTNode::SQLFunction {
+            name: SQLObjectName(vec!["row_number".to_string()]),
+            args: vec![],
+            order_by: vec![],
+            within_group: vec![],
+            over: Some(Box::new(SQLWindowType::WindowSpec(SQLWindowSpec {
+                window_name: None,
+                partition_by: vec![],
+                order_by: vec![SQLOrderByExpr {
+                    expr: ASTNode::SQLIdentifier("dt".to_string()),
+                    asc: Some(false),
+                    nulls_first: None,
+                }],
+                window_frame: None,
+            }))),
+            distinct: false,
+        },
+        expr_from_projection(&select.projection[0])
+    );
+}
+
+#[test]
+fn parse_named_window() {
+    let sql = "SELECT sum(x) OVER w FROM t WINDOW w AS (PARTITION BY y ORDER BY z)";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        &ASTNode::SQLFunction {
+            name: SQLObjectName(vec!["sum".to_string()]),
+            args: vec![ASTNode::SQLIdentifier("x".to_string())],
+            order_by: vec![],
+            within_group: vec![],
+            over: Some(Box::new(SQLWindowType::NamedWindow("w".to_string()))),
+            distinct: false,
+        },
+        expr_from_projection(only(&select.projection))
+    );
+    assert_eq!(
+        vec![(
+            "w".to_string(),
+            SQLWindowSpec {
+                window_name: None,
+                partition_by: vec![ASTNode::SQLIdentifier("y".to_string())],
+                order_by: vec![SQLOrderByExpr {
+                    expr: ASTNode::SQLIdentifier("z".to_string()),
+                    asc: None,
+                    nulls_first: None,
+                }],
+                window_frame: None,
+            }
+        )],
+        select.window
+    );
+
+    let sql = "SELECT sum(x) OVER w2, avg(x) OVER (w1 ROWS UNBOUNDED PRECEDING) FROM t \
+               GROUP BY x HAVING x > 0 \
+               WINDOW w1 AS (PARTITION BY y), w2 AS (w1 ORDER BY a) \
+               ORDER BY x";
+    let select = verified_only_select(sql);
+    assert_eq!(2, select.window.len());
+    assert_eq!(Some("w1".to_string()), select.window[1].1.window_name);
+    match expr_from_projection(&select.projection[1]) {
+        ASTNode::SQLFunction {
+            over: Some(over), ..
+        } => match over.as_ref() {
+            SQLWindowType::WindowSpec(spec) => {
+                assert_eq!(Some("w1".to_string()), spec.window_name)
+            }
+            _ => panic!("Expected an inline window spec"),
+        },
+        _ => panic!("Expected a window function with an inline window spec"),
+    }
+}
+
+#[test]
+fn parse_aggregate_with_group_by() {
+    let sql = "SELECT a, COUNT(1), MIN(b), MAX(b) FROM foo GROUP BY a";
+    let _ast = verified_only_select(sql);
+    //TODO: assertions
+}
+
+#[test]
+fn parse_literal_string() {
+    let sql = "SELECT 'one', N'national string'";
+    let select = verified_only_select(sql);
+    assert_eq!(2, select.projection.len());
+    assert_eq!(
+        &ASTNode::SQLValue(Value::SingleQuotedString("one".to_string())),
+        expr_from_projection(&select.projection[0])
+    );
+    assert_eq!(
+        &ASTNode::SQLValue(Value::NationalStringLiteral("national string".to_string())),
+        expr_from_projection(&select.projection[1])
+    );
+}
+
+#[test]
+fn parse_simple_math_expr_plus() {
+    let sql = "SELECT a + b, 2 + a, 2.5 + a, a_f + b_f, 2 + a_f, 2.5 + a_f FROM c";
+    verified_only_select(sql);
+}
+
+#[test]
+fn parse_simple_math_expr_minus() {
+    let sql = "SELECT a - b, 2 - a, 2.5 - a, a_f - b_f, 2 - a_f, 2.5 - a_f FROM c";
+    verified_only_select(sql);
+}
+
+#[test]
+fn parse_delimited_identifiers() {
+    // check that quoted identifiers in any position remain quoted after serialization
+    let select = verified_only_select(
+        r#"SELECT "alias"."bar baz", "myfun"(), "simple id" AS "column alias" FROM "a table" AS "alias""#
+    );
+    // check FROM
+    match select.relation.unwrap() {
+        TableFactor::Table {
+            lateral,
+            name,
+            partitions,
+            alias,
+            sample,
+            args,
+            with_hints,
+        } => {
+            assert!(!lateral);
+            assert_eq!(vec![r#""a table""#.to_string()], name.0);
+            assert!(partitions.is_empty());
+            assert_eq!(r#""alias""#, alias.unwrap().name);
+            assert!(sample.is_none());
+            assert!(args.is_none());
+            assert!(with_hints.is_empty());
+        }
+        _ => panic!("Expecting TableFactor::Table"),
+    }
+    // check SELECT
+    assert_eq!(3, select.projection.len());
+    assert_eq!(
+        &ASTNode::SQLCompoundIdentifier(vec![r#""alias""#.to_string(), r#""bar baz""#.to_string()]),
+        expr_from_projection(&select.projection[0]),
+    );
+    assert_eq!(
+        &ASTNode::SQLFunction {
+            name: SQLObjectName(vec![r#""myfun""#.to_string()]),
+            args: vec![],
+            order_by: vec![],
+            within_group: vec![],
+            over: None,
+            distinct: false,
+        },
+        expr_from_projection(&select.projection[1]),
+    );
+    match &select.projection[2] {
+        SQLSelectItem::ExpressionWithAlias { expr, alias } => {
+            assert_eq!(&ASTNode::SQLIdentifier(r#""simple id""#.to_string()), expr);
+            assert_eq!(r#""column alias""#, alias);
+        }
+        _ => panic!("Expected ExpressionWithAlias"),
+    }
+
+    verified_stmt(r#"CREATE TABLE "foo" ("bar" "int")"#);
+    verified_stmt(r#"ALTER TABLE foo ADD CONSTRAINT "bar" PRIMARY KEY (baz)"#);
+    //TODO verified_stmt(r#"UPDATE foo SET "bar" = 5"#);
+}
+
+#[test]
+fn parse_parens() {
+    use self::ASTNode::*;
+    use self::SQLOperator::*;
+    let sql = "(a + b) - (c + d)";
+    assert_eq!(
+        SQLBinaryExpr {
+            left: Box::new(SQLNested(Box::new(SQLBinaryExpr {
+                left: Box::new(SQLIdentifier("a".to_string())),
+                op: Plus,
+                right: Box::new(SQLIdentifier("b".to_string()))
+            }))),
+            op: Minus,
+            right: Box::new(SQLNested(Box::new(SQLBinaryExpr {
+                left: Box::new(SQLIdentifier("c".to_string())),
+                op: Plus,
+                right: Box::new(SQLIdentifier("d".to_string()))
+            })))
+        },
+        verified_expr(sql)
+    );
+}
+
+#[test]
+fn parse_searched_case_expression() {
+    let sql = "SELECT CASE WHEN bar IS NULL THEN 'null' WHEN bar = 0 THEN '=0' WHEN bar >= 0 THEN '>=0' ELSE '<0' END FROM foo";
+    use self::ASTNode::{SQLBinaryExpr, SQLCase, SQLIdentifier, SQLIsNull, SQLValue};
+    use self::SQLOperator::*;
+    let select = verified_only_select(sql);
+    assert_eq!(
+        &SQLCase {
+            operand: None,
+            conditions: vec![
+                SQLIsNull(Box::new(SQLIdentifier("bar".to_string()))),
+                SQLBinaryExpr {
+                    left: Box::new(SQLIdentifier("bar".to_string())),
+                    op: Eq,
+                    right: Box::new(SQLValue(Value::Long(0)))
+                },
+                SQLBinaryExpr {
+                    left: Box::new(SQLIdentifier("bar".to_string())),
+                    op: GtEq,
+                    right: Box::new(SQLValue(Value::Long(0)))
+                }
+            ],
+            results: vec![
+                SQLValue(Value::SingleQuotedString("null".to_string())),
+                SQLValue(Value::SingleQuotedString("=0".to_string())),
+                SQLValue(Value::SingleQuotedString(">=0".to_string()))
+            ],
+            else_result: Some(Box::new(SQLValue(Value::SingleQuotedString(
+                "<0".to_string()
+            ))))
+        },
+        expr_from_projection(only(&select.projection)),
+    );
+}
+
+#[test]
+fn parse_simple_case_expression() {
+    // ANSI calls a CASE expression with an operand "<simple case>"
+    let sql = "SELECT CASE foo WHEN 1 THEN 'Y' ELSE 'N' END";
+    let select = verified_only_select(sql);
+    use self::ASTNode::{SQLCase, SQLIdentifier, SQLValue};
+    assert_eq!(
+        &SQLCase {
+            operand: Some(Box::new(SQLIdentifier("foo".to_string()))),
+            conditions: vec![SQLValue(Value::Long(1))],
+            results: vec![SQLValue(Value::SingleQuotedString("Y".to_string())),],
+            else_result: Some(Box::new(SQLValue(Value::SingleQuotedString(
+                "N".to_string()
+            ))))
+        },
+        expr_from_projection(only(&select.projection)),
+    );
+}
+
+#[test]
+fn parse_from_advanced() {
+    let sql = "SELECT * FROM fn(1, 2) AS foo, schema.bar AS bar WITH (NOLOCK)";
+    let _select = verified_only_select(sql);
+}
+
+#[test]
+fn parse_table_function() {
+    let select = verified_only_select("SELECT * FROM numbers(10)");
+    assert_eq!(
+        Some(TableFactor::Table {
+            lateral: false,
+            name: SQLObjectName(vec!["numbers".to_string()]),
+            partitions: vec![],
+            alias: None,
+            args: Some(vec![ASTNode::SQLValue(Value::Long(10))]),
+            sample: None,
+            with_hints: vec![],
+        }),
+        select.relation
+    );
+
+    let select = verified_only_select("SELECT * FROM file('data.csv', CSV) AS f");
+    assert_eq!(
+        Some(TableFactor::Table {
+            lateral: false,
+            name: SQLObjectName(vec!["file".to_string()]),
+            partitions: vec![],
+            alias: Some(TableAlias {
+                name: "f".to_string(),
+                columns: vec![],
+            }),
+            args: Some(vec![
+                ASTNode::SQLValue(Value::SingleQuotedString("data.csv".to_string())),
+                ASTNode::SQLIdentifier("CSV".to_string()),
+            ]),
+            sample: None,
+            with_hints: vec![],
+        }),
+        select.relation
+    );
+
+    // the alias can name the function's output columns, too
+    let select = verified_only_select("SELECT g.n FROM generate_series(1, 5) AS g (n)");
+    match select.relation {
+        Some(TableFactor::Table { alias, args, .. }) => {
+            assert_eq!(
+                Some(TableAlias {
+                    name: "g".to_string(),
+                    columns: vec!["n".to_string()],
+                }),
+                alias
+            );
+            assert_eq!(
+                Some(vec![
+                    ASTNode::SQLValue(Value::Long(1)),
+                    ASTNode::SQLValue(Value::Long(5)),
+                ]),
+                args
+            );
+        }
+        _ => unreachable!(),
+    }
+    one_statement_parses_to(
+        "SELECT * FROM f(1) t(a, b)",
+        "SELECT * FROM f(1) AS t (a, b)",
+    );
+}
+
+#[test]
+fn parse_implicit_join() {
+    let sql = "SELECT * FROM t1, t2";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        &Join {
+            relation: TableFactor::Table {
+                lateral: false,
+                name: SQLObjectName(vec!["t2".to_string()]),
+                partitions: vec![],
+                alias: None,
+                args: None,
+                sample: None,
+                with_hints: vec![],
+            },
+            join_operator: JoinOperator::Implicit
+        },
+        only(&select.joins),
+    );
+}
+
+#[test]
+fn parse_cross_join() {
+    let sql = "SELECT * FROM t1 CROSS JOIN t2";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        &Join {
+            relation: TableFactor::Table {
+                lateral: false,
+                name: SQLObjectName(vec!["t2".to_string()]),
+                partitions: vec![],
+                alias: None,
+                args: None,
+                sample: None,
+                with_hints: vec![],
+            },
+            join_operator: JoinOperator::Cross
+        },
+        only(&select.joins),
+    );
+}
+
+#[test]
+fn parse_joins_on() {
+    fn join_with_constraint(
+        relation: impl Into<String>,
+        alias: Option<SQLIdent>,
+        f: impl Fn(JoinConstraint) -> JoinOperator,
+    ) -> Join {
+        Join {
+            relation: TableFactor::Table {
+                lateral: false,
+                name: SQLObjectName(vec![relation.into()]),
+                partitions: vec![],
+                alias: alias.map(|name| TableAlias {
+                    name,
+                    columns: vec![],
+                }),
+                args: None,
+                sample: None,
+                with_hints: vec![],
+            },
+            join_operator: f(JoinConstraint::On(ASTNode::SQLBinaryExpr {
+                left: Box::new(ASTNode::SQLIdentifier("c1".into())),
+                op: SQLOperator::Eq,
+                right: Box::new(ASTNode::SQLIdentifier("c2".into())),
+            })),
+        }
+    }
+    // Test parsing of aliases
+    assert_eq!(
+        verified_only_select("SELECT * FROM t1 JOIN t2 AS foo ON c1 = c2").joins,
+        vec![join_with_constraint(
+            "t2",
+            Some("foo".to_string()),
+            JoinOperator::Inner
+        )]
+    );
+    one_statement_parses_to(
+        "SELECT * FROM t1 JOIN t2 foo ON c1 = c2",
+        "SELECT * FROM t1 JOIN t2 AS foo ON c1 = c2",
+    );
+    // Test parsing of different join operators
+    assert_eq!(
+        verified_only_select("SELECT * FROM t1 JOIN t2 ON c1 = c2").joins,
+        vec![join_with_constraint("t2", None, JoinOperator::Inner)]
+    );
+    assert_eq!(
+        verified_only_select("SELECT * FROM t1 LEFT JOIN t2 ON c1 = c2").joins,
+        vec![join_with_constraint("t2", None, JoinOperator::LeftOuter)]
+    );
+    assert_eq!(
+        verified_only_select("SELECT * FROM t1 RIGHT JOIN t2 ON c1 = c2").joins,
+        vec![join_with_constraint("t2", None, JoinOperator::RightOuter)]
+    );
+    assert_eq!(
+        verified_only_select("SELECT * FROM t1 FULL JOIN t2 ON c1 = c2").joins,
+        vec![join_with_constraint("t2", None, JoinOperator::FullOuter)]
+    );
+}
+
+#[test]
+fn parse_joins_using() {
+    fn join_with_constraint(
+        relation: impl Into<String>,
+        alias: Option<SQLIdent>,
+        f: impl Fn(JoinConstraint) -> JoinOperator,
+    ) -> Join {
+        Join {
+            relation: TableFactor::Table {
+                lateral: false,
+                name: SQLObjectName(vec![relation.into()]),
+                partitions: vec![],
+                alias: alias.map(|name| TableAlias {
+                    name,
+                    columns: vec![],
+                }),
+                args: None,
+                sample: None,
+                with_hints: vec![],
+            },
+            join_operator: f(JoinConstraint::Using(vec!["c1".into()])),
+        }
+    }
+    // Test parsing of aliases
+    assert_eq!(
+        verified_only_select("SELECT * FROM t1 JOIN t2 AS foo USING(c1)").joins,
+        vec![join_with_constraint(
+            "t2",
+            Some("foo".to_string()),
+            JoinOperator::Inner
+        )]
+    );
+    one_statement_parses_to(
+        "SELECT * FROM t1 JOIN t2 foo USING(c1)",
+        "SELECT * FROM t1 JOIN t2 AS foo USING(c1)",
+    );
+    // Test parsing of different join operators
+    assert_eq!(
+        verified_only_select("SELECT * FROM t1 JOIN t2 USING(c1)").joins,
+        vec![join_with_constraint("t2", None, JoinOperator::Inner)]
+    );
+    assert_eq!(
+        verified_only_select("SELECT * FROM t1 LEFT JOIN t2 USING(c1)").joins,
+        vec![join_with_constraint("t2", None, JoinOperator::LeftOuter)]
+    );
+    assert_eq!(
+        verified_only_select("SELECT * FROM t1 RIGHT JOIN t2 USING(c1)").joins,
+        vec![join_with_constraint("t2", None, JoinOperator::RightOuter)]
+    );
+    assert_eq!(
+        verified_only_select("SELECT * FROM t1 FULL JOIN t2 USING(c1)").joins,
+        vec![join_with_constraint("t2", None, JoinOperator::FullOuter)]
+    );
+}
+
+#[test]
+fn parse_complex_join() {
+    let sql = "SELECT c1, c2 FROM t1, t4 JOIN t2 ON t2.c = t1.c LEFT JOIN t3 USING(q, c) WHERE t4.c = t1.c";
+    verified_only_select(sql);
+}
+
+#[test]
+fn parse_join_syntax_variants() {
+    one_statement_parses_to(
+        "SELECT c1 FROM t1 INNER JOIN t2 USING(c1)",
+        "SELECT c1 FROM t1 JOIN t2 USING(c1)",
+    );
+    one_statement_parses_to(
+        "SELECT c1 FROM t1 LEFT OUTER JOIN t2 USING(c1)",
+        "SELECT c1 FROM t1 LEFT JOIN t2 USING(c1)",
+    );
+    one_statement_parses_to(
+        "SELECT c1 FROM t1 RIGHT OUTER JOIN t2 USING(c1)",
+        "SELECT c1 FROM t1 RIGHT JOIN t2 USING(c1)",
+    );
+    one_statement_parses_to(
+        "SELECT c1 FROM t1 FULL OUTER JOIN t2 USING(c1)",
+        "SELECT c1 FROM t1 FULL JOIN t2 USING(c1)",
+    );
+}
+
+#[test]
+fn parse_ctes() {
+    let cte_sqls = vec!["SELECT 1 AS foo", "SELECT 2 AS bar"];
+    let with = &format!(
+        "WITH a AS ({}), b AS ({}) SELECT foo + bar FROM a, b",
+        cte_sqls[0], cte_sqls[1]
+    );
+
+    fn assert_ctes_in_select(expected: &[&str], sel: &SQLQuery) {
+        let mut i = 0;
+        for exp in expected {
+            let Cte {
+                query,
+                alias,
+                renamed_columns,
+                search,
+                cycle,
+            } = &sel.ctes[i];
+            assert_eq!(*exp, query.to_string());
+            assert_eq!(if i == 0 { "a" } else { "b" }, alias);
+            assert!(renamed_columns.is_empty());
+            assert_eq!(&None, search);
+            assert_eq!(&None, cycle);
+            i += 1;
+        }
+    }
+
+    // Top-level CTE
+    assert_ctes_in_select(&cte_sqls, &verified_query(with));
+    // CTE in a subquery
+    let sql = &format!("SELECT ({})", with);
+    let select = verified_only_select(sql);
+    match expr_from_projection(only(&select.projection)) {
+        ASTNode::SQLSubquery(ref subquery) => {
+            assert_ctes_in_select(&cte_sqls, subquery.as_ref());
+        }
+        _ => panic!("Expected subquery"),
+    }
+    // CTE in a derived table
+    let sql = &format!("SELECT * FROM ({})", with);
+    let select = verified_only_select(sql);
+    match select.relation {
+        Some(TableFactor::Derived { subquery, .. }) => {
+            assert_ctes_in_select(&cte_sqls, subquery.as_ref())
+        }
+        _ => panic!("Expected derived table"),
+    }
+    // CTE in a view
+    let sql = &format!("CREATE VIEW v AS {}", with);
+    match verified_stmt(sql) {
+        SQLStatement::SQLCreateView { query, .. } => assert_ctes_in_select(&cte_sqls, &query),
+        _ => panic!("Expected CREATE VIEW"),
+    }
+    // CTE in a CTE...
+    let sql = &format!("WITH outer_cte AS ({}) SELECT * FROM outer_cte", with);
+    let select = verified_query(sql);
+    assert_ctes_in_select(&cte_sqls, &only(&select.ctes).query);
+}
+
+#[test]
+fn parse_cte_renamed_columns() {
+    let sql = "WITH cte (col1, col2) AS (SELECT foo, bar FROM baz) SELECT * FROM cte";
+    let query = all_dialects().verified_query(sql);
+    assert_eq!(
+        vec!["col1", "col2"],
+        query.ctes.first().unwrap().renamed_columns
+    );
+}
+
+#[test]
+fn parse_recursive_cte() {
+    let sql = "WITH RECURSIVE nums (n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM nums) \
+               SELECT n FROM nums";
+    let query = verified_query(sql);
+    assert!(query.recursive);
+    assert_eq!("nums", only(&query.ctes).alias);
+
+    assert!(!verified_query("WITH cte AS (SELECT 1) SELECT * FROM cte").recursive);
+}
+
+#[test]
+fn parse_recursive_cte_search() {
+    let sql = "WITH RECURSIVE tree (id, parent_id) AS (SELECT id, parent_id FROM nodes) \
+               SEARCH DEPTH FIRST BY id, parent_id SET ordercol \
+               SELECT * FROM tree ORDER BY ordercol";
+    let query = verified_query(sql);
+    assert_eq!(
+        Some(CteSearch {
+            depth_first: true,
+            columns: vec!["id".to_string(), "parent_id".to_string()],
+            set: "ordercol".to_string(),
+        }),
+        only(&query.ctes).search
+    );
+    assert_eq!(None, only(&query.ctes).cycle);
+
+    verified_query(
+        "WITH RECURSIVE tree AS (SELECT id FROM nodes) \
+         SEARCH BREADTH FIRST BY id SET ordercol SELECT * FROM tree",
+    );
+}
+
+#[test]
+fn parse_recursive_cte_cycle() {
+    let sql = "WITH RECURSIVE graph (id, link) AS (SELECT id, link FROM edges) \
+               CYCLE id SET is_cycle USING path \
+               SELECT * FROM graph";
+    let query = verified_query(sql);
+    assert_eq!(
+        Some(CteCycle {
+            columns: vec!["id".to_string()],
+            set: "is_cycle".to_string(),
+            mark: None,
+            using: "path".to_string(),
+        }),
+        only(&query.ctes).cycle
+    );
+
+    let sql = "WITH RECURSIVE graph AS (SELECT id FROM edges) \
+               SEARCH DEPTH FIRST BY id SET ordercol \
+               CYCLE id SET is_cycle TO 'Y' DEFAULT 'N' USING path \
+               SELECT * FROM graph";
+    let query = verified_query(sql);
+    let cte = only(&query.ctes);
+    assert!(cte.search.is_some());
+    assert_eq!(
+        Some((
+            Value::SingleQuotedString("Y".to_string()),
+            Value::SingleQuotedString("N".to_string())
+        )),
+        cte.cycle.as_ref().unwrap().mark
+    );
+}
+
+#[test]
+fn parse_json_value() {
+    let sql = "SELECT JSON_VALUE(data, '$.x' RETURNING int DEFAULT 0 ON ERROR) FROM t";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        &ASTNode::SQLJsonFunction {
+            function: SQLJsonFunctionName::JsonValue,
+            json_expr: Box::new(ASTNode::SQLIdentifier("data".to_string())),
+            json_path: "$.x".to_string(),
+            returning: Some(SQLType::Int),
+            on_empty: None,
+            on_error: Some(SQLJsonBehavior::Default(Box::new(ASTNode::SQLValue(
+                Value::Long(0)
+            )))),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    let sql = "SELECT JSON_VALUE(data, '$.x' NULL ON EMPTY ERROR ON ERROR) FROM t";
+    let select = verified_only_select(sql);
+    match expr_from_projection(only(&select.projection)) {
+        ASTNode::SQLJsonFunction {
+            returning,
+            on_empty,
+            on_error,
+            ..
+        } => {
+            assert_eq!(&None, returning);
+            assert_eq!(&Some(SQLJsonBehavior::Null), on_empty);
+            assert_eq!(&Some(SQLJsonBehavior::Error), on_error);
+        }
+        _ => panic!("Expected JSON_VALUE"),
+    }
+
+    verified_only_select("SELECT JSON_QUERY(data, '$.items' EMPTY ARRAY ON EMPTY) FROM t");
+
+    // the ON EMPTY clause must come before ON ERROR
+    let res = parse_sql_statements("SELECT JSON_VALUE(data, '$.x' ERROR ON ERROR NULL ON EMPTY)");
+    assert_eq!(
+        ParserError::ParserError("Expected ), found: NULL at line 1, column 46".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_json_exists() {
+    let sql = "SELECT * FROM t WHERE JSON_EXISTS(data, '$.tags[*] ? (@ == \"a\")' FALSE ON ERROR)";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        ASTNode::SQLJsonFunction {
+            function: SQLJsonFunctionName::JsonExists,
+            json_expr: Box::new(ASTNode::SQLIdentifier("data".to_string())),
+            json_path: "$.tags[*] ? (@ == \"a\")".to_string(),
+            returning: None,
+            on_empty: None,
+            on_error: Some(SQLJsonBehavior::False),
+        },
+        select.selection.unwrap()
+    );
+
+    // without parens, `json_exists` is just an identifier
+    verified_only_select("SELECT json_exists FROM t");
+}
+
+#[test]
+fn parse_json_object() {
+    let sql = "SELECT JSON_OBJECT('a' VALUE 1, 'b' VALUE t.b) FROM t";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        &ASTNode::SQLFunction {
+            name: SQLObjectName(vec!["JSON_OBJECT".to_string()]),
+            args: vec![
+                ASTNode::SQLJsonKeyValue {
+                    key: Box::new(ASTNode::SQLValue(Value::SingleQuotedString(
+                        "a".to_string()
+                    ))),
+                    value: Box::new(ASTNode::SQLValue(Value::Long(1))),
+                },
+                ASTNode::SQLJsonKeyValue {
+                    key: Box::new(ASTNode::SQLValue(Value::SingleQuotedString(
+                        "b".to_string()
+                    ))),
+                    value: Box::new(ASTNode::SQLCompoundIdentifier(vec![
+                        "t".to_string(),
+                        "b".to_string()
+                    ])),
+                },
+            ],
+            order_by: vec![],
+            within_group: vec![],
+            over: None,
+            distinct: false,
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    verified_only_select("SELECT JSON_OBJECT() FROM t");
+    verified_only_select("SELECT JSON_OBJECTAGG(k VALUE v) FROM t GROUP BY g");
+
+    // only the first argument decides between the two forms
+    let res = parse_sql_statements("SELECT JSON_OBJECT('a' VALUE 1, 'b', 2)");
+    assert_eq!(
+        ParserError::ParserError("Expected VALUE, found: , at line 1, column 36".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_json_array() {
+    let sql = "SELECT JSON_ARRAY(1, 2) FROM t";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        &ASTNode::SQLFunction {
+            name: SQLObjectName(vec!["JSON_ARRAY".to_string()]),
+            args: vec![
+                ASTNode::SQLValue(Value::Long(1)),
+                ASTNode::SQLValue(Value::Long(2)),
+            ],
+            order_by: vec![],
+            within_group: vec![],
+            over: None,
+            distinct: false,
+        },
+        expr_from_projection(only(&select.projection))
+    );
+}
+
+#[test]
+fn parse_json_table() {
+    let sql = "SELECT jt.id, jt.name FROM orders, \
+               JSON_TABLE(orders.data, '$.items[*]' \
+               COLUMNS (id int PATH '$.id', name text PATH '$.''name''')) AS jt";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        TableFactor::JsonTable {
+            json_expr: ASTNode::SQLCompoundIdentifier(vec![
+                "orders".to_string(),
+                "data".to_string()
+            ]),
+            json_path: "$.items[*]".to_string(),
+            columns: vec![
+                JsonTableColumn {
+                    name: "id".to_string(),
+                    data_type: SQLType::Int,
+                    path: "$.id".to_string(),
+                },
+                JsonTableColumn {
+                    name: "name".to_string(),
+                    data_type: SQLType::Text,
+                    path: "$.'name'".to_string(),
+                },
+            ],
+            alias: Some(TableAlias {
+                name: "jt".to_string(),
+                columns: vec![],
+            }),
+        },
+        only(&select.joins).relation
+    );
+
+    // a table that merely happens to be named `json_table`
+    let select = verified_only_select("SELECT * FROM json_table");
+    match select.relation {
+        Some(TableFactor::Table { name, .. }) => {
+            assert_eq!(SQLObjectName(vec!["json_table".to_string()]), name)
+        }
+        _ => panic!("Expected a table named json_table"),
+    }
+
+    let res = parse_sql_statements("SELECT * FROM JSON_TABLE(data, '$' COLUMNS (id INT))");
+    assert_eq!(
+        ParserError::ParserError("Expected PATH, found: ) at line 1, column 51".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_unnest() {
+    let select = verified_only_select("SELECT x FROM UNNEST(a) AS t (x)");
+    assert_eq!(
+        Some(TableFactor::Unnest {
+            array_expr: ASTNode::SQLIdentifier("a".to_string()),
+            alias: Some(TableAlias {
+                name: "t".to_string(),
+                columns: vec!["x".to_string()],
+            }),
+            with_offset: false,
+        }),
+        select.relation
+    );
+
+    let select = verified_only_select("SELECT * FROM UNNEST(a) WITH OFFSET AS t");
+    assert_eq!(
+        Some(TableFactor::Unnest {
+            array_expr: ASTNode::SQLIdentifier("a".to_string()),
+            alias: Some(TableAlias {
+                name: "t".to_string(),
+                columns: vec![],
+            }),
+            with_offset: true,
+        }),
+        select.relation
+    );
+
+    // any expression is accepted as the array
+    verified_only_select("SELECT * FROM orders, UNNEST(string_to_array(orders.tags, ','))");
+
+    // a table that merely happens to be named `unnest`
+    let select = verified_only_select("SELECT * FROM unnest");
+    match select.relation {
+        Some(TableFactor::Table { name, .. }) => {
+            assert_eq!(SQLObjectName(vec!["unnest".to_string()]), name)
+        }
+        _ => panic!("Expected a table named unnest"),
+    }
+}
+
+#[test]
+fn parse_lateral_function() {
+    let sql = "SELECT * FROM t, LATERAL generate_series(1, t.n) AS s";
+    let select = verified_only_select(sql);
+    match &only(&select.joins).relation {
+        TableFactor::Table {
+            lateral,
+            name,
+            alias,
+            args,
+            ..
+        } => {
+            assert!(lateral);
+            assert_eq!("generate_series", name.to_string());
+            assert_eq!("s", alias.as_ref().unwrap().name);
+            assert_eq!(
+                Some(vec![
+                    ASTNode::SQLValue(Value::Long(1)),
+                    ASTNode::SQLCompoundIdentifier(vec!["t".to_string(), "n".to_string()])
+                ]),
+                *args
+            );
+        }
+        _ => panic!("Expected TableFactor::Table"),
+    }
+
+    verified_stmt("SELECT * FROM t JOIN LATERAL f(t.a) ON t.a = 1");
+
+    // an empty argument list is kept, unlike the absent one of a table
+    let select = verified_only_select("SELECT * FROM t, LATERAL f() AS x");
+    match &only(&select.joins).relation {
+        TableFactor::Table { lateral, args, .. } => {
+            assert!(lateral);
+            assert_eq!(Some(vec![]), *args);
+        }
+        _ => panic!("Expected TableFactor::Table"),
+    }
+
+    let res = parse_sql_statements("SELECT * FROM t, LATERAL u");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected subquery or function call after LATERAL, found: EOF".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_lateral_derived() {
+    let sql = "SELECT * FROM t, LATERAL (SELECT * FROM u WHERE u.a = t.a) AS v";
+    let select = verified_only_select(sql);
+    match &only(&select.joins).relation {
+        TableFactor::Derived { lateral, .. } => assert!(lateral),
+        _ => panic!("Expected TableFactor::Derived"),
+    }
+}
+
+#[test]
+fn parse_derived_tables() {
+    let sql = "SELECT a.x, b.y FROM (SELECT x FROM foo) AS a CROSS JOIN (SELECT y FROM bar) AS b";
+    let _ = verified_only_select(sql);
+    //TODO: add assertions
+}
+
+#[test]
+fn parse_union() {
+    // TODO: add assertions
+    verified_stmt("SELECT 1 UNION SELECT 2");
+    verified_stmt("SELECT 1 UNION ALL SELECT 2");
+    verified_stmt("SELECT 1 EXCEPT SELECT 2");
+    verified_stmt("SELECT 1 EXCEPT ALL SELECT 2");
+    verified_stmt("SELECT 1 INTERSECT SELECT 2");
+    verified_stmt("SELECT 1 INTERSECT ALL SELECT 2");
+    verified_stmt("SELECT 1 UNION SELECT 2 UNION SELECT 3");
+    verified_stmt("SELECT 1 EXCEPT SELECT 2 UNION SELECT 3"); // Union[Except[1,2], 3]
+    verified_stmt("SELECT 1 INTERSECT (SELECT 2 EXCEPT SELECT 3)");
+    verified_stmt("WITH cte AS (SELECT 1 AS foo) (SELECT foo FROM cte ORDER BY 1 LIMIT 1)");
+    verified_stmt("SELECT 1 UNION (SELECT 2 ORDER BY 1 LIMIT 1)");
+    verified_stmt("SELECT 1 UNION SELECT 2 INTERSECT SELECT 3"); // Union[1, Intersect[2,3]]
+    verified_stmt("SELECT foo FROM tab UNION SELECT bar FROM TAB");
+}
+
+#[test]
+fn parse_multiple_statements() {
+    fn test_with(sql1: &str, sql2_kw: &str, sql2_rest: &str) {
+        // Check that a string consisting of two statements delimited by a semicolon
+        // parses the same as both statements individually:
+        let res = parse_sql_statements(&(sql1.to_owned() + ";" + sql2_kw + sql2_rest));
+        assert_eq!(
+            vec![
+                one_statement_parses_to(&sql1, ""),
+                one_statement_parses_to(&(sql2_kw.to_owned() + sql2_rest), ""),
+            ],
+            res.unwrap()
+        );
+        // Check that extra semicolon at the end is stripped by normalization:
+        one_statement_parses_to(&(sql1.to_owned() + ";"), sql1);
+        // Check that forgetting the semicolon results in an error:
+        let res = parse_sql_statements(&(sql1.to_owned() + " " + sql2_kw + sql2_rest));
+        assert_eq!(
+            ParserError::ParserError(format!(
+                "Expected end of statement, found: {} at line 1, column {}",
+                sql2_kw,
+                sql1.len() + 2
+            )),
+            res.unwrap_err()
+        );
+    }
+    test_with("SELECT foo", "SELECT", " bar");
+    // ensure that SELECT/WITH is not parsed as a table or column alias if ';'
+    // separating the statements is omitted:
+    test_with("SELECT foo FROM baz", "SELECT", " bar");
+    test_with("SELECT foo", "WITH", " cte AS (SELECT 1 AS s) SELECT bar");
+    test_with(
+        "SELECT foo FROM baz",
+        "WITH",
+        " cte AS (SELECT 1 AS s) SELECT bar",
+    );
+    test_with("DELETE FROM foo", "SELECT", " bar");
+    test_with("INSERT INTO foo VALUES (1)", "SELECT", " bar");
+    test_with("CREATE TABLE foo (baz int)", "SELECT", " bar");
+    // Make sure that empty statements do not cause an error:
+    let res = parse_sql_statements(";;");
+    assert_eq!(0, res.unwrap().len());
+}
+
+#[test]
+fn parse_scalar_subqueries() {
+    use self::ASTNode::*;
+    let sql = "(SELECT 1) + (SELECT 2)";
+    assert_matches!(verified_expr(sql), SQLBinaryExpr {
+        op: SQLOperator::Plus, ..
+        //left: box SQLSubquery { .. },
+        //right: box SQLSubquery { .. },
+    });
+}
+
+#[test]
+fn parse_create_view() {
+    let sql = "CREATE VIEW myschema.myview AS SELECT foo FROM bar";
+    match verified_stmt(sql) {
+        SQLStatement::SQLCreateView {
+            name,
+            columns,
+            query,
+            materialized,
+            with_options,
+        } => {
+            assert_eq!("myschema.myview", name.to_string());
+            assert_eq!(Vec::<SQLIdent>::new(), columns);
+            assert_eq!("SELECT foo FROM bar", query.to_string());
+            assert!(!materialized);
+            assert_eq!(Vec::<SQLOption>::new(), with_options);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_create_view_with_columns() {
+    let sql = "CREATE VIEW v (a, b) AS SELECT x, y FROM t";
+    match verified_stmt(sql) {
+        SQLStatement::SQLCreateView {
+            name,
+            columns,
+            query,
+            materialized,
+            with_options,
+        } => {
+            assert_eq!("v", name.to_string());
+            assert_eq!(vec!["a".to_string(), "b".to_string()], columns);
+            assert_eq!("SELECT x, y FROM t", query.to_string());
+            assert!(!materialized);
+            assert_eq!(Vec::<SQLOption>::new(), with_options);
+        }
+        _ => unreachable!(),
+    }
+
+    let sql = r#"CREATE VIEW v ("a b", "C") AS SELECT x, y FROM t"#;
+    match verified_stmt(sql) {
+        SQLStatement::SQLCreateView { columns, .. } => {
+            assert_eq!(vec![r#""a b""#.to_string(), r#""C""#.to_string()], columns);
+        }
+        _ => unreachable!(),
+    }
+
+    let res = parse_sql_statements("CREATE VIEW v () AS SELECT x FROM t");
+    assert_eq!(
+        ParserError::ParserError("Expected identifier, found: ) at line 1, column 16".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_create_view_with_options() {
+    let sql = "CREATE VIEW v (a) WITH (check_option = 'local', security_barrier = true) AS SELECT x FROM t";
+    match verified_stmt(sql) {
+        SQLStatement::SQLCreateView { with_options, .. } => {
+            assert_eq!(
+                vec![
+                    SQLOption {
+                        name: "check_option".to_string(),
+                        value: Value::SingleQuotedString("local".to_string()),
+                    },
+                    SQLOption {
+                        name: "security_barrier".to_string(),
+                        value: Value::Boolean(true),
+                    },
+                ],
+                with_options
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_create_materialized_view() {
+    let sql = "CREATE MATERIALIZED VIEW myschema.myview AS SELECT foo FROM bar";
+    match verified_stmt(sql) {
+        SQLStatement::SQLCreateView {
+            name,
+            columns,
+            query,
+            materialized,
+            with_options,
+        } => {
+            assert_eq!("myschema.myview", name.to_string());
+            assert_eq!(Vec::<SQLIdent>::new(), columns);
+            assert_eq!("SELECT foo FROM bar", query.to_string());
+            assert!(materialized);
+            assert_eq!(Vec::<SQLOption>::new(), with_options);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_drop_table() {
+    let sql = "DROP TABLE foo";
+    match verified_stmt(sql) {
+        SQLStatement::SQLDrop {
+            object_type,
+            if_exists,
+            names,
+            cascade,
+        } => {
+            assert_eq!(false, if_exists);
+            assert_eq!(SQLObjectType::Table, object_type);
+            assert_eq!(
+                vec!["foo"],
+                names.iter().map(|n| n.to_string()).collect::<Vec<_>>()
+            );
+            assert_eq!(false, cascade);
+        }
+        _ => assert!(false),
+    }
+
+    let sql = "DROP TABLE IF EXISTS foo, bar CASCADE";
+    match verified_stmt(sql) {
+        SQLStatement::SQLDrop {
+            object_type,
+            if_exists,
+            names,
+            cascade,
+        } => {
+            assert_eq!(true, if_exists);
+            assert_eq!(SQLObjectType::Table, object_type);
+            assert_eq!(
+                vec!["foo", "bar"],
+                names.iter().map(|n| n.to_string()).collect::<Vec<_>>()
+            );
+            assert_eq!(true, cascade);
+        }
+        _ => assert!(false),
+    }
+
+    let sql = "DROP TABLE";
+    assert_eq!(
+        ParserError::ParserError("Expected identifier, found: EOF".to_string()),
+        parse_sql_statements(sql).unwrap_err(),
+    );
+
+    let sql = "DROP TABLE IF EXISTS foo, bar CASCADE RESTRICT";
+    assert_eq!(
+        ParserError::ParserError(
+            "Cannot specify both CASCADE and RESTRICT in DROP at line 1, column 39".to_string()
+        ),
+        parse_sql_statements(sql).unwrap_err(),
+    );
+}
+
+#[test]
+fn parse_drop_view() {
+    let sql = "DROP VIEW myschema.myview";
+    match verified_stmt(sql) {
+        SQLStatement::SQLDrop {
+            names, object_type, ..
+        } => {
+            assert_eq!(
+                vec!["myschema.myview"],
+                names.iter().map(|n| n.to_string()).collect::<Vec<_>>()
+            );
+            assert_eq!(SQLObjectType::View, object_type);
+        }
+        _ => assert!(false),
+    }
+}
+
+#[test]
+fn parse_create_index() {
+    let sql = "CREATE UNIQUE INDEX IF NOT EXISTS idx_name ON test (name, age)";
+    match verified_stmt(sql) {
+        SQLStatement::SQLCreateIndex {
+            unique,
+            if_not_exists,
+            name,
+            table_name,
+            method,
+            columns,
+        } => {
+            assert!(unique);
+            assert!(if_not_exists);
+            assert_eq!("idx_name", name.to_string());
+            assert_eq!("test", table_name.to_string());
+            assert_eq!(None, method);
+            assert_eq!(vec!["name", "age"], columns);
+        }
+        _ => unreachable!(),
+    }
+
+    for m in &["btree", "gin", "gist", "hash", "brin"] {
+        let sql = format!("CREATE INDEX idx ON myschema.t USING {} (a)", m);
+        match verified_stmt(&sql) {
+            SQLStatement::SQLCreateIndex { unique, method, .. } => {
+                assert!(!unique);
+                assert_eq!(Some(m.to_string()), method);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    let res = parse_sql_statements("CREATE INDEX idx ON t USING btree");
     assert_eq!(
-        &SQLCase {
-            operand: Some(Box::new(SQLIdentifier("foo".to_string()))),
-            conditions: vec![SQLValue(Value::Long(1))],
-            results: vec![SQLValue(Value::SingleQuotedString("Y".to_string())),],
-            else_result: Some(Box::new(SQLValue(Value::SingleQuotedString(
-                "N".to_string()
-            ))))
-        },
-        expr_from_projection(only(&select.projection)),
+        ParserError::ParserError(
+            "Expected a list of columns in parentheses, found: EOF".to_string()
+        ),
+        res.unwrap_err()
     );
 }
 
 #[test]
-fn parse_from_advanced() {
-    let sql = "SELECT * FROM fn(1, 2) AS foo, schema.bar AS bar WITH (NOLOCK)";
-    let _select = verified_only_select(sql);
+fn parse_drop_index() {
+    match verified_stmt("DROP INDEX IF EXISTS idx_a, myschema.idx_b") {
+        SQLStatement::SQLDrop {
+            names,
+            object_type,
+            if_exists,
+            ..
+        } => {
+            assert_eq!(
+                vec!["idx_a", "myschema.idx_b"],
+                names.iter().map(|n| n.to_string()).collect::<Vec<_>>()
+            );
+            assert_eq!(SQLObjectType::Index, object_type);
+            assert!(if_exists);
+        }
+        _ => unreachable!(),
+    }
 }
 
 #[test]
-fn parse_implicit_join() {
-    let sql = "SELECT * FROM t1, t2";
-    let select = verified_only_select(sql);
-    assert_eq!(
-        &Join {
-            relation: TableFactor::Table {
-                name: SQLObjectName(vec!["t2".to_string()]),
-                alias: None,
-                args: vec![],
-                with_hints: vec![],
-            },
-            join_operator: JoinOperator::Implicit
-        },
-        only(&select.joins),
-    );
+fn parse_create_schema() {
+    match verified_stmt("CREATE SCHEMA IF NOT EXISTS myschema") {
+        SQLStatement::SQLCreateSchema {
+            schema_name,
+            if_not_exists,
+        } => {
+            assert_eq!("myschema", schema_name.to_string());
+            assert!(if_not_exists);
+        }
+        _ => unreachable!(),
+    }
+    match verified_stmt("CREATE SCHEMA mydb.myschema") {
+        SQLStatement::SQLCreateSchema {
+            schema_name,
+            if_not_exists,
+        } => {
+            assert_eq!("mydb.myschema", schema_name.to_string());
+            assert!(!if_not_exists);
+        }
+        _ => unreachable!(),
+    }
 }
 
 #[test]
-fn parse_cross_join() {
-    let sql = "SELECT * FROM t1 CROSS JOIN t2";
-    let select = verified_only_select(sql);
-    assert_eq!(
-        &Join {
-            relation: TableFactor::Table {
-                name: SQLObjectName(vec!["t2".to_string()]),
-                alias: None,
-                args: vec![],
-                with_hints: vec![],
-            },
-            join_operator: JoinOperator::Cross
-        },
-        only(&select.joins),
-    );
+fn parse_drop_schema() {
+    match verified_stmt("DROP SCHEMA myschema CASCADE") {
+        SQLStatement::SQLDrop {
+            object_type,
+            if_exists,
+            names,
+            cascade,
+        } => {
+            assert_eq!(SQLObjectType::Schema, object_type);
+            assert!(!if_exists);
+            assert_eq!(
+                vec!["myschema"],
+                names.iter().map(|n| n.to_string()).collect::<Vec<_>>()
+            );
+            assert!(cascade);
+        }
+        _ => unreachable!(),
+    }
+    verified_stmt("DROP SCHEMA IF EXISTS myschema");
 }
 
 #[test]
-fn parse_joins_on() {
-    fn join_with_constraint(
-        relation: impl Into<String>,
-        alias: Option<SQLIdent>,
-        f: impl Fn(JoinConstraint) -> JoinOperator,
-    ) -> Join {
-        Join {
-            relation: TableFactor::Table {
-                name: SQLObjectName(vec![relation.into()]),
-                alias,
-                args: vec![],
-                with_hints: vec![],
-            },
-            join_operator: f(JoinConstraint::On(ASTNode::SQLBinaryExpr {
-                left: Box::new(ASTNode::SQLIdentifier("c1".into())),
-                op: SQLOperator::Eq,
-                right: Box::new(ASTNode::SQLIdentifier("c2".into())),
-            })),
+fn parse_create_sequence() {
+    let sql =
+        "CREATE SEQUENCE seq INCREMENT BY 2 MINVALUE 0 MAXVALUE 100 START WITH 1 CACHE 10 CYCLE";
+    match verified_stmt(sql) {
+        SQLStatement::SQLCreateSequence {
+            name,
+            temporary,
+            if_not_exists,
+            options,
+        } => {
+            assert_eq!("seq", name.to_string());
+            assert!(!temporary);
+            assert!(!if_not_exists);
+            assert_eq!(
+                vec![
+                    SQLSequenceOption::IncrementBy(2),
+                    SQLSequenceOption::MinValue(Some(0)),
+                    SQLSequenceOption::MaxValue(Some(100)),
+                    SQLSequenceOption::StartWith(1),
+                    SQLSequenceOption::Cache(10),
+                    SQLSequenceOption::Cycle(true),
+                ],
+                options
+            );
         }
+        _ => unreachable!(),
     }
-    // Test parsing of aliases
-    assert_eq!(
-        verified_only_select("SELECT * FROM t1 JOIN t2 AS foo ON c1 = c2").joins,
-        vec![join_with_constraint(
-            "t2",
-            Some("foo".to_string()),
-            JoinOperator::Inner
-        )]
-    );
+
+    // Options are accepted in any order and serialized in the canonical order
     one_statement_parses_to(
-        "SELECT * FROM t1 JOIN t2 foo ON c1 = c2",
-        "SELECT * FROM t1 JOIN t2 AS foo ON c1 = c2",
-    );
-    // Test parsing of different join operators
-    assert_eq!(
-        verified_only_select("SELECT * FROM t1 JOIN t2 ON c1 = c2").joins,
-        vec![join_with_constraint("t2", None, JoinOperator::Inner)]
-    );
-    assert_eq!(
-        verified_only_select("SELECT * FROM t1 LEFT JOIN t2 ON c1 = c2").joins,
-        vec![join_with_constraint("t2", None, JoinOperator::LeftOuter)]
-    );
-    assert_eq!(
-        verified_only_select("SELECT * FROM t1 RIGHT JOIN t2 ON c1 = c2").joins,
-        vec![join_with_constraint("t2", None, JoinOperator::RightOuter)]
+        "CREATE SEQUENCE seq START 1 CYCLE INCREMENT 2 MAXVALUE 100 CACHE 10 MINVALUE 0",
+        sql,
     );
+
+    match verified_stmt(
+        "CREATE TEMPORARY SEQUENCE IF NOT EXISTS myschema.seq INCREMENT BY -1 NO MINVALUE NO MAXVALUE NO CYCLE",
+    ) {
+        SQLStatement::SQLCreateSequence {
+            name,
+            temporary,
+            if_not_exists,
+            options,
+        } => {
+            assert_eq!("myschema.seq", name.to_string());
+            assert!(temporary);
+            assert!(if_not_exists);
+            assert_eq!(
+                vec![
+                    SQLSequenceOption::IncrementBy(-1),
+                    SQLSequenceOption::MinValue(None),
+                    SQLSequenceOption::MaxValue(None),
+                    SQLSequenceOption::Cycle(false),
+                ],
+                options
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    verified_stmt("CREATE SEQUENCE seq");
+
+    let res = parse_sql_statements("CREATE SEQUENCE seq CACHE 1 CACHE 2");
     assert_eq!(
-        verified_only_select("SELECT * FROM t1 FULL JOIN t2 ON c1 = c2").joins,
-        vec![join_with_constraint("t2", None, JoinOperator::FullOuter)]
+        ParserError::ParserError(
+            "Conflicting or redundant sequence option: CACHE 2 at line 1, column 29".to_string()
+        ),
+        res.unwrap_err()
     );
 }
 
 #[test]
-fn parse_joins_using() {
-    fn join_with_constraint(
-        relation: impl Into<String>,
-        alias: Option<SQLIdent>,
-        f: impl Fn(JoinConstraint) -> JoinOperator,
-    ) -> Join {
-        Join {
-            relation: TableFactor::Table {
-                name: SQLObjectName(vec![relation.into()]),
-                alias,
-                args: vec![],
-                with_hints: vec![],
-            },
-            join_operator: f(JoinConstraint::Using(vec!["c1".into()])),
+fn parse_alter_sequence() {
+    match verified_stmt("ALTER SEQUENCE seq INCREMENT BY 5 NO MAXVALUE") {
+        SQLStatement::SQLAlterSequence { name, options } => {
+            assert_eq!("seq", name.to_string());
+            assert_eq!(
+                vec![
+                    SQLSequenceOption::IncrementBy(5),
+                    SQLSequenceOption::MaxValue(None),
+                ],
+                options
+            );
         }
+        _ => unreachable!(),
     }
-    // Test parsing of aliases
-    assert_eq!(
-        verified_only_select("SELECT * FROM t1 JOIN t2 AS foo USING(c1)").joins,
-        vec![join_with_constraint(
-            "t2",
-            Some("foo".to_string()),
-            JoinOperator::Inner
-        )]
-    );
-    one_statement_parses_to(
-        "SELECT * FROM t1 JOIN t2 foo USING(c1)",
-        "SELECT * FROM t1 JOIN t2 AS foo USING(c1)",
+}
+
+#[test]
+fn parse_drop_sequence() {
+    match verified_stmt("DROP SEQUENCE IF EXISTS seq1, seq2 CASCADE") {
+        SQLStatement::SQLDrop {
+            object_type,
+            if_exists,
+            names,
+            cascade,
+        } => {
+            assert_eq!(SQLObjectType::Sequence, object_type);
+            assert!(if_exists);
+            assert_eq!(
+                vec!["seq1", "seq2"],
+                names.iter().map(|n| n.to_string()).collect::<Vec<_>>()
+            );
+            assert!(cascade);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn rename_table() {
+    let from = SQLObjectName(vec!["customer".to_string()]);
+    let to = SQLObjectName(vec!["public".to_string(), "client".to_string()]);
+
+    let stmt = verified_stmt(
+        "SELECT customer.id, customer.* FROM customer JOIN orders ON customer.id = orders.customer_id \
+         WHERE customer.id IN (SELECT id FROM customer)",
     );
-    // Test parsing of different join operators
     assert_eq!(
-        verified_only_select("SELECT * FROM t1 JOIN t2 USING(c1)").joins,
-        vec![join_with_constraint("t2", None, JoinOperator::Inner)]
+        "SELECT public.client.id, public.client.* FROM public.client \
+         JOIN orders ON public.client.id = orders.customer_id \
+         WHERE public.client.id IN (SELECT id FROM public.client)",
+        stmt.rename_table(&from, &to).to_string()
     );
+    // the original statement is left untouched
     assert_eq!(
-        verified_only_select("SELECT * FROM t1 LEFT JOIN t2 USING(c1)").joins,
-        vec![join_with_constraint("t2", None, JoinOperator::LeftOuter)]
+        "SELECT customer.id, customer.* FROM customer JOIN orders ON customer.id = orders.customer_id \
+         WHERE customer.id IN (SELECT id FROM customer)",
+        stmt.to_string()
     );
+
+    let stmt = verified_stmt("DELETE FROM customer WHERE customer.id = 1");
     assert_eq!(
-        verified_only_select("SELECT * FROM t1 RIGHT JOIN t2 USING(c1)").joins,
-        vec![join_with_constraint("t2", None, JoinOperator::RightOuter)]
+        "DELETE FROM public.client WHERE public.client.id = 1",
+        stmt.rename_table(&from, &to).to_string()
     );
+
+    let stmt = verified_stmt("TABLE customer UNION TABLE orders");
     assert_eq!(
-        verified_only_select("SELECT * FROM t1 FULL JOIN t2 USING(c1)").joins,
-        vec![join_with_constraint("t2", None, JoinOperator::FullOuter)]
+        "TABLE public.client UNION TABLE orders",
+        stmt.rename_table(&from, &to).to_string()
     );
-}
-
-#[test]
-fn parse_complex_join() {
-    let sql = "SELECT c1, c2 FROM t1, t4 JOIN t2 ON t2.c = t1.c LEFT JOIN t3 USING(q, c) WHERE t4.c = t1.c";
-    verified_only_select(sql);
-}
 
-#[test]
-fn parse_join_syntax_variants() {
-    one_statement_parses_to(
-        "SELECT c1 FROM t1 INNER JOIN t2 USING(c1)",
-        "SELECT c1 FROM t1 JOIN t2 USING(c1)",
-    );
-    one_statement_parses_to(
-        "SELECT c1 FROM t1 LEFT OUTER JOIN t2 USING(c1)",
-        "SELECT c1 FROM t1 LEFT JOIN t2 USING(c1)",
+    // functions, collations and types that happen to have the same name are
+    // left alone
+    let stmt = verified_stmt(
+        "SELECT customer(a), CAST(b AS customer) FROM customer WHERE c COLLATE customer = 1",
     );
-    one_statement_parses_to(
-        "SELECT c1 FROM t1 RIGHT OUTER JOIN t2 USING(c1)",
-        "SELECT c1 FROM t1 RIGHT JOIN t2 USING(c1)",
+    assert_eq!(
+        "SELECT customer(a), CAST(b AS customer) FROM public.client WHERE c COLLATE customer = 1",
+        stmt.rename_table(&from, &to).to_string()
     );
-    one_statement_parses_to(
-        "SELECT c1 FROM t1 FULL OUTER JOIN t2 USING(c1)",
-        "SELECT c1 FROM t1 FULL JOIN t2 USING(c1)",
+
+    // an empty compound identifier, which the parser never produces, is
+    // left alone as well
+    let mut query = verified_query("SELECT a FROM customer");
+    if let SQLSetExpr::Select(select) = &mut query.body {
+        select.projection = vec![SQLSelectItem::UnnamedExpression(
+            ASTNode::SQLCompoundIdentifier(vec![]),
+        )];
+    }
+    let stmt = SQLStatement::SQLQuery(Box::new(query));
+    assert_eq!(
+        "SELECT  FROM public.client",
+        stmt.rename_table(&from, &to).to_string()
     );
 }
 
 #[test]
-fn parse_ctes() {
-    let cte_sqls = vec!["SELECT 1 AS foo", "SELECT 2 AS bar"];
-    let with = &format!(
-        "WITH a AS ({}), b AS ({}) SELECT foo + bar FROM a, b",
-        cte_sqls[0], cte_sqls[1]
-    );
+fn visit_identifiers() {
+    #[derive(Default)]
+    struct IdentifierCounter {
+        identifiers: usize,
+        functions: Vec<String>,
+    }
 
-    fn assert_ctes_in_select(expected: &[&str], sel: &SQLQuery) {
-        let mut i = 0;
-        for exp in expected {
-            let Cte {
-                query,
-                alias,
-                renamed_columns,
-            } = &sel.ctes[i];
-            assert_eq!(*exp, query.to_string());
-            assert_eq!(if i == 0 { "a" } else { "b" }, alias);
-            assert!(renamed_columns.is_empty());
-            i += 1;
+    impl Visitor for IdentifierCounter {
+        fn visit_identifier(&mut self, _identifier: &ASTNode) {
+            self.identifiers += 1;
         }
-    }
 
-    // Top-level CTE
-    assert_ctes_in_select(&cte_sqls, &verified_query(with));
-    // CTE in a subquery
-    let sql = &format!("SELECT ({})", with);
-    let select = verified_only_select(sql);
-    match expr_from_projection(only(&select.projection)) {
-        ASTNode::SQLSubquery(ref subquery) => {
-            assert_ctes_in_select(&cte_sqls, subquery.as_ref());
+        fn visit_compound_identifier(&mut self, _compound_identifier: &ASTNode) {
+            self.identifiers += 1;
         }
-        _ => panic!("Expected subquery"),
-    }
-    // CTE in a derived table
-    let sql = &format!("SELECT * FROM ({})", with);
-    let select = verified_only_select(sql);
-    match select.relation {
-        Some(TableFactor::Derived { subquery, .. }) => {
-            assert_ctes_in_select(&cte_sqls, subquery.as_ref())
+
+        fn visit_function(&mut self, function: &ASTNode) {
+            if let ASTNode::SQLFunction { name, .. } = function {
+                self.functions.push(name.to_string());
+            }
+            visit::walk_function(self, function);
         }
-        _ => panic!("Expected derived table"),
-    }
-    // CTE in a view
-    let sql = &format!("CREATE VIEW v AS {}", with);
-    match verified_stmt(sql) {
-        SQLStatement::SQLCreateView { query, .. } => assert_ctes_in_select(&cte_sqls, &query),
-        _ => panic!("Expected CREATE VIEW"),
     }
-    // CTE in a CTE...
-    let sql = &format!("WITH outer_cte AS ({}) SELECT * FROM outer_cte", with);
-    let select = verified_query(sql);
-    assert_ctes_in_select(&cte_sqls, &only(&select.ctes).query);
+
+    let stmt = verified_stmt(
+        "SELECT a, t.b, max(c) FROM t \
+         WHERE d IN (SELECT e FROM u WHERE f = 1) ORDER BY lower(g)",
+    );
+    let mut counter = IdentifierCounter::default();
+    counter.visit_statement(&stmt);
+    assert_eq!(7, counter.identifiers);
+    assert_eq!(
+        vec!["max".to_string(), "lower".to_string()],
+        counter.functions
+    );
 }
 
 #[test]
-fn parse_cte_renamed_columns() {
-    let sql = "WITH cte (col1, col2) AS (SELECT foo, bar FROM baz) SELECT * FROM cte";
-    let query = all_dialects().verified_query(sql);
+fn visit_mut_identifiers() {
+    struct Uppercase;
+
+    impl VisitorMut for Uppercase {
+        fn visit_identifier(&mut self, identifier: &mut ASTNode) {
+            if let ASTNode::SQLIdentifier(ident) = identifier {
+                *ident = ident.to_uppercase();
+            }
+        }
+    }
+
+    let mut stmt = verified_stmt("DELETE FROM t WHERE a = b + 1 AND c IN (SELECT d FROM u)");
+    Uppercase.visit_statement(&mut stmt);
     assert_eq!(
-        vec!["col1", "col2"],
-        query.ctes.first().unwrap().renamed_columns
+        "DELETE FROM t WHERE A = B + 1 AND C IN (SELECT D FROM u)",
+        stmt.to_string()
     );
 }
 
 #[test]
-fn parse_derived_tables() {
-    let sql = "SELECT a.x, b.y FROM (SELECT x FROM foo) AS a CROSS JOIN (SELECT y FROM bar) AS b";
-    let _ = verified_only_select(sql);
-    //TODO: add assertions
+fn display_round_trip() {
+    // Each statement is in the canonical form, i.e. it must be reproduced
+    // verbatim by the `Display` impl of the AST it parses to.
+    let corpus = [
+        "SELECT 1, 1.5, 2.0, 'it''s', N'it''s', NULL, true, false",
+        "SELECT - a, NOT b, a + b * c, (a + b) * c, a % b",
+        "SELECT a FROM t WHERE a IS NULL OR b IS NOT NULL AND c <> d",
+        "SELECT a FROM t WHERE a IN (1, 2) AND b NOT IN (SELECT c FROM u)",
+        "SELECT a FROM t WHERE a BETWEEN 1 AND 2 AND b NOT BETWEEN c AND d",
+        "SELECT a FROM t WHERE a LIKE 'x%' AND b NOT LIKE 'y%'",
+        "SELECT CAST(a AS bigint), CAST(b AS numeric(10,2)), CAST(c AS character varying(5))",
+        "SELECT a COLLATE de_DE FROM t",
+        "SELECT CASE WHEN a = 1 THEN 'x' WHEN a = 2 THEN 'y' ELSE 'z' END",
+        "SELECT CASE a WHEN 1 THEN 'x' END",
+        "SELECT COUNT(DISTINCT a), max(b) FROM t",
+        "SELECT row_number() OVER (PARTITION BY a ORDER BY b DESC ROWS BETWEEN 1 PRECEDING AND CURRENT ROW)",
+        "SELECT sum(a) OVER (ORDER BY b RANGE UNBOUNDED PRECEDING)",
+        "SELECT DISTINCT t.*, a AS b, * FROM db.t AS x (c, d)",
+        "SELECT a FROM t1 JOIN t2 ON t1.a = t2.a LEFT JOIN t3 USING(b) CROSS JOIN t4, t5",
+        "SELECT a FROM t1 NATURAL JOIN t2 NATURAL FULL JOIN t3 RIGHT JOIN t4 ON true",
+        "SELECT a FROM (SELECT b FROM t) AS s WHERE a = (SELECT 1)",
+        "SELECT a, count(*) FROM t GROUP BY a HAVING count(*) > 1",
+        "SELECT a FROM t UNION ALL SELECT b FROM u EXCEPT (SELECT c FROM v) ORDER BY a ASC",
+        "WITH RECURSIVE c (n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM c) SELECT n FROM c",
+        "SELECT a FROM t ORDER BY a LIMIT 10 OFFSET 5 ROWS FETCH FIRST 2 ROWS WITH TIES",
+        "SELECT a FROM t FETCH FIRST 10 PERCENT ROWS ONLY",
+        "SELECT a FROM t FOR UPDATE OF t NOWAIT FOR SHARE SKIP LOCKED",
+        "VALUES (1, 'a'), (2, 'b')",
+        "TABLE t",
+        "INSERT INTO t (a, b) VALUES (1, 2), (3, 4)",
+        "INSERT INTO t SELECT * FROM u",
+        "UPDATE t SET a = 1, b = 'x' WHERE c = 2",
+        "DELETE FROM t WHERE a = 1",
+        "CREATE TABLE IF NOT EXISTS t (a int PRIMARY KEY, b text UNIQUE DEFAULT 'x' NOT NULL)",
+        "CREATE VIEW v (a, b) AS SELECT 1, 2",
+        "CREATE MATERIALIZED VIEW v WITH (foo = 'bar') AS SELECT 1",
+        "CREATE SCHEMA IF NOT EXISTS s",
+        "CREATE SEQUENCE s INCREMENT BY 2 NO MINVALUE MAXVALUE 100 START WITH 1 CACHE 5 NO CYCLE",
+        "ALTER TABLE t ADD CONSTRAINT fk FOREIGN KEY (a) REFERENCES u(b)",
+        "DROP TABLE IF EXISTS t, u CASCADE",
+        "DROP VIEW v",
+        "SELECT * FROM t WHERE a = ? AND b IN (?, ?)",
+        "SELECT make_point(x => 1, y => 2), s.f(a, b => c)",
+        "SELECT percentile_cont(0.5) WITHIN GROUP (ORDER BY a DESC) FROM t",
+        "SELECT string_agg(a, ',' ORDER BY a) OVER (PARTITION BY b) FROM t",
+        "CREATE TABLE t (a interval day to second(3), b time(0), c timestamp(6), d float(24))",
+        "UPDATE t SET a = DEFAULT",
+    ];
+    all_dialects().verify_round_trips(&corpus);
 }
 
 #[test]
-fn parse_union() {
-    // TODO: add assertions
-    verified_stmt("SELECT 1 UNION SELECT 2");
-    verified_stmt("SELECT 1 UNION ALL SELECT 2");
-    verified_stmt("SELECT 1 EXCEPT SELECT 2");
-    verified_stmt("SELECT 1 EXCEPT ALL SELECT 2");
-    verified_stmt("SELECT 1 INTERSECT SELECT 2");
-    verified_stmt("SELECT 1 INTERSECT ALL SELECT 2");
-    verified_stmt("SELECT 1 UNION SELECT 2 UNION SELECT 3");
-    verified_stmt("SELECT 1 EXCEPT SELECT 2 UNION SELECT 3"); // Union[Except[1,2], 3]
-    verified_stmt("SELECT 1 INTERSECT (SELECT 2 EXCEPT SELECT 3)");
-    verified_stmt("WITH cte AS (SELECT 1 AS foo) (SELECT foo FROM cte ORDER BY 1 LIMIT 1)");
-    verified_stmt("SELECT 1 UNION (SELECT 2 ORDER BY 1 LIMIT 1)");
-    verified_stmt("SELECT 1 UNION SELECT 2 INTERSECT SELECT 3"); // Union[1, Intersect[2,3]]
-    verified_stmt("SELECT foo FROM tab UNION SELECT bar FROM TAB");
+#[should_panic(expected = "4 failures in 3 statements:\nSELECT  1\n  with GenericSqlDialect")]
+fn display_round_trip_reports_failures() {
+    // one failure per dialect
+    all_dialects().verify_round_trips(&["SELECT 1", "SELECT  1", "SELECT 2"]);
 }
 
 #[test]
-fn parse_multiple_statements() {
-    fn test_with(sql1: &str, sql2_kw: &str, sql2_rest: &str) {
-        // Check that a string consisting of two statements delimited by a semicolon
-        // parses the same as both statements individually:
-        let res = parse_sql_statements(&(sql1.to_owned() + ";" + sql2_kw + sql2_rest));
-        assert_eq!(
-            vec![
-                one_statement_parses_to(&sql1, ""),
-                one_statement_parses_to(&(sql2_kw.to_owned() + sql2_rest), ""),
-            ],
-            res.unwrap()
-        );
-        // Check that extra semicolon at the end is stripped by normalization:
-        one_statement_parses_to(&(sql1.to_owned() + ";"), sql1);
-        // Check that forgetting the semicolon results in an error:
-        let res = parse_sql_statements(&(sql1.to_owned() + " " + sql2_kw + sql2_rest));
-        assert_eq!(
-            ParserError::ParserError("Expected end of statement, found: ".to_string() + sql2_kw),
-            res.unwrap_err()
-        );
+#[cfg(feature = "serde")]
+fn serde_round_trip() {
+    for sql in &[
+        "WITH RECURSIVE t (n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM t) \
+         SELECT DISTINCT n, CAST(n AS double) FROM t AS x (n) WHERE n IN (1, 2.5) \
+         ORDER BY n DESC LIMIT 10",
+        "SELECT count(*) OVER (PARTITION BY a ORDER BY b ROWS UNBOUNDED PRECEDING) \
+         FROM t LEFT JOIN (VALUES (1, 'a')) AS v ON t.a = v.a",
+        "INSERT INTO t (a, b) VALUES (1, NULL), (2, true)",
+        "CREATE TABLE t (a int NOT NULL, b character varying(10) DEFAULT 'x')",
+        "DROP TABLE IF EXISTS a, b CASCADE",
+    ] {
+        let stmt = verified_stmt(sql);
+        let json = serde_json::to_string(&stmt).unwrap();
+        let deserialized: SQLStatement = serde_json::from_str(&json).unwrap();
+        assert_eq!(stmt, deserialized);
+        assert_eq!(*sql, deserialized.to_string());
     }
-    test_with("SELECT foo", "SELECT", " bar");
-    // ensure that SELECT/WITH is not parsed as a table or column alias if ';'
-    // separating the statements is omitted:
-    test_with("SELECT foo FROM baz", "SELECT", " bar");
-    test_with("SELECT foo", "WITH", " cte AS (SELECT 1 AS s) SELECT bar");
-    test_with(
-        "SELECT foo FROM baz",
-        "WITH",
-        " cte AS (SELECT 1 AS s) SELECT bar",
+}
+
+#[test]
+fn parse_invalid_subquery_without_parens() {
+    let res = parse_sql_statements("SELECT SELECT 1 FROM bar WHERE 1=1 FROM baz");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected an expression, found: SELECT at line 1, column 8".to_string()
+        ),
+        res.unwrap_err()
     );
-    test_with("DELETE FROM foo", "SELECT", " bar");
-    test_with("INSERT INTO foo VALUES(1)", "SELECT", " bar");
-    test_with("CREATE TABLE foo (baz int)", "SELECT", " bar");
-    // Make sure that empty statements do not cause an error:
-    let res = parse_sql_statements(";;");
-    assert_eq!(0, res.unwrap().len());
 }
 
 #[test]
-fn parse_scalar_subqueries() {
-    use self::ASTNode::*;
-    let sql = "(SELECT 1) + (SELECT 2)";
-    assert_matches!(verified_expr(sql), SQLBinaryExpr {
-        op: SQLOperator::Plus, ..
-        //left: box SQLSubquery { .. },
-        //right: box SQLSubquery { .. },
-    });
+fn parse_use() {
+    match verified_stmt("USE mydb") {
+        SQLStatement::SQLUse { db_name } => assert_eq!("mydb", db_name.to_string()),
+        _ => unreachable!(),
+    }
+    verified_stmt("USE mycatalog.myschema");
+
+    let statements = parse_sql_statements("USE mydb; SELECT 1").unwrap();
+    assert_eq!(2, statements.len());
+    assert_eq!("USE mydb", statements[0].to_string());
+    assert_eq!("SELECT 1", statements[1].to_string());
 }
 
 #[test]
-fn parse_create_view() {
-    let sql = "CREATE VIEW myschema.myview AS SELECT foo FROM bar";
-    match verified_stmt(sql) {
-        SQLStatement::SQLCreateView {
-            name,
-            query,
-            materialized,
+fn parse_grant() {
+    let sql = "GRANT SELECT, INSERT ON TABLE foo, bar TO alice, bob";
+    match one_statement_parses_to(sql, "GRANT SELECT, INSERT ON foo, bar TO alice, bob") {
+        SQLStatement::SQLGrant {
+            privileges,
+            objects,
+            grantees,
+            with_grant_option,
         } => {
-            assert_eq!("myschema.myview", name.to_string());
-            assert_eq!("SELECT foo FROM bar", query.to_string());
-            assert!(!materialized);
+            assert_eq!(
+                Privileges::Privileges(vec![
+                    Privilege::Select { columns: None },
+                    Privilege::Insert { columns: None },
+                ]),
+                privileges
+            );
+            assert_eq!(
+                GrantObjects::Tables(vec![
+                    SQLObjectName(vec!["foo".to_string()]),
+                    SQLObjectName(vec!["bar".to_string()]),
+                ]),
+                objects
+            );
+            assert_eq!(vec!["alice", "bob"], grantees);
+            assert!(!with_grant_option);
         }
         _ => unreachable!(),
     }
+
+    one_statement_parses_to(
+        "GRANT ALL ON foo TO alice",
+        "GRANT ALL PRIVILEGES ON foo TO alice",
+    );
+    one_statement_parses_to(
+        "GRANT TEMP, CONNECT ON foo TO alice",
+        "GRANT TEMPORARY, CONNECT ON foo TO alice",
+    );
+
+    let res = parse_sql_statements("GRANT SELECT, FOO ON foo TO alice");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected a privilege, found: FOO at line 1, column 15".to_string()
+        ),
+        res.unwrap_err()
+    );
 }
 
 #[test]
-fn parse_create_materialized_view() {
-    let sql = "CREATE MATERIALIZED VIEW myschema.myview AS SELECT foo FROM bar";
+fn parse_grant_all_tables_in_schema() {
+    let sql = "GRANT ALL PRIVILEGES ON ALL TABLES IN SCHEMA public, s2 TO reader WITH GRANT OPTION";
     match verified_stmt(sql) {
-        SQLStatement::SQLCreateView {
-            name,
-            query,
-            materialized,
+        SQLStatement::SQLGrant {
+            privileges,
+            objects,
+            grantees,
+            with_grant_option,
         } => {
-            assert_eq!("myschema.myview", name.to_string());
-            assert_eq!("SELECT foo FROM bar", query.to_string());
-            assert!(materialized);
+            assert_eq!(Privileges::All, privileges);
+            assert_eq!(
+                GrantObjects::AllTablesInSchema {
+                    schemas: vec![
+                        SQLObjectName(vec!["public".to_string()]),
+                        SQLObjectName(vec!["s2".to_string()]),
+                    ]
+                },
+                objects
+            );
+            assert_eq!(vec!["reader"], grantees);
+            assert!(with_grant_option);
         }
         _ => unreachable!(),
     }
+
+    verified_stmt("GRANT SELECT, UPDATE ON ALL TABLES IN SCHEMA s TO r");
 }
 
 #[test]
-fn parse_drop_table() {
-    let sql = "DROP TABLE foo";
+fn parse_grant_column_privileges() {
+    let sql = "GRANT SELECT (a, b), UPDATE (c), DELETE ON t TO r";
     match verified_stmt(sql) {
-        SQLStatement::SQLDrop {
-            object_type,
-            if_exists,
-            names,
-            cascade,
+        SQLStatement::SQLGrant { privileges, .. } => assert_eq!(
+            Privileges::Privileges(vec![
+                Privilege::Select {
+                    columns: Some(vec!["a".to_string(), "b".to_string()])
+                },
+                Privilege::Update {
+                    columns: Some(vec!["c".to_string()])
+                },
+                Privilege::Delete,
+            ]),
+            privileges
+        ),
+        _ => unreachable!(),
+    }
+
+    verified_stmt("GRANT INSERT (a), REFERENCES (b, c) ON t TO r");
+}
+
+#[test]
+fn parse_grant_role() {
+    let sql = "GRANT role1, role2 TO alice, bob WITH ADMIN OPTION";
+    match verified_stmt(sql) {
+        SQLStatement::SQLGrantRole {
+            roles,
+            grantees,
+            with_admin_option,
         } => {
-            assert_eq!(false, if_exists);
-            assert_eq!(SQLObjectType::Table, object_type);
-            assert_eq!(
-                vec!["foo"],
-                names.iter().map(|n| n.to_string()).collect::<Vec<_>>()
-            );
-            assert_eq!(false, cascade);
+            assert_eq!(vec!["role1", "role2"], roles);
+            assert_eq!(vec!["alice", "bob"], grantees);
+            assert!(with_admin_option);
         }
-        _ => assert!(false),
+        _ => unreachable!(),
     }
 
-    let sql = "DROP TABLE IF EXISTS foo, bar CASCADE";
-    match verified_stmt(sql) {
-        SQLStatement::SQLDrop {
-            object_type,
-            if_exists,
-            names,
-            cascade,
+    match verified_stmt("GRANT \"select\" TO alice") {
+        SQLStatement::SQLGrantRole {
+            roles,
+            with_admin_option,
+            ..
         } => {
-            assert_eq!(true, if_exists);
-            assert_eq!(SQLObjectType::Table, object_type);
-            assert_eq!(
-                vec!["foo", "bar"],
-                names.iter().map(|n| n.to_string()).collect::<Vec<_>>()
-            );
-            assert_eq!(true, cascade);
+            assert_eq!(vec!["\"select\""], roles);
+            assert!(!with_admin_option);
         }
-        _ => assert!(false),
+        _ => unreachable!(),
     }
 
-    let sql = "DROP TABLE";
+    let res = parse_sql_statements("GRANT role1 ON t TO alice");
     assert_eq!(
-        ParserError::ParserError("Expected identifier, found: EOF".to_string()),
-        parse_sql_statements(sql).unwrap_err(),
+        ParserError::ParserError("Expected TO, found: ON at line 1, column 13".to_string()),
+        res.unwrap_err()
     );
+}
 
-    let sql = "DROP TABLE IF EXISTS foo, bar CASCADE RESTRICT";
-    assert_eq!(
-        ParserError::ParserError("Cannot specify both CASCADE and RESTRICT in DROP".to_string()),
-        parse_sql_statements(sql).unwrap_err(),
-    );
+#[test]
+fn parse_show_variable() {
+    match verified_stmt("SHOW search_path") {
+        SQLStatement::SQLShowVariable { variable } => assert_eq!("search_path", variable),
+        _ => unreachable!(),
+    }
 }
 
 #[test]
-fn parse_drop_view() {
-    let sql = "DROP VIEW myschema.myview";
-    match verified_stmt(sql) {
-        SQLStatement::SQLDrop {
-            names, object_type, ..
-        } => {
-            assert_eq!(
-                vec!["myschema.myview"],
-                names.iter().map(|n| n.to_string()).collect::<Vec<_>>()
-            );
-            assert_eq!(SQLObjectType::View, object_type);
+fn parse_sql_borrowed_matches_owned() {
+    let sqls = [
+        "SELECT a, \"b\" AS c, t.* FROM s.t AS x JOIN u ON x.id = u.id WHERE a > 1 ORDER BY c",
+        "WITH cte (id) AS (SELECT 1) SELECT * FROM cte UNION SELECT \"a\"\"b\" FROM t",
+        "INSERT INTO customer (id, name) VALUES (1, 'x')",
+        "UPDATE t SET a = 1, \"b\" = f(c, d) WHERE e IS NULL",
+        "CREATE TABLE t (id INT NOT NULL, dept INT REFERENCES depts(id), PRIMARY KEY (id))",
+        "ALTER TABLE t ADD CONSTRAINT fk FOREIGN KEY (a) REFERENCES u(b)",
+    ];
+    for sql in sqls.iter() {
+        for dialect in all_dialects().dialects {
+            let owned = Parser::parse_sql(&*dialect, sql.to_string()).unwrap();
+            let borrowed = Parser::parse_sql_borrowed(&*dialect, sql).unwrap();
+            assert_eq!(owned.len(), borrowed.len());
+            for (owned, borrowed) in owned.iter().zip(borrowed.iter()) {
+                assert_eq!(owned.to_string(), borrowed.to_string());
+                assert_eq!(format!("{:?}", owned), format!("{:?}", borrowed));
+            }
         }
-        _ => assert!(false),
     }
 }
 
 #[test]
-fn parse_invalid_subquery_without_parens() {
-    let res = parse_sql_statements("SELECT SELECT 1 FROM bar WHERE 1=1 FROM baz");
-    assert_eq!(
-        ParserError::ParserError("Expected end of statement, found: 1".to_string()),
-        res.unwrap_err()
-    );
+fn parse_sql_borrowed_borrows_unquoted_identifiers() {
+    use std::borrow::Cow;
+
+    let sql = "SELECT a, \"b\" FROM t";
+    let dialect = sqlparser::dialect::GenericSqlDialect {};
+    let ast = Parser::parse_sql_borrowed(&dialect, sql).unwrap();
+    let select = match only(&ast) {
+        SQLStatement::SQLQuery(query) => match &query.body {
+            SQLSetExpr::Select(select) => select,
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+    match &select.projection[0] {
+        SQLSelectItem::UnnamedExpression(ASTNode::SQLIdentifier(Cow::Borrowed(a))) => {
+            assert_eq!("a", *a)
+        }
+        _ => unreachable!(),
+    }
+    // Delimited identifiers keep their quotes, so can't be borrowed
+    match &select.projection[1] {
+        SQLSelectItem::UnnamedExpression(ASTNode::SQLIdentifier(Cow::Owned(b))) => {
+            assert_eq!("\"b\"", b)
+        }
+        _ => unreachable!(),
+    }
 }
 
 #[test]
@@ -2,7 +2,7 @@
 //! Test SQL syntax specific to Microsoft's T-SQL. The parser based on the
 //! generic dialect is also tested (on the inputs it can handle).
 
-use sqlparser::dialect::{GenericSqlDialect, MsSqlDialect};
+use sqlparser::dialect::{AnsiSqlDialect, GenericSqlDialect, MsSqlDialect, PostgreSqlDialect};
 use sqlparser::sqlast::*;
 use sqlparser::test_utils::*;
 
@@ -11,7 +11,7 @@ fn parse_mssql_identifiers() {
     let sql = "SELECT @@version, _foo$123 FROM ##temp";
     let select = ms_and_generic().verified_only_select(sql);
     assert_eq!(
-        &ASTNode::SQLIdentifier("@@version".to_string()),
+        &ASTNode::SQLVariable("@@version".to_string()),
         expr_from_projection(&select.projection[0]),
     );
     assert_eq!(
@@ -27,7 +27,141 @@ fn parse_mssql_identifiers() {
     };
 }
 
-#[allow(dead_code)]
+#[test]
+fn parse_mssql_delimited_identifiers() {
+    let sql = "SELECT [my col], [t].[a], \"b\" FROM [my table] AS [t]";
+    let select = ms().verified_only_select(sql);
+    assert_eq!(
+        &ASTNode::SQLIdentifier("[my col]".to_string()),
+        expr_from_projection(&select.projection[0]),
+    );
+    assert_eq!(
+        &ASTNode::SQLCompoundIdentifier(vec!["[t]".to_string(), "[a]".to_string()]),
+        expr_from_projection(&select.projection[1]),
+    );
+    assert_eq!(
+        &ASTNode::SQLIdentifier("\"b\"".to_string()),
+        expr_from_projection(&select.projection[2]),
+    );
+    match select.relation {
+        Some(TableFactor::Table { name, alias, .. }) => {
+            assert_eq!(vec!["[my table]".to_string()], name.0);
+            assert_eq!("[t]", alias.unwrap().name);
+        }
+        _ => unreachable!(),
+    };
+}
+
+#[test]
+fn parse_mssql_bracket_quoted_identifiers() {
+    let select = ms().verified_only_select("SELECT [a b] FROM [t]");
+    assert_eq!(
+        &ASTNode::SQLIdentifier("[a b]".to_string()),
+        expr_from_projection(only(&select.projection)),
+    );
+
+    // `]]` is an escaped closing bracket
+    let select = ms().verified_only_select("SELECT [a]]b] FROM t");
+    assert_eq!(
+        &ASTNode::SQLIdentifier("[a]]b]".to_string()),
+        expr_from_projection(only(&select.projection)),
+    );
+
+    // brackets are not identifier quotes in other dialects
+    let dialects = TestedDialects {
+        dialects: vec![Box::new(PostgreSqlDialect {}), Box::new(AnsiSqlDialect {})],
+    };
+    assert!(dialects
+        .parse_sql_statements("SELECT [a b] FROM [t]")
+        .is_err());
+}
+
+#[test]
+fn parse_mssql_temp_tables_and_variables() {
+    let select = ms().verified_only_select("SELECT [col] FROM [#temp]");
+    assert_eq!(
+        &ASTNode::SQLIdentifier("[col]".to_string()),
+        expr_from_projection(only(&select.projection)),
+    );
+    match select.relation {
+        Some(TableFactor::Table { name, .. }) => {
+            assert_eq!(vec!["[#temp]".to_string()], name.0);
+        }
+        _ => unreachable!(),
+    };
+
+    // `#temp` tables are plain identifiers in T-SQL, `@local` is a variable
+    let select = ms().verified_only_select("SELECT @local FROM #temp");
+    assert_eq!(
+        &ASTNode::SQLVariable("@local".to_string()),
+        expr_from_projection(only(&select.projection)),
+    );
+
+    let dialects = TestedDialects {
+        dialects: vec![Box::new(PostgreSqlDialect {}), Box::new(AnsiSqlDialect {})],
+    };
+    assert!(dialects
+        .parse_sql_statements("SELECT @local FROM #temp")
+        .is_err());
+}
+
+#[test]
+fn parse_mssql_variables() {
+    let sql = "SELECT @foo, @@version FROM t WHERE a = @foo";
+    let select = ms_and_generic().verified_only_select(sql);
+    assert_eq!(
+        &ASTNode::SQLVariable("@foo".to_string()),
+        expr_from_projection(&select.projection[0]),
+    );
+    assert_eq!(
+        &ASTNode::SQLVariable("@@version".to_string()),
+        expr_from_projection(&select.projection[1]),
+    );
+    assert_eq!(
+        ASTNode::SQLBinaryExpr {
+            left: Box::new(ASTNode::SQLIdentifier("a".to_string())),
+            op: SQLOperator::Eq,
+            right: Box::new(ASTNode::SQLVariable("@foo".to_string())),
+        },
+        select.selection.unwrap()
+    );
+
+    // a bracket-quoted name starting with `@` is still an identifier
+    let select = ms().verified_only_select("SELECT [@foo] FROM t");
+    assert_eq!(
+        &ASTNode::SQLIdentifier("[@foo]".to_string()),
+        expr_from_projection(only(&select.projection)),
+    );
+}
+
+#[test]
+fn parse_mssql_table_sample() {
+    let sql = "SELECT * FROM t AS a TABLESAMPLE SYSTEM (100 ROWS) REPEATABLE (7) WITH (NOLOCK)";
+    let select = ms().verified_only_select(sql);
+    match select.relation {
+        Some(TableFactor::Table {
+            sample, with_hints, ..
+        }) => {
+            assert_eq!(
+                Some(Box::new(TableSample {
+                    method: TableSampleMethod::System,
+                    quantity: ASTNode::SQLValue(Value::Long(100)),
+                    unit: Some(TableSampleUnit::Rows),
+                    repeatable: Some(ASTNode::SQLValue(Value::Long(7))),
+                })),
+                sample
+            );
+            assert_eq!(
+                vec![ASTNode::SQLIdentifier("NOLOCK".to_string())],
+                with_hints
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    ms().verified_only_select("SELECT * FROM t TABLESAMPLE SYSTEM (10 PERCENT)");
+}
+
 fn ms() -> TestedDialects {
     TestedDialects {
         dialects: vec![Box::new(MsSqlDialect {})],
@@ -0,0 +1,615 @@
+#![warn(clippy::all)]
+//! Test SQL syntax specific to MySQL. The parser based on the generic dialect
+//! is also tested (on the inputs it can handle).
+
+use sqlparser::dialect::{AnsiSqlDialect, GenericSqlDialect, MySqlDialect};
+use sqlparser::sqlast::*;
+use sqlparser::sqlparser::ParserError;
+use sqlparser::test_utils::*;
+
+#[test]
+fn parse_mysql_identifiers() {
+    let select = mysql().verified_only_select("SELECT $foo, bar$1 FROM t");
+    assert_eq!(
+        &ASTNode::SQLIdentifier("$foo".to_string()),
+        expr_from_projection(&select.projection[0]),
+    );
+    assert_eq!(
+        &ASTNode::SQLIdentifier("bar$1".to_string()),
+        expr_from_projection(&select.projection[1]),
+    );
+}
+
+#[test]
+fn parse_mysql_delimited_identifiers() {
+    let sql = "SELECT `my col`, `t`.`a` FROM `my table` AS `t`";
+    let select = mysql().verified_only_select(sql);
+    assert_eq!(
+        &ASTNode::SQLIdentifier("`my col`".to_string()),
+        expr_from_projection(&select.projection[0]),
+    );
+    assert_eq!(
+        &ASTNode::SQLCompoundIdentifier(vec!["`t`".to_string(), "`a`".to_string()]),
+        expr_from_projection(&select.projection[1]),
+    );
+    match select.relation {
+        Some(TableFactor::Table { name, alias, .. }) => {
+            assert_eq!(vec!["`my table`".to_string()], name.0);
+            assert_eq!("`t`", alias.unwrap().name);
+        }
+        _ => unreachable!(),
+    };
+}
+
+#[test]
+fn parse_mysql_variables() {
+    let sql = "SELECT @foo, @@version FROM t WHERE a = @foo";
+    let select = mysql_and_generic().verified_only_select(sql);
+    assert_eq!(
+        &ASTNode::SQLVariable("@foo".to_string()),
+        expr_from_projection(&select.projection[0]),
+    );
+    assert_eq!(
+        &ASTNode::SQLVariable("@@version".to_string()),
+        expr_from_projection(&select.projection[1]),
+    );
+    assert_eq!(
+        ASTNode::SQLBinaryExpr {
+            left: Box::new(ASTNode::SQLIdentifier("a".to_string())),
+            op: SQLOperator::Eq,
+            right: Box::new(ASTNode::SQLVariable("@foo".to_string())),
+        },
+        select.selection.unwrap()
+    );
+
+    // a backtick-quoted name starting with `@` is still an identifier
+    let select = mysql().verified_only_select("SELECT `@foo` FROM t");
+    assert_eq!(
+        &ASTNode::SQLIdentifier("`@foo`".to_string()),
+        expr_from_projection(only(&select.projection)),
+    );
+}
+
+#[test]
+fn parse_bitwise_operators() {
+    for (sql_op, op) in &[
+        ("&", SQLOperator::BitwiseAnd),
+        ("|", SQLOperator::BitwiseOr),
+        ("<<", SQLOperator::ShiftLeft),
+        (">>", SQLOperator::ShiftRight),
+    ] {
+        let sql = format!("SELECT a {} b", sql_op);
+        let select = mysql_and_generic().verified_only_select(&sql);
+        assert_eq!(
+            &ASTNode::SQLBinaryExpr {
+                left: Box::new(ASTNode::SQLIdentifier("a".to_string())),
+                op: op.clone(),
+                right: Box::new(ASTNode::SQLIdentifier("b".to_string())),
+            },
+            expr_from_projection(only(&select.projection))
+        );
+    }
+
+    // `#` is not an operator in MySQL (where it starts a comment), nor in
+    // the generic dialect, where it starts an identifier (here an alias)
+    let res = mysql().parse_sql_statements("SELECT a # b");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected end of statement, found: # at line 1, column 10".to_string()
+        ),
+        res.unwrap_err()
+    );
+    let generic = TestedDialects {
+        dialects: vec![Box::new(GenericSqlDialect {})],
+    };
+    let res = generic.parse_sql_statements("SELECT a # b");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected end of statement, found: b at line 1, column 12".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_json_object() {
+    let sql = "SELECT JSON_OBJECT('a', 1, 'b', t.b) FROM t";
+    let select = mysql().verified_only_select(sql);
+    assert_eq!(
+        &ASTNode::SQLFunction {
+            name: SQLObjectName(vec!["JSON_OBJECT".to_string()]),
+            args: vec![
+                ASTNode::SQLValue(Value::SingleQuotedString("a".to_string())),
+                ASTNode::SQLValue(Value::Long(1)),
+                ASTNode::SQLValue(Value::SingleQuotedString("b".to_string())),
+                ASTNode::SQLCompoundIdentifier(vec!["t".to_string(), "b".to_string()]),
+            ],
+            order_by: vec![],
+            within_group: vec![],
+            over: None,
+            distinct: false,
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    mysql().verified_only_select("SELECT JSON_OBJECTAGG(k, v) FROM t GROUP BY g");
+}
+
+#[test]
+fn parse_group_by_with_rollup() {
+    let sql = "SELECT a, b, SUM(c) FROM t GROUP BY a, b WITH ROLLUP";
+    let select = mysql_and_generic().verified_only_select(sql);
+    assert!(select.with_rollup);
+    assert_eq!(
+        vec![
+            ASTNode::SQLIdentifier("a".to_string()),
+            ASTNode::SQLIdentifier("b".to_string()),
+        ],
+        select.group_by
+    );
+
+    // the standard syntax is parsed as a ROLLUP grouping element instead
+    let sql = "SELECT a, b, SUM(c) FROM t GROUP BY ROLLUP(a, b)";
+    let select = mysql_and_generic().verified_only_select(sql);
+    assert!(!select.with_rollup);
+    match only(&select.group_by) {
+        ASTNode::SQLRollup(sets) => assert_eq!(2, sets.len()),
+        _ => panic!("Expected ROLLUP()"),
+    }
+
+    mysql_and_generic().verified_stmt("SELECT a FROM t GROUP BY a WITH ROLLUP HAVING a > 1");
+}
+
+#[test]
+fn parse_group_by_with_rollup_not_supported() {
+    let res = ansi().parse_sql_statements("SELECT a FROM t GROUP BY a WITH ROLLUP");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected end of statement, found: WITH at line 1, column 28".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_straight_join() {
+    let sql = "SELECT * FROM t1 STRAIGHT_JOIN t2 ON t1.id = t2.id";
+    let select = mysql_and_generic().verified_only_select(sql);
+    let join = only(&select.joins);
+    assert_eq!("t2", join.relation.to_string());
+    assert_eq!(
+        JoinOperator::StraightJoin(JoinConstraint::On(ASTNode::SQLBinaryExpr {
+            left: Box::new(ASTNode::SQLCompoundIdentifier(vec![
+                "t1".to_string(),
+                "id".to_string()
+            ])),
+            op: SQLOperator::Eq,
+            right: Box::new(ASTNode::SQLCompoundIdentifier(vec![
+                "t2".to_string(),
+                "id".to_string()
+            ])),
+        })),
+        join.join_operator
+    );
+
+    // unlike other inner joins, STRAIGHT_JOIN does not need a constraint
+    let select = mysql_and_generic().verified_only_select("SELECT * FROM t1 STRAIGHT_JOIN t2");
+    assert_eq!(
+        JoinOperator::StraightJoin(JoinConstraint::None),
+        only(&select.joins).join_operator
+    );
+    mysql_and_generic().verified_stmt("SELECT * FROM t1 STRAIGHT_JOIN t2 USING(id)");
+
+    let res = mysql_and_generic().parse_sql_statements("SELECT * FROM t1 NATURAL STRAIGHT_JOIN t2");
+    assert!(res.is_err());
+}
+
+#[test]
+fn parse_straight_join_not_supported() {
+    // STRAIGHT_JOIN is an ordinary identifier in other dialects
+    let select = ansi().verified_only_select("SELECT * FROM t1 AS straight_join");
+    assert!(select.joins.is_empty());
+    ansi().one_statement_parses_to(
+        "SELECT * FROM t1 straight_join",
+        "SELECT * FROM t1 AS straight_join",
+    );
+
+    let res = ansi().parse_sql_statements("SELECT * FROM t1 STRAIGHT_JOIN t2");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected end of statement, found: t2 at line 1, column 32".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_select_modifiers() {
+    let sql = "SELECT HIGH_PRIORITY SQL_CALC_FOUND_ROWS a FROM t";
+    let select = mysql_and_generic().verified_only_select(sql);
+    assert_eq!(
+        vec![
+            SQLSelectModifier::HighPriority,
+            SQLSelectModifier::CalcFoundRows
+        ],
+        select.modifiers
+    );
+
+    // modifiers are displayed in canonical order
+    mysql_and_generic().one_statement_parses_to(
+        "SELECT DISTINCT SQL_NO_CACHE STRAIGHT_JOIN SQL_BIG_RESULT a FROM t",
+        "SELECT DISTINCT STRAIGHT_JOIN SQL_BIG_RESULT SQL_NO_CACHE a FROM t",
+    );
+    mysql_and_generic().verified_stmt(
+        "SELECT HIGH_PRIORITY STRAIGHT_JOIN SQL_SMALL_RESULT SQL_BIG_RESULT \
+         SQL_BUFFER_RESULT SQL_NO_CACHE SQL_CALC_FOUND_ROWS a FROM t",
+    );
+
+    let res = mysql().parse_sql_statements("SELECT SQL_NO_CACHE SQL_NO_CACHE a FROM t");
+    assert_eq!(
+        ParserError::ParserError(
+            "Redundant SELECT modifier: SQL_NO_CACHE at line 1, column 21".to_string()
+        ),
+        res.unwrap_err()
+    );
+
+    // other dialects treat the modifiers as regular identifiers
+    let select = ansi().verified_only_select("SELECT high_priority FROM t");
+    assert!(select.modifiers.is_empty());
+    assert_eq!(
+        &ASTNode::SQLIdentifier("high_priority".to_string()),
+        expr_from_projection(only(&select.projection))
+    );
+}
+
+#[test]
+fn parse_select_partition() {
+    let sql = "SELECT * FROM t PARTITION (p0, p1) AS a WHERE a.x = 1";
+    let select = mysql_and_generic().verified_only_select(sql);
+    match select.relation {
+        Some(TableFactor::Table {
+            partitions, alias, ..
+        }) => {
+            assert_eq!(vec!["p0".to_string(), "p1".to_string()], partitions);
+            assert_eq!("a", alias.unwrap().name);
+        }
+        _ => unreachable!(),
+    }
+
+    let res = mysql().parse_sql_statements("SELECT * FROM t PARTITION ()");
+    assert_eq!(
+        ParserError::ParserError("Expected identifier, found: ) at line 1, column 28".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_insert_partition() {
+    let sql = "INSERT INTO t PARTITION (p0, p1) (a, b) VALUES (1, 2)";
+    match mysql_and_generic().verified_stmt(sql) {
+        SQLStatement::SQLInsert {
+            table_name,
+            partitions,
+            columns,
+            ..
+        } => {
+            assert_eq!("t", table_name.to_string());
+            assert_eq!(vec!["p0".to_string(), "p1".to_string()], partitions);
+            assert_eq!(vec!["a".to_string(), "b".to_string()], columns);
+        }
+        _ => unreachable!(),
+    }
+    mysql_and_generic().verified_stmt("INSERT INTO t PARTITION (p0) VALUES (1)");
+}
+
+#[test]
+fn parse_insert_modifiers() {
+    for (sql, expected_priority) in &[
+        (
+            "INSERT LOW_PRIORITY INTO t VALUES (1)",
+            SQLInsertPriority::LowPriority,
+        ),
+        (
+            "INSERT DELAYED INTO t VALUES (1)",
+            SQLInsertPriority::Delayed,
+        ),
+        (
+            "INSERT HIGH_PRIORITY IGNORE INTO t VALUES (1)",
+            SQLInsertPriority::HighPriority,
+        ),
+    ] {
+        match mysql_and_generic().verified_stmt(sql) {
+            SQLStatement::SQLInsert { priority, .. } => {
+                assert_eq!(Some(expected_priority), priority.as_ref())
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    match mysql_and_generic().verified_stmt("INSERT IGNORE INTO t (a) VALUES (1)") {
+        SQLStatement::SQLInsert {
+            priority, ignore, ..
+        } => {
+            assert_eq!(None, priority);
+            assert!(ignore);
+        }
+        _ => unreachable!(),
+    }
+
+    let res = mysql().parse_sql_statements("INSERT IGNORE LOW_PRIORITY INTO t VALUES (1)");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected INTO, found: LOW_PRIORITY at line 1, column 15".to_string()
+        ),
+        res.unwrap_err()
+    );
+
+    let res = ansi().parse_sql_statements("INSERT DELAYED INTO t VALUES (1)");
+    assert_eq!(
+        ParserError::ParserError("Expected INTO, found: DELAYED at line 1, column 8".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_limit_offset_comma() {
+    let stmt = mysql_and_generic().one_statement_parses_to(
+        "SELECT * FROM t LIMIT 5, 10",
+        "SELECT * FROM t LIMIT 10 OFFSET 5",
+    );
+    match stmt {
+        SQLStatement::SQLQuery(query) => {
+            assert_eq!(Some(ASTNode::SQLValue(Value::Long(10))), query.limit);
+            assert_eq!(
+                Some(Offset {
+                    value: ASTNode::SQLValue(Value::Long(5)),
+                    rows: false,
+                }),
+                query.offset
+            );
+        }
+        _ => panic!("Expected a query"),
+    }
+
+    // the comma form is displayed as the equivalent `LIMIT <count> OFFSET
+    // <offset>`, which parses back to the same query
+    let canonical = "SELECT * FROM t LIMIT 5 OFFSET 10";
+    assert_eq!(
+        mysql_and_generic().verified_stmt(canonical),
+        mysql_and_generic().one_statement_parses_to("SELECT * FROM t LIMIT 10, 5", canonical)
+    );
+
+    // the other forms of LIMIT behave as before
+    mysql_and_generic().verified_query("SELECT * FROM t LIMIT 5");
+    mysql_and_generic().verified_query("SELECT * FROM t LIMIT 5 OFFSET 2 ROWS");
+    mysql_and_generic().one_statement_parses_to("SELECT * FROM t LIMIT ALL", "SELECT * FROM t");
+
+    let res = mysql().parse_sql_statements("SELECT * FROM t LIMIT 5, 10 OFFSET 2 ROWS");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected end of statement, found: OFFSET at line 1, column 29".to_string()
+        ),
+        res.unwrap_err()
+    );
+
+    let res = ansi().parse_sql_statements("SELECT * FROM t LIMIT 5, 10");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected end of statement, found: , at line 1, column 24".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_insert_on_duplicate_key_update() {
+    let stmt = mysql_and_generic().verified_stmt(
+        "INSERT INTO t (id, hits) VALUES (1, 1) ON DUPLICATE KEY UPDATE hits = hits + 1",
+    );
+    match stmt {
+        SQLStatement::SQLInsert { on_conflict, .. } => assert_eq!(
+            Some(SQLOnConflict::DuplicateKeyUpdate(vec![SQLAssignment {
+                id: "hits".to_string(),
+                value: ASTNode::SQLBinaryExpr {
+                    left: Box::new(ASTNode::SQLIdentifier("hits".to_string())),
+                    op: SQLOperator::Plus,
+                    right: Box::new(ASTNode::SQLValue(Value::Long(1))),
+                },
+            }])),
+            on_conflict
+        ),
+        _ => panic!("Expected an INSERT"),
+    }
+
+    let res = mysql().parse_sql_statements("INSERT INTO t VALUES (1) ON CONFLICT DO NOTHING");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected end of statement, found: ON at line 1, column 26".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_mysql_text_types() {
+    let sql = "CREATE TABLE t (a TINYTEXT, b MEDIUMTEXT, c LONGTEXT, d TEXT)";
+    let canonical = "CREATE TABLE t (a tinytext, b mediumtext, c longtext, d text)";
+    match mysql().one_statement_parses_to(sql, canonical) {
+        SQLStatement::SQLCreateTable { columns, .. } => {
+            let types: Vec<SQLType> = columns.into_iter().map(|c| c.data_type).collect();
+            assert_eq!(
+                vec![
+                    SQLType::TinyText,
+                    SQLType::MediumText,
+                    SQLType::LongText,
+                    SQLType::Text
+                ],
+                types
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    // other dialects treat the sized variants as custom types
+    let generic = TestedDialects {
+        dialects: vec![Box::new(GenericSqlDialect {})],
+    };
+    match generic.verified_stmt("CREATE TABLE t (a longtext)") {
+        SQLStatement::SQLCreateTable { columns, .. } => assert_eq!(
+            SQLType::Custom(SQLObjectName(vec!["longtext".to_string()])),
+            columns[0].data_type
+        ),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_mysql_json_type() {
+    match mysql().one_statement_parses_to("CREATE TABLE t (doc JSON)", "CREATE TABLE t (doc json)")
+    {
+        SQLStatement::SQLCreateTable { columns, .. } => {
+            assert_eq!(SQLType::Json, columns[0].data_type);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_show_tables() {
+    match mysql_and_generic().verified_stmt("SHOW TABLES") {
+        SQLStatement::SQLShowTables { db_name, filter } => {
+            assert_eq!(None, db_name);
+            assert_eq!(None, filter);
+        }
+        _ => unreachable!(),
+    }
+
+    match mysql_and_generic().verified_stmt("SHOW TABLES FROM mydb LIKE 'customer%'") {
+        SQLStatement::SQLShowTables { db_name, filter } => {
+            assert_eq!(Some(SQLObjectName(vec!["mydb".to_string()])), db_name);
+            assert_eq!(
+                Some(SQLShowStatementFilter::Like("customer%".to_string())),
+                filter
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    mysql_and_generic().one_statement_parses_to("SHOW TABLES IN mydb", "SHOW TABLES FROM mydb");
+    mysql_and_generic().verified_stmt("SHOW TABLES WHERE 1 = 2");
+}
+
+#[test]
+fn parse_show_columns() {
+    match mysql_and_generic().verified_stmt("SHOW COLUMNS FROM mytable") {
+        SQLStatement::SQLShowColumns { table_name, filter } => {
+            assert_eq!("mytable", table_name.to_string());
+            assert_eq!(None, filter);
+        }
+        _ => unreachable!(),
+    }
+    mysql_and_generic().verified_stmt("SHOW COLUMNS FROM mydb.mytable LIKE 'pattern'");
+    mysql_and_generic().verified_stmt("SHOW COLUMNS FROM mytable WHERE 1 = 2");
+    mysql_and_generic().one_statement_parses_to(
+        "SHOW COLUMNS IN mytable FROM mydb",
+        "SHOW COLUMNS FROM mydb.mytable",
+    );
+
+    let res = mysql_and_generic().parse_sql_statements("SHOW COLUMNS mytable");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected FROM or IN after SHOW COLUMNS, found: mytable at line 1, column 14"
+                .to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_show_variables() {
+    match mysql_and_generic().verified_stmt("SHOW VARIABLES LIKE 'auto%'") {
+        SQLStatement::SQLShowVariables { filter } => assert_eq!(
+            Some(SQLShowStatementFilter::Like("auto%".to_string())),
+            filter
+        ),
+        _ => unreachable!(),
+    }
+    mysql_and_generic().verified_stmt("SHOW VARIABLES");
+}
+
+#[test]
+fn parse_show_statements_not_supported() {
+    // `SHOW TABLES` is just `SHOW <name>` in other dialects...
+    match ansi().verified_stmt("SHOW TABLES") {
+        SQLStatement::SQLShowVariable { variable } => assert_eq!("TABLES", variable),
+        _ => unreachable!(),
+    }
+    // ...so the MySQL-specific forms are rejected
+    let res = ansi().parse_sql_statements("SHOW TABLES FROM mydb");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected end of statement, found: FROM at line 1, column 13".to_string()
+        ),
+        res.unwrap_err()
+    );
+    let res = ansi().parse_sql_statements("SHOW COLUMNS FROM mytable");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected end of statement, found: FROM at line 1, column 14".to_string()
+        ),
+        res.unwrap_err()
+    );
+    let res = ansi().parse_sql_statements("SHOW VARIABLES LIKE 'auto%'");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected end of statement, found: LIKE at line 1, column 16".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_partition_not_supported() {
+    // PARTITION is an ordinary identifier in other dialects
+    ansi().one_statement_parses_to("SELECT * FROM t partition", "SELECT * FROM t AS partition");
+
+    // ...so what looks like a partition selection is an alias with a column list
+    ansi().one_statement_parses_to(
+        "SELECT * FROM t PARTITION (p0)",
+        "SELECT * FROM t AS PARTITION (p0)",
+    );
+    let select = ansi().verified_only_select("SELECT * FROM t AS PARTITION (p0)");
+    match select.relation {
+        Some(TableFactor::Table {
+            partitions, alias, ..
+        }) => {
+            assert!(partitions.is_empty());
+            assert_eq!(
+                Some(TableAlias {
+                    name: "PARTITION".to_string(),
+                    columns: vec!["p0".to_string()],
+                }),
+                alias
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn mysql() -> TestedDialects {
+    TestedDialects {
+        dialects: vec![Box::new(MySqlDialect {})],
+    }
+}
+
+fn mysql_and_generic() -> TestedDialects {
+    TestedDialects {
+        dialects: vec![Box::new(MySqlDialect {}), Box::new(GenericSqlDialect {})],
+    }
+}
+
+fn ansi() -> TestedDialects {
+    TestedDialects {
+        dialects: vec![Box::new(AnsiSqlDialect {})],
+    }
+}
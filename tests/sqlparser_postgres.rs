@@ -2,8 +2,9 @@
 //! Test SQL syntax specific to PostgreSQL. The parser based on the
 //! generic dialect is also tested (on the inputs it can handle).
 
-use sqlparser::dialect::{GenericSqlDialect, PostgreSqlDialect};
+use sqlparser::dialect::{AnsiSqlDialect, GenericSqlDialect, PostgreSqlDialect};
 use sqlparser::sqlast::*;
+use sqlparser::sqlparser::ParserError;
 use sqlparser::test_utils::*;
 
 #[test]
@@ -23,27 +24,39 @@ fn parse_create_table_with_defaults() {
         SQLStatement::SQLCreateTable {
             name,
             columns,
+            constraints,
             external: false,
             file_format: None,
             location: None,
+            if_not_exists: false,
         } => {
+            assert!(constraints.is_empty());
             assert_eq!("public.customer", name.to_string());
             assert_eq!(10, columns.len());
 
             let c_name = &columns[0];
             assert_eq!("customer_id", c_name.name);
             assert_eq!(SQLType::Int, c_name.data_type);
-            assert_eq!(false, c_name.allow_null);
+            assert!(c_name
+                .options
+                .iter()
+                .any(|o| o.option == ColumnOption::NotNull));
 
             let c_lat = &columns[1];
             assert_eq!("store_id", c_lat.name);
             assert_eq!(SQLType::SmallInt, c_lat.data_type);
-            assert_eq!(false, c_lat.allow_null);
+            assert!(c_lat
+                .options
+                .iter()
+                .any(|o| o.option == ColumnOption::NotNull));
 
             let c_lng = &columns[2];
             assert_eq!("first_name", c_lng.name);
             assert_eq!(SQLType::Varchar(Some(45)), c_lng.data_type);
-            assert_eq!(false, c_lng.allow_null);
+            assert!(c_lng
+                .options
+                .iter()
+                .any(|o| o.option == ColumnOption::NotNull));
         }
         _ => unreachable!(),
     }
@@ -69,39 +82,60 @@ fn parse_create_table_from_pg_dump() {
         SQLStatement::SQLCreateTable {
             name,
             columns,
+            constraints,
             external: false,
             file_format: None,
             location: None,
+            if_not_exists: false,
         } => {
+            assert!(constraints.is_empty());
             assert_eq!("public.customer", name.to_string());
 
             let c_customer_id = &columns[0];
             assert_eq!("customer_id", c_customer_id.name);
             assert_eq!(SQLType::Int, c_customer_id.data_type);
-            assert_eq!(false, c_customer_id.allow_null);
+            assert!(c_customer_id
+                .options
+                .iter()
+                .any(|o| o.option == ColumnOption::NotNull));
 
             let c_store_id = &columns[1];
             assert_eq!("store_id", c_store_id.name);
             assert_eq!(SQLType::SmallInt, c_store_id.data_type);
-            assert_eq!(false, c_store_id.allow_null);
+            assert!(c_store_id
+                .options
+                .iter()
+                .any(|o| o.option == ColumnOption::NotNull));
 
             let c_first_name = &columns[2];
             assert_eq!("first_name", c_first_name.name);
             assert_eq!(SQLType::Varchar(Some(45)), c_first_name.data_type);
-            assert_eq!(false, c_first_name.allow_null);
+            assert!(c_first_name
+                .options
+                .iter()
+                .any(|o| o.option == ColumnOption::NotNull));
 
             let c_create_date1 = &columns[8];
             assert_eq!(
-                Some(ASTNode::SQLCast {
-                    expr: Box::new(ASTNode::SQLCast {
-                        expr: Box::new(ASTNode::SQLValue(Value::SingleQuotedString(
-                            "now".to_string()
-                        ))),
-                        data_type: SQLType::Text
-                    }),
-                    data_type: SQLType::Date
-                }),
-                c_create_date1.default
+                vec![
+                    ColumnOptionDef {
+                        name: None,
+                        option: ColumnOption::Default(ASTNode::SQLCast {
+                            expr: Box::new(ASTNode::SQLCast {
+                                expr: Box::new(ASTNode::SQLValue(Value::SingleQuotedString(
+                                    "now".to_string()
+                                ))),
+                                data_type: SQLType::Text
+                            }),
+                            data_type: SQLType::Date
+                        }),
+                    },
+                    ColumnOptionDef {
+                        name: None,
+                        option: ColumnOption::NotNull,
+                    },
+                ],
+                c_create_date1.options
             );
 
             let c_release_year = &columns[10];
@@ -130,25 +164,34 @@ fn parse_create_table_with_inherit() {
         SQLStatement::SQLCreateTable {
             name,
             columns,
+            constraints,
             external: false,
             file_format: None,
             location: None,
+            if_not_exists: false,
         } => {
+            assert!(constraints.is_empty());
             assert_eq!("bazaar.settings", name.to_string());
 
             let c_name = &columns[0];
             assert_eq!("settings_id", c_name.name);
             assert_eq!(SQLType::Uuid, c_name.data_type);
-            assert_eq!(false, c_name.allow_null);
-            assert_eq!(true, c_name.is_primary);
-            assert_eq!(false, c_name.is_unique);
+            let options: Vec<_> = c_name.options.iter().map(|o| &o.option).collect();
+            assert_eq!(3, options.len());
+            assert_eq!(&ColumnOption::Unique { is_primary: true }, options[0]);
+            assert!(matches!(options[1], ColumnOption::Default(_)));
+            assert_eq!(&ColumnOption::NotNull, options[2]);
 
             let c_name = &columns[1];
             assert_eq!("user_id", c_name.name);
             assert_eq!(SQLType::Uuid, c_name.data_type);
-            assert_eq!(true, c_name.allow_null);
-            assert_eq!(false, c_name.is_primary);
-            assert_eq!(true, c_name.is_unique);
+            assert_eq!(
+                vec![ColumnOptionDef {
+                    name: None,
+                    option: ColumnOption::Unique { is_primary: false },
+                }],
+                c_name.options
+            );
         }
         _ => unreachable!(),
     }
@@ -181,6 +224,764 @@ PHP	₱ USD $
     //assert_eq!(sql, ast.to_string());
 }
 
+#[test]
+fn parse_create_function() {
+    let sql = "CREATE OR REPLACE FUNCTION add(a int, b int) RETURNS int AS 'SELECT a + b' LANGUAGE sql IMMUTABLE";
+    match pg_and_generic().verified_stmt(sql) {
+        SQLStatement::SQLCreateFunction {
+            or_replace,
+            name,
+            args,
+            return_type,
+            body,
+            language,
+            behavior,
+        } => {
+            assert!(or_replace);
+            assert_eq!("add", name.to_string());
+            assert_eq!(
+                vec![
+                    SQLFunctionArgDef {
+                        name: Some("a".to_string()),
+                        data_type: SQLType::Int,
+                    },
+                    SQLFunctionArgDef {
+                        name: Some("b".to_string()),
+                        data_type: SQLType::Int,
+                    },
+                ],
+                args
+            );
+            assert_eq!(SQLType::Int, return_type);
+            assert_eq!(Value::SingleQuotedString("SELECT a + b".to_string()), body);
+            assert_eq!(Some("sql".to_string()), language);
+            assert_eq!(Some(SQLFunctionBehavior::Immutable), behavior);
+        }
+        _ => unreachable!(),
+    }
+
+    // pg_dump emits LANGUAGE before the body; unnamed parameters are allowed
+    pg_and_generic().one_statement_parses_to(
+        "CREATE FUNCTION public.f(character varying, real) RETURNS text STABLE LANGUAGE plpgsql AS 'BEGIN RETURN ''x''; END'",
+        "CREATE FUNCTION public.f(character varying, real) RETURNS text AS 'BEGIN RETURN ''x''; END' LANGUAGE plpgsql STABLE",
+    );
+    pg_and_generic().verified_stmt("CREATE FUNCTION f() RETURNS int AS 'SELECT 1'");
+    pg_and_generic().verified_stmt(
+        "CREATE FUNCTION f() RETURNS text AS $body$BEGIN RETURN 'x'; END$body$ LANGUAGE plpgsql",
+    );
+
+    let res = pg_and_generic().parse_sql_statements("CREATE FUNCTION f() RETURNS int LANGUAGE sql");
+    assert_eq!(
+        ParserError::ParserError("Expected AS <function body>, found: EOF".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_dollar_quoted_string() {
+    let select = pg_and_generic().verified_only_select("SELECT $$a'b$$");
+    assert_eq!(
+        &ASTNode::SQLValue(Value::DollarQuotedString {
+            tag: None,
+            value: "a'b".to_string(),
+        }),
+        expr_from_projection(only(&select.projection))
+    );
+
+    let select = pg_and_generic().verified_only_select("SELECT $tag$x$tag$");
+    assert_eq!(
+        &ASTNode::SQLValue(Value::DollarQuotedString {
+            tag: Some("tag".to_string()),
+            value: "x".to_string(),
+        }),
+        expr_from_projection(only(&select.projection))
+    );
+
+    // a quote with a different tag does not terminate the string
+    pg_and_generic().verified_stmt("SELECT $outer$a $inner$b$inner$ $$c$$$outer$");
+}
+
+#[test]
+fn parse_ilike() {
+    let select = pg_and_generic().verified_only_select("SELECT * FROM t WHERE name ILIKE '%a'");
+    assert_eq!(
+        ASTNode::SQLBinaryExpr {
+            left: Box::new(ASTNode::SQLIdentifier("name".to_string())),
+            op: SQLOperator::ILike,
+            right: Box::new(ASTNode::SQLValue(Value::SingleQuotedString(
+                "%a".to_string()
+            ))),
+        },
+        select.selection.unwrap()
+    );
+
+    let select = pg_and_generic().verified_only_select("SELECT * FROM t WHERE name NOT ILIKE '%a'");
+    match select.selection.unwrap() {
+        ASTNode::SQLBinaryExpr { op, .. } => assert_eq!(SQLOperator::NotILike, op),
+        _ => panic!("Expected NOT ILIKE"),
+    }
+
+    let select = pg_and_generic()
+        .verified_only_select("SELECT * FROM t WHERE name NOT ILIKE '%a!%' ESCAPE '!'");
+    match select.selection.unwrap() {
+        ASTNode::SQLLike {
+            op, escape_char, ..
+        } => {
+            assert_eq!(SQLOperator::NotILike, op);
+            assert_eq!('!', escape_char);
+        }
+        _ => panic!("Expected NOT ILIKE ... ESCAPE"),
+    }
+
+    let ansi = TestedDialects {
+        dialects: vec![Box::new(AnsiSqlDialect {})],
+    };
+    let res = ansi.parse_sql_statements("SELECT * FROM t WHERE name ILIKE '%a'");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected end of statement, found: ILIKE at line 1, column 28".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_similar_to_and_regex_match() {
+    for (sql_op, op) in &[
+        ("SIMILAR TO", SQLOperator::SimilarTo),
+        ("NOT SIMILAR TO", SQLOperator::NotSimilarTo),
+        ("~", SQLOperator::RegexMatch),
+        ("~*", SQLOperator::RegexIMatch),
+        ("!~", SQLOperator::RegexNotMatch),
+        ("!~*", SQLOperator::RegexNotIMatch),
+    ] {
+        let sql = format!("SELECT * FROM t WHERE col {} '^a'", sql_op);
+        let select = pg_and_generic().verified_only_select(&sql);
+        assert_eq!(
+            ASTNode::SQLBinaryExpr {
+                left: Box::new(ASTNode::SQLIdentifier("col".to_string())),
+                op: op.clone(),
+                right: Box::new(ASTNode::SQLValue(Value::SingleQuotedString(
+                    "^a".to_string()
+                ))),
+            },
+            select.selection.unwrap()
+        );
+    }
+
+    // binds tighter than AND, but looser than arithmetic
+    let select = pg_and_generic().verified_only_select("SELECT * FROM t WHERE a + 1 ~* b AND c");
+    match select.selection.unwrap() {
+        ASTNode::SQLBinaryExpr {
+            left,
+            op: SQLOperator::And,
+            ..
+        } => match *left {
+            ASTNode::SQLBinaryExpr { op, .. } => assert_eq!(SQLOperator::RegexIMatch, op),
+            _ => panic!("Expected ~*"),
+        },
+        _ => panic!("Expected AND"),
+    }
+
+    let ansi = TestedDialects {
+        dialects: vec![Box::new(AnsiSqlDialect {})],
+    };
+    let res = ansi.parse_sql_statements("SELECT * FROM t WHERE col ~ '^a'");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected end of statement, found: ~ at line 1, column 27".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_pattern_match_escape() {
+    for (sql_op, op) in &[
+        ("LIKE", SQLOperator::Like),
+        ("NOT LIKE", SQLOperator::NotLike),
+        ("ILIKE", SQLOperator::ILike),
+        ("NOT ILIKE", SQLOperator::NotILike),
+        ("SIMILAR TO", SQLOperator::SimilarTo),
+        ("NOT SIMILAR TO", SQLOperator::NotSimilarTo),
+    ] {
+        let sql = format!("SELECT * FROM t WHERE name {} '%a!%' ESCAPE '!'", sql_op);
+        let select = pg_and_generic().verified_only_select(&sql);
+        assert_eq!(
+            ASTNode::SQLLike {
+                expr: Box::new(ASTNode::SQLIdentifier("name".to_string())),
+                op: op.clone(),
+                pattern: Box::new(ASTNode::SQLValue(Value::SingleQuotedString(
+                    "%a!%".to_string()
+                ))),
+                escape_char: '!',
+            },
+            select.selection.unwrap()
+        );
+    }
+
+    // the regex match operators don't take an ESCAPE clause
+    let res = pg().parse_sql_statements("SELECT * FROM t WHERE name ~ 'a' ESCAPE '!'");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected end of statement, found: ESCAPE at line 1, column 34".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_bitwise_operators() {
+    use self::ASTNode::*;
+    for (sql_op, op) in &[
+        ("&", SQLOperator::BitwiseAnd),
+        ("|", SQLOperator::BitwiseOr),
+        ("<<", SQLOperator::ShiftLeft),
+        (">>", SQLOperator::ShiftRight),
+    ] {
+        let sql = format!("SELECT a {} b", sql_op);
+        let select = pg_and_generic().verified_only_select(&sql);
+        assert_eq!(
+            &SQLBinaryExpr {
+                left: Box::new(SQLIdentifier("a".to_string())),
+                op: op.clone(),
+                right: Box::new(SQLIdentifier("b".to_string())),
+            },
+            expr_from_projection(only(&select.projection))
+        );
+    }
+
+    // `#` starts an identifier in the generic dialect
+    let select = pg().verified_only_select("SELECT a # b");
+    assert_eq!(
+        &SQLBinaryExpr {
+            left: Box::new(SQLIdentifier("a".to_string())),
+            op: SQLOperator::BitwiseXor,
+            right: Box::new(SQLIdentifier("b".to_string())),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    // `&` binds tighter than `|`, and `<<` tighter than both, but all of
+    // them bind looser than arithmetic and tighter than comparisons
+    let select = pg_and_generic().verified_only_select("SELECT a & b | c << 2 + 1 = d");
+    assert_eq!(
+        &SQLBinaryExpr {
+            left: Box::new(SQLBinaryExpr {
+                left: Box::new(SQLBinaryExpr {
+                    left: Box::new(SQLIdentifier("a".to_string())),
+                    op: SQLOperator::BitwiseAnd,
+                    right: Box::new(SQLIdentifier("b".to_string())),
+                }),
+                op: SQLOperator::BitwiseOr,
+                right: Box::new(SQLBinaryExpr {
+                    left: Box::new(SQLIdentifier("c".to_string())),
+                    op: SQLOperator::ShiftLeft,
+                    right: Box::new(SQLBinaryExpr {
+                        left: Box::new(SQLValue(Value::Long(2))),
+                        op: SQLOperator::Plus,
+                        right: Box::new(SQLValue(Value::Long(1))),
+                    }),
+                }),
+            }),
+            op: SQLOperator::Eq,
+            right: Box::new(SQLIdentifier("d".to_string())),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    let ansi = TestedDialects {
+        dialects: vec![Box::new(AnsiSqlDialect {})],
+    };
+    let res = ansi.parse_sql_statements("SELECT a & b");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected end of statement, found: & at line 1, column 10".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_exponent() {
+    use self::ASTNode::*;
+    let select = pg_and_generic().verified_only_select("SELECT 2 ^ 3");
+    assert_eq!(
+        &SQLBinaryExpr {
+            left: Box::new(SQLValue(Value::Long(2))),
+            op: SQLOperator::Exponent,
+            right: Box::new(SQLValue(Value::Long(3))),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    // right-associative, and binds tighter than multiplication
+    let select = pg_and_generic().verified_only_select("SELECT 2 * 2 ^ 3 ^ 2");
+    assert_eq!(
+        &SQLBinaryExpr {
+            left: Box::new(SQLValue(Value::Long(2))),
+            op: SQLOperator::Multiply,
+            right: Box::new(SQLBinaryExpr {
+                left: Box::new(SQLValue(Value::Long(2))),
+                op: SQLOperator::Exponent,
+                right: Box::new(SQLBinaryExpr {
+                    left: Box::new(SQLValue(Value::Long(3))),
+                    op: SQLOperator::Exponent,
+                    right: Box::new(SQLValue(Value::Long(2))),
+                }),
+            }),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    let ansi = TestedDialects {
+        dialects: vec![Box::new(AnsiSqlDialect {})],
+    };
+    let res = ansi.parse_sql_statements("SELECT 2 ^ 3");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected end of statement, found: ^ at line 1, column 10".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_insert_on_conflict() {
+    let stmt = pg_and_generic()
+        .verified_stmt("INSERT INTO t (id, name) VALUES (1, 'a') ON CONFLICT (id) DO NOTHING");
+    match stmt {
+        SQLStatement::SQLInsert { on_conflict, .. } => assert_eq!(
+            Some(SQLOnConflict::DoNothing {
+                conflict_target: vec!["id".to_string()],
+            }),
+            on_conflict
+        ),
+        _ => panic!("Expected an INSERT"),
+    }
+
+    let stmt = pg_and_generic().verified_stmt(
+        "INSERT INTO t (id, name) VALUES (1, 'a') ON CONFLICT (id) DO UPDATE SET name = 'b', id = 2 WHERE id > 0",
+    );
+    match stmt {
+        SQLStatement::SQLInsert { on_conflict, .. } => assert_eq!(
+            Some(SQLOnConflict::DoUpdate {
+                conflict_target: vec!["id".to_string()],
+                assignments: vec![
+                    SQLAssignment {
+                        id: "name".to_string(),
+                        value: ASTNode::SQLValue(Value::SingleQuotedString("b".to_string())),
+                    },
+                    SQLAssignment {
+                        id: "id".to_string(),
+                        value: ASTNode::SQLValue(Value::Long(2)),
+                    },
+                ],
+                selection: Some(Box::new(ASTNode::SQLBinaryExpr {
+                    left: Box::new(ASTNode::SQLIdentifier("id".to_string())),
+                    op: SQLOperator::Gt,
+                    right: Box::new(ASTNode::SQLValue(Value::Long(0))),
+                })),
+            }),
+            on_conflict
+        ),
+        _ => panic!("Expected an INSERT"),
+    }
+
+    pg_and_generic().verified_stmt("INSERT INTO t VALUES (1) ON CONFLICT DO NOTHING");
+
+    let ansi = TestedDialects {
+        dialects: vec![Box::new(AnsiSqlDialect {})],
+    };
+    let res = ansi.parse_sql_statements("INSERT INTO t VALUES (1) ON CONFLICT DO NOTHING");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected end of statement, found: ON at line 1, column 26".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_returning() {
+    let stmt = pg_and_generic().verified_stmt("INSERT INTO t (a) VALUES (1) RETURNING *");
+    match stmt {
+        SQLStatement::SQLInsert { returning, .. } => {
+            assert_eq!(Some(vec![SQLSelectItem::Wildcard]), returning)
+        }
+        _ => panic!("Expected an INSERT"),
+    }
+
+    let stmt = pg_and_generic().verified_stmt("UPDATE t SET a = 1 RETURNING a, b AS c");
+    match stmt {
+        SQLStatement::SQLUpdate { returning, .. } => assert_eq!(
+            Some(vec![
+                SQLSelectItem::UnnamedExpression(ASTNode::SQLIdentifier("a".to_string())),
+                SQLSelectItem::ExpressionWithAlias {
+                    expr: ASTNode::SQLIdentifier("b".to_string()),
+                    alias: "c".to_string(),
+                },
+            ]),
+            returning
+        ),
+        _ => panic!("Expected an UPDATE"),
+    }
+
+    let stmt = pg_and_generic().verified_stmt("DELETE FROM t WHERE a = 1 RETURNING *");
+    match stmt {
+        SQLStatement::SQLDelete { returning, .. } => {
+            assert_eq!(Some(vec![SQLSelectItem::Wildcard]), returning)
+        }
+        _ => panic!("Expected a DELETE"),
+    }
+
+    pg().verified_stmt("INSERT INTO t (id) VALUES (1) ON CONFLICT (id) DO NOTHING RETURNING id");
+
+    let ansi = TestedDialects {
+        dialects: vec![Box::new(AnsiSqlDialect {})],
+    };
+    let res = ansi.parse_sql_statements("DELETE FROM t RETURNING *");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected end of statement, found: RETURNING at line 1, column 15".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_type_aliases() {
+    let aliases = vec![
+        ("int2", SQLType::SmallInt, "smallint"),
+        ("int4", SQLType::Int, "int"),
+        ("INT8", SQLType::BigInt, "bigint"),
+        ("float4", SQLType::Real, "real"),
+        ("float8", SQLType::Double, "double"),
+        ("bool", SQLType::Boolean, "boolean"),
+    ];
+    for (alias, data_type, canonical) in aliases {
+        let sql = format!("CAST(a AS {})", alias);
+        let expr = pg().run_parser_method(&sql, |parser| parser.parse_expr().unwrap());
+        assert_eq!(
+            ASTNode::SQLCast {
+                expr: Box::new(ASTNode::SQLIdentifier("a".to_string())),
+                data_type,
+            },
+            expr
+        );
+        assert_eq!(format!("CAST(a AS {})", canonical), expr.to_string());
+    }
+
+    match pg().one_statement_parses_to(
+        "CREATE TABLE t (a int4, b float8, c bool)",
+        "CREATE TABLE t (a int, b double, c boolean)",
+    ) {
+        SQLStatement::SQLCreateTable { columns, .. } => {
+            let data_types: Vec<_> = columns.iter().map(|c| c.data_type.clone()).collect();
+            assert_eq!(
+                vec![SQLType::Int, SQLType::Double, SQLType::Boolean],
+                data_types
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    // quoted or qualified names, and other dialects, are not resolved
+    pg().verified_stmt(r#"SELECT CAST(a AS "int4"), CAST(b AS pg_catalog.int4)"#);
+    let ansi = TestedDialects {
+        dialects: vec![Box::new(AnsiSqlDialect {})],
+    };
+    let select = ansi.verified_only_select("SELECT CAST(a AS int4)");
+    assert_eq!(
+        &ASTNode::SQLCast {
+            expr: Box::new(ASTNode::SQLIdentifier("a".to_string())),
+            data_type: SQLType::Custom(SQLObjectName(vec!["int4".to_string()])),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+}
+
+#[test]
+fn parse_oid_types() {
+    let stmt = pg().one_statement_parses_to(
+        "SELECT CAST('t'::regclass AS oid), 'f'::regproc, CAST(a AS REGTYPE), b::regnamespace",
+        "SELECT CAST(CAST('t' AS regclass) AS oid), CAST('f' AS regproc), \
+         CAST(a AS regtype), CAST(b AS regnamespace)",
+    );
+    let projection = match stmt {
+        SQLStatement::SQLQuery(query) => match query.body {
+            SQLSetExpr::Select(select) => select.projection,
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        &ASTNode::SQLCast {
+            expr: Box::new(ASTNode::SQLCast {
+                expr: Box::new(ASTNode::SQLValue(Value::SingleQuotedString(
+                    "t".to_string()
+                ))),
+                data_type: SQLType::Regclass,
+            }),
+            data_type: SQLType::Oid,
+        },
+        expr_from_projection(&projection[0])
+    );
+    let data_types: Vec<_> = projection[1..]
+        .iter()
+        .map(|item| match expr_from_projection(item) {
+            ASTNode::SQLCast { data_type, .. } => data_type.clone(),
+            _ => unreachable!(),
+        })
+        .collect();
+    assert_eq!(
+        vec![SQLType::Regproc, SQLType::Regtype, SQLType::Regnamespace],
+        data_types
+    );
+
+    // the OID alias types are specific to PostgreSQL
+    let generic = TestedDialects {
+        dialects: vec![Box::new(GenericSqlDialect {})],
+    };
+    let select = generic.verified_only_select("SELECT CAST(a AS oid)");
+    assert_eq!(
+        &ASTNode::SQLCast {
+            expr: Box::new(ASTNode::SQLIdentifier("a".to_string())),
+            data_type: SQLType::Custom(SQLObjectName(vec!["oid".to_string()])),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+}
+
+#[test]
+fn parse_array_types() {
+    let sql = "CREATE TABLE t (a int[], b int[3][3], c int ARRAY[3], d text ARRAY, e text[])";
+    let canonical = "CREATE TABLE t (a int[], b int[3][3], c int[3], d text[], e text[])";
+    match pg_and_generic().one_statement_parses_to(sql, canonical) {
+        SQLStatement::SQLCreateTable { columns, .. } => {
+            let data_types: Vec<_> = columns.into_iter().map(|c| c.data_type).collect();
+            assert_eq!(
+                vec![
+                    SQLType::Array(Box::new(SQLType::Int), None),
+                    SQLType::Array(
+                        Box::new(SQLType::Array(Box::new(SQLType::Int), Some(3))),
+                        Some(3)
+                    ),
+                    SQLType::Array(Box::new(SQLType::Int), Some(3)),
+                    SQLType::Array(Box::new(SQLType::Text), None),
+                    SQLType::Array(Box::new(SQLType::Text), None),
+                ],
+                data_types
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    let select = pg_and_generic().verified_only_select("SELECT CAST(a AS bigint[2][])");
+    assert_eq!(
+        &ASTNode::SQLCast {
+            expr: Box::new(ASTNode::SQLIdentifier("a".to_string())),
+            data_type: SQLType::Array(
+                Box::new(SQLType::Array(Box::new(SQLType::BigInt), Some(2))),
+                None
+            ),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    match pg_and_generic().verified_stmt("CREATE TABLE t (tags text[], matrix int[][])") {
+        SQLStatement::SQLCreateTable { columns, .. } => assert_eq!(
+            SQLType::Array(Box::new(SQLType::Array(Box::new(SQLType::Int), None)), None),
+            columns[1].data_type
+        ),
+        _ => unreachable!(),
+    }
+    pg_and_generic().one_statement_parses_to(
+        "SELECT CAST(a AS int ARRAY ARRAY), b::text[][]",
+        "SELECT CAST(a AS int[][]), CAST(b AS text[][])",
+    );
+
+    let res = pg().parse_sql_statements("CREATE TABLE t (a int[3)");
+    assert_eq!(
+        ParserError::ParserError("Expected ], found: ) at line 1, column 24".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_serial_types() {
+    let sql = "CREATE TABLE t (\
+               id serial PRIMARY KEY, \
+               big bigserial NOT NULL, \
+               small smallserial)";
+    match pg().verified_stmt(sql) {
+        SQLStatement::SQLCreateTable { columns, .. } => {
+            assert_eq!(
+                SQLColumnDef {
+                    name: "id".into(),
+                    data_type: SQLType::Serial,
+                    options: vec![ColumnOptionDef {
+                        name: None,
+                        option: ColumnOption::Unique { is_primary: true },
+                    }],
+                },
+                columns[0]
+            );
+            let data_types: Vec<_> = columns.iter().map(|c| c.data_type.clone()).collect();
+            assert_eq!(
+                vec![SQLType::Serial, SQLType::BigSerial, SQLType::SmallSerial],
+                data_types
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg().one_statement_parses_to(
+        "CREATE TABLE t (a SERIAL2, b SERIAL4, c SERIAL8)",
+        "CREATE TABLE t (a smallserial, b serial, c bigserial)",
+    );
+}
+
+#[test]
+fn parse_json_types() {
+    match pg_and_generic().one_statement_parses_to(
+        "CREATE TABLE t (a JSON, b JSONB NOT NULL)",
+        "CREATE TABLE t (a json, b jsonb NOT NULL)",
+    ) {
+        SQLStatement::SQLCreateTable { columns, .. } => {
+            assert_eq!(SQLType::Json, columns[0].data_type);
+            assert_eq!(SQLType::Jsonb, columns[1].data_type);
+        }
+        _ => unreachable!(),
+    }
+
+    let select = pg_and_generic().verified_only_select("SELECT CAST(x AS jsonb), CAST(y AS json)");
+    assert_eq!(
+        &ASTNode::SQLCast {
+            expr: Box::new(ASTNode::SQLIdentifier("x".to_string())),
+            data_type: SQLType::Jsonb,
+        },
+        expr_from_projection(&select.projection[0])
+    );
+    assert_eq!(
+        &ASTNode::SQLCast {
+            expr: Box::new(ASTNode::SQLIdentifier("y".to_string())),
+            data_type: SQLType::Json,
+        },
+        expr_from_projection(&select.projection[1])
+    );
+
+    let sql = "CREATE TABLE t (id int, data jsonb DEFAULT '{}' NOT NULL)";
+    match pg_and_generic().verified_stmt(sql) {
+        SQLStatement::SQLCreateTable { columns, .. } => {
+            assert_eq!(SQLType::Jsonb, columns[1].data_type);
+            assert_eq!(
+                vec![
+                    ColumnOptionDef {
+                        name: None,
+                        option: ColumnOption::Default(ASTNode::SQLValue(
+                            Value::SingleQuotedString("{}".to_string())
+                        )),
+                    },
+                    ColumnOptionDef {
+                        name: None,
+                        option: ColumnOption::NotNull,
+                    },
+                ],
+                columns[1].options
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    match pg().one_statement_parses_to("SELECT data::jsonb", "SELECT CAST(data AS jsonb)") {
+        SQLStatement::SQLQuery(query) => match query.body {
+            SQLSetExpr::Select(select) => assert_eq!(
+                &ASTNode::SQLCast {
+                    expr: Box::new(ASTNode::SQLIdentifier("data".to_string())),
+                    data_type: SQLType::Jsonb,
+                },
+                expr_from_projection(only(&select.projection))
+            ),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_numbered_placeholders() {
+    let sql = "SELECT * FROM t WHERE a = $1 AND b = ? AND c = $12";
+    let select = pg_and_generic().verified_only_select(sql);
+    assert_eq!(
+        ASTNode::SQLBinaryExpr {
+            left: Box::new(ASTNode::SQLBinaryExpr {
+                left: Box::new(ASTNode::SQLBinaryExpr {
+                    left: Box::new(ASTNode::SQLIdentifier("a".to_string())),
+                    op: SQLOperator::Eq,
+                    right: Box::new(ASTNode::SQLValue(Value::Placeholder("$1".to_string()))),
+                }),
+                op: SQLOperator::And,
+                right: Box::new(ASTNode::SQLBinaryExpr {
+                    left: Box::new(ASTNode::SQLIdentifier("b".to_string())),
+                    op: SQLOperator::Eq,
+                    right: Box::new(ASTNode::SQLValue(Value::Placeholder("?".to_string()))),
+                }),
+            }),
+            op: SQLOperator::And,
+            right: Box::new(ASTNode::SQLBinaryExpr {
+                left: Box::new(ASTNode::SQLIdentifier("c".to_string())),
+                op: SQLOperator::Eq,
+                right: Box::new(ASTNode::SQLValue(Value::Placeholder("$12".to_string()))),
+            }),
+        },
+        select.selection.unwrap()
+    );
+
+    let ansi = TestedDialects {
+        dialects: vec![Box::new(AnsiSqlDialect {})],
+    };
+    assert!(ansi.parse_sql_statements("SELECT $1").is_err());
+}
+
+#[test]
+fn parse_statement_parameters() {
+    let stmt = pg_and_generic().verified_stmt("SELECT * FROM t WHERE a = $1 OR b = $2");
+    assert_eq!(vec!["$1".to_string(), "$2".to_string()], stmt.parameters());
+
+    let stmt = pg_and_generic().verified_stmt(
+        "UPDATE t SET a = $2, b = lower($1) \
+         WHERE c IN (SELECT d FROM u WHERE e = $1) AND f = ? RETURNING a",
+    );
+    assert_eq!(
+        vec![
+            "$2".to_string(),
+            "$1".to_string(),
+            "$1".to_string(),
+            "?".to_string()
+        ],
+        stmt.parameters()
+    );
+
+    let stmt = pg_and_generic().verified_stmt("SELECT 1");
+    assert!(stmt.parameters().is_empty());
+}
+
+#[test]
+fn pg_display_round_trip() {
+    pg_and_generic().verify_round_trips(&[
+        "SELECT * FROM t WHERE a = $1 AND b = $2",
+        "SELECT $$it's$$, $tag$a$b$tag$",
+        "SELECT a FROM t WHERE a ILIKE 'x%' AND b ~* 'y' AND c !~ 'z'",
+        "SELECT a & b, a << 2, a ^ 2",
+        "CREATE TABLE t (a int[], b int[3][3], c jsonb DEFAULT '{}', d uuid)",
+        "INSERT INTO t (a) VALUES (1) ON CONFLICT (a) DO UPDATE SET a = 2 RETURNING a",
+        "INSERT INTO t (a) VALUES (1) ON CONFLICT DO NOTHING",
+        "UPDATE t SET a = 1 WHERE b = 2 RETURNING *",
+        "DELETE FROM t WHERE a = 1 RETURNING a, b AS c",
+    ]);
+}
+
 fn pg() -> TestedDialects {
     TestedDialects {
         dialects: vec![Box::new(PostgreSqlDialect {})],